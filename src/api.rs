@@ -1,13 +1,20 @@
 use crate::{
     account::AccountManager,
     asset::AssetManager,
-    client::Client,
+    client::{
+        Client,
+        RetryPolicy,
+    },
     config::Config,
     general::General,
     market::MarketData,
     position::PositionManager,
     trade::Trader,
-    ws::Stream,
+    transport::Transport,
+    ws::{
+        HeartbeatPolicy,
+        Stream,
+    },
 };
 
 pub enum API {
@@ -20,11 +27,12 @@ pub enum API {
     SpotMargin(SpotMargin),
 }
 /// Bybit Endpoints
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum WebsocketAPI {
     PublicSpot,
     PublicLinear,
     PublicInverse,
+    PublicOption,
     Private,
     TradeStream,
 }
@@ -285,6 +293,7 @@ impl AsRef<str> for WebsocketAPI {
             WebsocketAPI::PublicSpot => "/public/spot",
             WebsocketAPI::PublicLinear => "/public/linear",
             WebsocketAPI::PublicInverse => "/public/inverse",
+            WebsocketAPI::PublicOption => "/public/option",
             WebsocketAPI::Private => "/private",
             WebsocketAPI::TradeStream => "/trade",
         }
@@ -457,6 +466,122 @@ impl Bybit for Stream {
     ) -> Stream {
         Stream {
             client: Client::new(api_key, secret_key, config.ws_endpoint.to_string()),
+            reconnect: None,
+            heartbeat: HeartbeatPolicy::default(),
+        }
+    }
+}
+
+/// A single entry point to every API module, built once from a `Config` and
+/// credentials.
+///
+/// All views share one `Client`, so they reuse the same HTTP connection pool and
+/// credentials instead of each module building its own. Views are cheap to create
+/// and can be requested as often as needed.
+///
+/// # Example
+///
+/// ```no_run
+/// use bybit::api::{Bybit, BybitClient};
+///
+/// # async fn run() -> Result<(), bybit::errors::BybitError> {
+/// let bybit = BybitClient::new(Some("key".into()), Some("secret".into()));
+/// let tickers = bybit.market().get_futures_tickers(Some("BTCUSDT")).await?;
+/// let wallet = bybit.account().get_wallet_balance("UNIFIED", None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct BybitClient {
+    pub client: Client,
+    pub config: Config,
+}
+
+impl BybitClient {
+    /// Retries failed REST requests of every view according to `policy`.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.client = self.client.with_retry(policy);
+        self
+    }
+
+    /// Sends the REST requests of every view through `transport`.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.client = self.client.with_transport(transport);
+        self
+    }
+
+    pub fn general(&self) -> General {
+        General {
+            client: self.client.clone(),
+        }
+    }
+
+    pub fn market(&self) -> MarketData {
+        MarketData {
+            client: self.client.clone(),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    pub fn trade(&self) -> Trader {
+        Trader {
+            client: self.client.clone(),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    pub fn position(&self) -> PositionManager {
+        PositionManager {
+            client: self.client.clone(),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    pub fn account(&self) -> AccountManager {
+        AccountManager {
+            client: self.client.clone(),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    pub fn asset(&self) -> AssetManager {
+        AssetManager {
+            client: self.client.clone(),
+            recv_window: self.config.recv_window,
+        }
+    }
+
+    /// A websocket `Stream` connecting to `Config.ws_endpoint` with the shared
+    /// credentials.
+    pub fn ws(&self) -> Stream {
+        Stream {
+            client: Client {
+                host: self.config.ws_endpoint.to_string(),
+                ..self.client.clone()
+            },
+            reconnect: None,
+            heartbeat: HeartbeatPolicy::default(),
+        }
+    }
+}
+
+impl Bybit for BybitClient {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> BybitClient {
+        Self::new_with_config(&Config::default(), api_key, secret_key)
+    }
+
+    fn new_with_config(
+        config: &Config,
+        api_key: Option<String>,
+        secret_key: Option<String>,
+    ) -> BybitClient {
+        BybitClient {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.to_string(),
+            ),
+            config: config.clone(),
         }
     }
 }
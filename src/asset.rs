@@ -11,6 +11,7 @@ use crate::{
     errors::BybitError,
     model::{
         AllCoinsResponse,
+        Amount,
         ConvertCommonResponse,
         QuoteApply,
         QuoteApplyResponse,
@@ -61,7 +62,7 @@ impl AssetManager {
         from_coin: &str,
         to_coin: &str,
         request_coin: &str,
-        request_amount: String,
+        request_amount: Amount,
         account_type: QuotaAccountType,
     ) -> Result<QuoteApplyResponse, BybitError> {
         let req = QuoteApply::custom(
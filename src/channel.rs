@@ -0,0 +1,285 @@
+use crate::errors::BybitError;
+use futures::task::AtomicWaker;
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{
+        atomic::{
+            AtomicBool,
+            AtomicU64,
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+    task::{
+        Context,
+        Poll,
+        Waker,
+    },
+};
+use tokio::sync::mpsc;
+
+/// What a bounded channel does with a new value once it is full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Stop reading from the websocket until the consumer catches up.
+    ///
+    /// Capacity is checked before each frame is read, so a frame carrying several
+    /// values may push the queue slightly past its capacity.
+    #[default]
+    Block,
+    /// Discard the oldest queued value to make room for the new one.
+    DropOldest,
+    /// Discard the new value and keep the queue as it is.
+    DropNewest,
+    /// Keep only the latest value per topic, replacing older values in place.
+    ///
+    /// When the queue is full and the topic is not queued yet, the oldest value is
+    /// discarded.
+    ConflateLatest,
+}
+
+/// Destination for values decoded by the `ws_*` helpers of `Stream`.
+///
+/// Implemented for tokio's `UnboundedSender` and for `BoundedSender`.
+pub trait EventSender<T>: Send + 'static {
+    /// Sends a value received on `topic`.
+    fn send_event(&self, topic: &str, value: T) -> Result<(), BybitError>;
+
+    /// Polled by the event loop before it reads the next frame. Returning
+    /// `Poll::Pending` pauses the reader until the sender has room again.
+    fn poll_ready(&self, _cx: &mut Context<'_>) -> Poll<Result<(), BybitError>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T: Send + 'static> EventSender<T> for mpsc::UnboundedSender<T> {
+    fn send_event(&self, _topic: &str, value: T) -> Result<(), BybitError> {
+        Ok(self.send(value)?)
+    }
+}
+
+struct Shared<T> {
+    queue: Mutex<VecDeque<(String, T)>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: AtomicU64,
+    senders: AtomicUsize,
+    closed: AtomicBool,
+    recv_waker: AtomicWaker,
+    /// Every `BoundedSender` clone waiting for room, woken together.
+    send_wakers: Mutex<Vec<Waker>>,
+}
+
+impl<T> Shared<T> {
+    fn wake_senders(&self) {
+        for waker in self.send_wakers.lock().unwrap().drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// Creates a bounded channel that applies `policy` once `capacity` values are queued.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn bounded_channel<T>(
+    capacity: usize,
+    policy: OverflowPolicy,
+) -> (BoundedSender<T>, BoundedReceiver<T>) {
+    assert!(
+        capacity > 0,
+        "bounded channel capacity must be greater than zero"
+    );
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(VecDeque::with_capacity(capacity)),
+        capacity,
+        policy,
+        dropped: AtomicU64::new(0),
+        senders: AtomicUsize::new(1),
+        closed: AtomicBool::new(false),
+        recv_waker: AtomicWaker::new(),
+        send_wakers: Mutex::new(Vec::new()),
+    });
+    (
+        BoundedSender {
+            shared: shared.clone(),
+        },
+        BoundedReceiver { shared },
+    )
+}
+
+/// Sending half of a channel created with `bounded_channel`.
+pub struct BoundedSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedSender<T> {
+    /// Queues `value`, applying the overflow policy if the channel is full.
+    ///
+    /// Values discarded by the policy are counted in `dropped`. Returns
+    /// `BybitError::ChannelClosed` once the receiver has been dropped.
+    pub fn send(&self, topic: &str, value: T) -> Result<(), BybitError> {
+        let shared = &self.shared;
+        if shared.closed.load(Ordering::Acquire) {
+            return Err(BybitError::ChannelClosed);
+        }
+        let mut queue = shared.queue.lock().unwrap();
+        let full = queue.len() >= shared.capacity;
+        match shared.policy {
+            OverflowPolicy::Block => queue.push_back((topic.to_string(), value)),
+            OverflowPolicy::DropOldest => {
+                if full {
+                    queue.pop_front();
+                    shared.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                queue.push_back((topic.to_string(), value));
+            }
+            OverflowPolicy::DropNewest => {
+                if full {
+                    shared.dropped.fetch_add(1, Ordering::Relaxed);
+                    return Ok(());
+                }
+                queue.push_back((topic.to_string(), value));
+            }
+            OverflowPolicy::ConflateLatest => {
+                if let Some(queued) = queue.iter_mut().find(|(key, _)| key == topic) {
+                    queued.1 = value;
+                    shared.dropped.fetch_add(1, Ordering::Relaxed);
+                } else {
+                    if full {
+                        queue.pop_front();
+                        shared.dropped.fetch_add(1, Ordering::Relaxed);
+                    }
+                    queue.push_back((topic.to_string(), value));
+                }
+            }
+        }
+        drop(queue);
+        shared.recv_waker.wake();
+        Ok(())
+    }
+
+    /// Number of values discarded by the overflow policy so far.
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Number of values currently queued.
+    pub fn len(&self) -> usize {
+        self.shared.queue.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn poll_capacity(&self, cx: &mut Context<'_>) -> Poll<Result<(), BybitError>> {
+        let shared = &self.shared;
+        if shared.closed.load(Ordering::Acquire) {
+            return Poll::Ready(Err(BybitError::ChannelClosed));
+        }
+        if shared.policy != OverflowPolicy::Block || self.len() < shared.capacity {
+            return Poll::Ready(Ok(()));
+        }
+        {
+            let mut wakers = shared.send_wakers.lock().unwrap();
+            if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+        // The receiver may have made room before the waker was registered.
+        if self.len() < shared.capacity || shared.closed.load(Ordering::Acquire) {
+            cx.waker().wake_by_ref();
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::AcqRel);
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for BoundedSender<T> {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.recv_waker.wake();
+        }
+    }
+}
+
+impl<T: Send + 'static> EventSender<T> for BoundedSender<T> {
+    fn send_event(&self, topic: &str, value: T) -> Result<(), BybitError> {
+        self.send(topic, value)
+    }
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), BybitError>> {
+        self.poll_capacity(cx)
+    }
+}
+
+/// Receiving half of a channel created with `bounded_channel`.
+pub struct BoundedReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedReceiver<T> {
+    /// Receives the next value, or `None` once every sender has been dropped and the
+    /// queue is empty.
+    pub async fn recv(&mut self) -> Option<T> {
+        std::future::poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Returns the next value if one is queued, without waiting.
+    pub fn try_recv(&mut self) -> Option<T> {
+        let value = self.shared.queue.lock().unwrap().pop_front();
+        if value.is_some() {
+            self.shared.wake_senders();
+        }
+        value.map(|(_, value)| value)
+    }
+
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        if let Some(value) = self.try_recv() {
+            return Poll::Ready(Some(value));
+        }
+        if self.shared.senders.load(Ordering::Acquire) == 0 {
+            return Poll::Ready(self.try_recv());
+        }
+        self.shared.recv_waker.register(cx.waker());
+        // A value may have been sent before the waker was registered.
+        match self.try_recv() {
+            Some(value) => Poll::Ready(Some(value)),
+            None if self.shared.senders.load(Ordering::Acquire) == 0 => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+
+    /// Number of values discarded by the overflow policy so far.
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> futures::Stream for BoundedReceiver<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.poll_recv(cx)
+    }
+}
+
+impl<T> Drop for BoundedReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.closed.store(true, Ordering::Release);
+        self.shared.wake_senders();
+    }
+}
@@ -1,24 +1,38 @@
-use tokio::net::TcpStream;
+use tokio::{
+    net::TcpStream,
+    time::{
+        sleep,
+        Duration,
+    },
+};
 
 use crate::{
     api::{
         WebsocketAPI,
         API,
     },
+    clock::ServerClock,
     errors::{
         BybitContentError,
         BybitError,
     },
-    util::{
-        generate_random_uid,
-        get_timestamp,
+    rate_limit::{
+        LimitKey,
+        RateLimiter,
     },
+    transport::{
+        HttpRequest,
+        HttpResponse,
+        Transport,
+    },
+    util::generate_random_uid,
 };
 use hex::encode as hex_encode;
 use hmac::{
     Hmac,
     Mac,
 };
+use rand::Rng;
 use reqwest::{
     header::{
         HeaderMap,
@@ -28,14 +42,18 @@ use reqwest::{
         USER_AGENT,
     },
     Client as ReqwestClient,
-    Response as ReqwestResponse,
+    Method,
     StatusCode,
 };
 
 use futures::sink::SinkExt;
-use serde::de::DeserializeOwned;
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+};
 use serde_json::json;
 use sha2::Sha256;
+use std::sync::Arc;
 use tokio_tungstenite::{
     connect_async,
     tungstenite::Message as WsMessage,
@@ -44,7 +62,7 @@ use tokio_tungstenite::{
 };
 use url::Url as WsUrl;
 
-/// The main client struct that wraps the reqwest client.
+/// The main client struct that wraps the HTTP transport.
 ///
 /// It stores the API key, secret key, and host to make requests to the Bybit API.
 #[derive(Clone)]
@@ -55,10 +73,101 @@ pub struct Client {
     pub secret_key: String,
     /// The host to make requests to.
     pub host: String,
-    /// The reqwest client that makes the HTTP requests.
+    /// Sends the HTTP requests, a default `reqwest::Client` unless replaced with
+    /// `with_transport`.
+    pub transport: Arc<dyn Transport>,
+    /// The rate limit budget shared by every clone of this client.
+    pub rate_limiter: RateLimiter,
+    /// The server time estimate used to stamp signed requests, shared by every
+    /// clone of this client.
+    pub clock: ServerClock,
+    /// How failed requests are retried. `None` sends every request once.
+    pub retry: Option<RetryPolicy>,
+    /// The `reqwest::Client` built by `Client::new`. It no longer sends this
+    /// client's requests once `with_transport` has replaced it.
+    #[deprecated(
+        note = "requests are sent through `transport`, pass a `reqwest::Client` to `with_transport` to configure it"
+    )]
     pub inner_client: ReqwestClient,
 }
 
+/// Backoff settings used by a `Client` to retry failed requests.
+///
+/// GET requests are retried on connection errors, timeouts, 5xx and 429 statuses,
+/// and the retCodes for which `BybitErrorCode::is_retryable` holds, such as rate
+/// limits and transient server errors. POST requests may already have taken effect
+/// when such an error comes back, so they are only resent when the request provably
+/// never reached Bybit: a failed connection or a `10006` rejection. Orders carrying
+/// an `order_link_id` are looked up before being resent, see
+/// `Trader::place_custom_order`.
+///
+/// The delay before retry `n` is `initial_delay * multiplier^(n - 1)`, capped at
+/// `max_delay` and scaled by a random factor in `1 - jitter..=1 + jitter`.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+    pub jitter: f64,
+}
+
+impl RetryPolicy {
+    pub const fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: 0.2,
+        }
+    }
+
+    pub const fn set_max_retries(self, max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..self
+        }
+    }
+
+    pub const fn set_initial_delay(self, initial_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            ..self
+        }
+    }
+
+    pub const fn set_max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay, ..self }
+    }
+
+    pub const fn set_multiplier(self, multiplier: f64) -> Self {
+        Self { multiplier, ..self }
+    }
+
+    pub const fn set_jitter(self, jitter: f64) -> Self {
+        Self { jitter, ..self }
+    }
+
+    /// Returns how long to wait before the given (1-based) retry.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        let delay = if delay.is_finite() {
+            delay.min(self.max_delay.as_secs_f64())
+        } else {
+            self.max_delay.as_secs_f64()
+        };
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let factor = if jitter > 0.0 {
+            rand::thread_rng().gen_range(1.0 - jitter..=1.0 + jitter)
+        } else {
+            1.0
+        };
+        Duration::from_secs_f64(delay * factor)
+    }
+}
+
 impl Client {
     /// Create a new instance of `Client`.
     ///
@@ -71,6 +180,8 @@ impl Client {
     /// # Returns
     ///
     /// A new instance of `Client`.
+    // Still fills in the deprecated `inner_client`.
+    #[allow(deprecated)]
     pub fn new(
         api_key: Option<String>,
         secret_key: Option<String>,
@@ -81,6 +192,9 @@ impl Client {
             .build()
             .expect("Failed to build reqwest client");
 
+        // Signed requests and rate limit windows both follow the server clock.
+        let clock = ServerClock::new();
+
         // Create a new instance of `Client` with the provided arguments.
         Client {
             // Set the API key. If `api_key` is `None`, set it to an empty string.
@@ -95,11 +209,27 @@ impl Client {
             },
             // Set the host.
             host,
-            // Set the reqwest client.
+            // Set the reqwest client as the transport.
+            transport: Arc::new(inner_client.clone()),
+            rate_limiter: RateLimiter::with_clock(clock.clone()),
+            clock,
+            retry: None,
             inner_client,
         }
     }
 
+    /// Retries failed requests according to `policy`.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Sends requests through `transport` instead of the default reqwest client.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
     /// Makes an unsigned HTTP GET request to the specified endpoint.
     ///
     /// # Arguments
@@ -117,6 +247,10 @@ impl Client {
     ) -> Result<T, BybitError> {
         // Construct the full URL
         let mut url = format!("{}/{}", self.host, endpoint.as_ref());
+        let limit = LimitKey::from_query(
+            endpoint.as_ref(),
+            request.as_deref().unwrap_or_default(),
+        );
         // If there is a query string, append it to the URL
         if let Some(request) = request {
             if !request.is_empty() {
@@ -125,10 +259,16 @@ impl Client {
             }
         }
 
-        // Make the request using the reqwest client
-        let response = self.inner_client.get(url).send().await?;
-        // Handle the response using the `handler` method
-        self.handler(response).await
+        // Make the request, retrying it if a retry policy is set
+        self.send(&endpoint, &limit, true, || {
+            Ok(HttpRequest {
+                method: Method::GET,
+                url: url.clone(),
+                headers: HeaderMap::new(),
+                body: None,
+            })
+        })
+        .await
     }
 
     /// Makes a signed HTTP GET request to the specified endpoint.
@@ -156,18 +296,27 @@ impl Client {
             url.push_str(format!("?{}", query_string).as_str());
         }
 
-        // Sign the request, passing the query string for signature
-        // The request is signed with the API secret key and requires
-        // the `recv_window` for the request to be within the specified timeframe.
-        let headers =
-            self.build_signed_headers(false, true, recv_window, Some(query_string))?;
-
-        // Make the signed HTTP GET request
-        let client = &self.inner_client;
-        let response = client.get(url.as_str()).headers(headers).send().await?;
-
-        // Handle the response
-        self.handler(response).await
+        let limit = LimitKey::from_query(endpoint.as_ref(), &query_string);
+
+        // Make the signed HTTP GET request, signing every attempt anew
+        self.send(&endpoint, &limit, true, || {
+            // Sign the request, passing the query string for signature
+            // The request is signed with the API secret key and requires
+            // the `recv_window` for the request to be within the specified timeframe.
+            let headers = self.build_signed_headers(
+                false,
+                true,
+                recv_window,
+                Some(query_string.clone()),
+            )?;
+            Ok(HttpRequest {
+                method: Method::GET,
+                url: url.clone(),
+                headers,
+                body: None,
+            })
+        })
+        .await
     }
 
     /// Makes an unsigned HTTP POST request to the specified endpoint.
@@ -187,6 +336,10 @@ impl Client {
     ) -> Result<T, BybitError> {
         // Construct the URL by appending the base host and endpoint to it
         let mut url: String = format!("{}/{}", self.host, endpoint.as_ref());
+        let limit = LimitKey::from_query(
+            endpoint.as_ref(),
+            request.as_deref().unwrap_or_default(),
+        );
 
         // If a request is provided, append it to the URL as a query string
         if let Some(request) = request {
@@ -195,14 +348,16 @@ impl Client {
             }
         }
 
-        // Get a reference to the inner client
-        let client = &self.inner_client;
-
-        // Send the POST request to the constructed URL
-        let response = client.post(url.as_str()).send().await?;
-
-        // Handle the response by passing it to the handler method
-        self.handler(response).await
+        // Send the POST request, only resending it if it cannot have taken effect
+        self.send(&endpoint, &limit, false, || {
+            Ok(HttpRequest {
+                method: Method::POST,
+                url: url.clone(),
+                headers: HeaderMap::new(),
+                body: None,
+            })
+        })
+        .await
     }
 
     /// Makes a signed HTTP POST request to the specified endpoint.
@@ -224,24 +379,88 @@ impl Client {
     ) -> Result<T, BybitError> {
         // Construct the full URL
         let url = format!("{}{}", self.host, endpoint.as_ref());
+        let limit = LimitKey::from_body(
+            endpoint.as_ref(),
+            raw_request_body.as_deref().unwrap_or_default(),
+        );
+
+        // Make the signed HTTP POST request, only resending it if it cannot have
+        // taken effect
+        self.send(&endpoint, &limit, false, || {
+            // Sign the request, passing the raw request body for signature
+            // The request is signed with the API secret key and requires
+            // the `recv_window` for the request to be within the specified timeframe.
+            let headers = self.build_signed_headers(
+                true,
+                true,
+                recv_window,
+                raw_request_body.clone(),
+            )?;
+            Ok(HttpRequest {
+                method: Method::POST,
+                url: url.clone(),
+                headers,
+                body: Some(raw_request_body.clone().unwrap_or_default()),
+            })
+        })
+        .await
+    }
 
-        // Sign the request, passing the raw request body for signature
-        // The request is signed with the API secret key and requires
-        // the `recv_window` for the request to be within the specified timeframe.
-        let headers =
-            self.build_signed_headers(true, true, recv_window, raw_request_body.clone())?;
-
-        // Make the signed HTTP POST request
-        let client = &self.inner_client;
-        let response = client
-            .post(url)
-            .headers(headers)
-            .body(raw_request_body.unwrap_or_default())
-            .send()
-            .await?;
-
-        // Handle the response
-        self.handler(response).await
+    /// Sends the request built by `build`, retrying it according to `retry`.
+    ///
+    /// Every attempt waits for the rate limit of `limit` before `build` is called,
+    /// so a delayed request is signed late enough not to fall out of its
+    /// `recv_window`. `idempotent` requests are retried on any retryable error,
+    /// others only when the failed attempt cannot have reached Bybit.
+    async fn send<T, F>(
+        &self,
+        endpoint: &API,
+        limit: &LimitKey,
+        idempotent: bool,
+        build: F,
+    ) -> Result<T, BybitError>
+    where
+        T: DeserializeOwned + Send + 'static,
+        F: Fn() -> Result<HttpRequest, BybitError>,
+    {
+        let mut attempt = 0;
+        loop {
+            self.rate_limiter.acquire(limit).await;
+            let result = match self.transport.send(build()?).await {
+                Ok(response) => {
+                    self.rate_limiter.update(limit, &response.headers);
+                    self.handler(response)
+                }
+                Err(err) => Err(err),
+            };
+            let err = match result {
+                Ok(response) => return Ok(response),
+                Err(err) => err,
+            };
+            let retry = match &self.retry {
+                Some(policy) if attempt < policy.max_retries => policy,
+                _ => return Err(err),
+            };
+            let resend = if idempotent {
+                err.is_retryable()
+            } else {
+                err.is_safe_to_resend()
+            };
+            if !resend {
+                return Err(err);
+            }
+            attempt += 1;
+            let delay = retry.delay(attempt);
+            tracing::warn!(
+                "Request to {} failed: {}, retrying in {:?} ({}/{})",
+                endpoint.as_ref(),
+                err,
+                delay,
+                attempt,
+                retry.max_retries
+            );
+            sleep(delay).await;
+        }
     }
 
     /// Builds the signed headers for an HTTP request.
@@ -267,8 +486,8 @@ impl Client {
         let mut custom_headers = HeaderMap::new();
         // Set the User-Agent header
         custom_headers.insert(USER_AGENT, HeaderValue::from_static("bybit-rs"));
-        // Get the current timestamp
-        let timestamp = get_timestamp().to_string();
+        // Get the current server timestamp
+        let timestamp = self.clock.now().to_string();
         // Get the receive window
         let window = recv_window.to_string();
         // Sign the request
@@ -402,7 +621,9 @@ impl Client {
     /// # Returns
     ///
     /// The result of deserializing the response body into a specific type.
-    /// Returns `Ok(T)` if the response status is `StatusCode::OK`,
+    /// Returns `Ok(T)` if the response status is `StatusCode::OK` and the `retCode`
+    /// is `0`, returns `Err(BybitError::BybitError(BybitContentError))` for any other
+    /// `retCode`,
     /// returns `Err(BybitError::BybitError(BybitContentError))` if the response
     /// status is `StatusCode::BAD_REQUEST`, returns `Err(BybitError::InternalServerError)`
     /// if the response status is `StatusCode::INTERNAL_SERVER_ERROR`,
@@ -411,30 +632,39 @@ impl Client {
     /// if the response status is `StatusCode::UNAUTHORIZED`, and returns
     /// `Err(BybitError::StatusCode(status))` if the response status is any other
     /// value.
-    async fn handler<T: DeserializeOwned + Send + 'static>(
+    fn handler<T: DeserializeOwned + Send + 'static>(
         &self,
-        response: ReqwestResponse,
+        response: HttpResponse,
     ) -> Result<T, BybitError> {
-        let status = response.status();
+        let status = response.status;
         // Match the status code of the response
         match status {
             // If the status code is OK, deserialize the response body into T and return it
             StatusCode::OK => {
-                let body = response.text().await?;
-                let response: T =
-                    serde_json::from_slice(body.as_bytes()).map_err(|e| {
-                        BybitError::Base(format!(
-                            "Failed to deserialize response `{}` with body: {body}",
-                            e
-                        ))
+                let body = response.body;
+                // Bybit reports most errors with a 200 status and a non-zero
+                // retCode, surface them as errors
+                if let Ok(status) = serde_json::from_str::<RetStatus>(&body) {
+                    if status.ret_code != 0 {
+                        return Err(BybitError::BybitError(BybitContentError {
+                            code: status.ret_code,
+                            msg: status.ret_msg,
+                        }));
+                    }
+                }
+                let mut deserializer = serde_json::Deserializer::from_str(&body);
+                let response: T = serde_path_to_error::deserialize(&mut deserializer)
+                    .map_err(|e| BybitError::Deserialize {
+                        path: e.path().to_string(),
+                        source: e.into_inner(),
+                        body: body.clone(),
                     })?;
                 Ok(response)
             }
             // If the status code is BAD_REQUEST, deserialize the response body into BybitContentError and
             // wrap it in BybitError and return it
             StatusCode::BAD_REQUEST => {
-                let error: BybitContentError =
-                    response.json().await.map_err(BybitError::from)?;
+                let error: BybitContentError = serde_json::from_str(&response.body)?;
                 Err(BybitError::BybitError(error).into())
             }
             // If the status code is INTERNAL_SERVER_ERROR, return BybitError::InternalServerError
@@ -475,7 +705,7 @@ impl Client {
 
         // Calculate the expiration time for the authentication message
         let expiry_time = alive_dur.unwrap_or(9) as u64 * 1000 * 60;
-        let expires = get_timestamp() + expiry_time as u64;
+        let expires = self.clock.now() + expiry_time as u64;
 
         // Calculate the signature for the authentication message
         let mut mac = Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
@@ -508,7 +738,16 @@ impl Client {
                 Ok(ws_stream)
             }
             // If the connection fails, return a BybitError
-            Err(err) => Err(BybitError::Tungstenite(err)),
+            Err(err) => Err(BybitError::from(err)),
         }
     }
 }
+
+/// The status fields shared by every REST response body.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RetStatus {
+    ret_code: i32,
+    #[serde(default)]
+    ret_msg: String,
+}
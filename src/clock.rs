@@ -0,0 +1,81 @@
+use crate::util::get_timestamp;
+use std::{
+    sync::{
+        atomic::{
+            AtomicI64,
+            Ordering,
+        },
+        Arc,
+    },
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+/// Bybit's server time as estimated from the local clock.
+///
+/// Signed REST requests and websocket auth messages are stamped with `now`, so a
+/// drifting host clock does not get them rejected with retCode `10002` for
+/// falling outside their `recv_window`. The offset starts at zero and is measured
+/// by `General::sync_clock`. Clones share the same offset, so every module built
+/// from one `Client` is corrected at once.
+#[derive(Clone, Debug, Default)]
+pub struct ServerClock {
+    /// Milliseconds to add to the local clock to get the server time.
+    offset: Arc<AtomicI64>,
+}
+
+impl ServerClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Milliseconds the server clock is ahead of the local one, negative if behind.
+    pub fn offset(&self) -> i64 {
+        self.offset.load(Ordering::Relaxed)
+    }
+
+    pub fn set_offset(&self, offset: i64) {
+        self.offset.store(offset, Ordering::Relaxed);
+    }
+
+    /// The estimated server time in milliseconds since the Unix epoch.
+    pub fn now(&self) -> u64 {
+        get_timestamp().saturating_add_signed(self.offset())
+    }
+}
+
+/// One server time query, timed with the local clock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockSample {
+    /// Local time in microseconds at which the request was sent.
+    pub sent: u64,
+    /// Local time in microseconds at which the response arrived.
+    pub received: u64,
+    /// The server time in nanoseconds, from `timeNano`.
+    pub server_nanos: u64,
+}
+
+impl ClockSample {
+    /// Round-trip time in microseconds.
+    pub fn round_trip(&self) -> u64 {
+        self.received.saturating_sub(self.sent)
+    }
+
+    /// Milliseconds to add to the local clock to get the server time, assuming the
+    /// server read its clock halfway through the round trip.
+    pub fn offset(&self) -> i64 {
+        let midpoint = (self.sent as i128 + self.received as i128) / 2;
+        let server = self.server_nanos as i128 / 1_000;
+        ((server - midpoint) as f64 / 1_000.0).round() as i64
+    }
+}
+
+/// The local time in microseconds since the Unix epoch.
+pub(crate) fn local_micros() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_micros() as u64
+}
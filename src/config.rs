@@ -1,7 +1,9 @@
+use std::borrow::Cow;
+
 #[derive(Clone, Debug)]
 pub struct Config {
-    pub rest_api_endpoint: &'static str,
-    pub ws_endpoint: &'static str,
+    pub rest_api_endpoint: Cow<'static, str>,
+    pub ws_endpoint: Cow<'static, str>,
     pub recv_window: u16,
 }
 
@@ -11,23 +13,37 @@ impl Config {
 
     pub const fn default() -> Self {
         Self {
-            rest_api_endpoint: Self::DEFAULT_REST_API_ENDPOINT,
-            ws_endpoint: Self::DEFAULT_WS_ENDPOINT,
+            rest_api_endpoint: Cow::Borrowed(Self::DEFAULT_REST_API_ENDPOINT),
+            ws_endpoint: Cow::Borrowed(Self::DEFAULT_WS_ENDPOINT),
             recv_window: 5000,
         }
     }
 
     pub const fn testnet() -> Self {
         Self {
-            rest_api_endpoint: "https://api-testnet.bybit.com",
-            ws_endpoint: "wss://stream-testnet.bybit.com/v5",
+            rest_api_endpoint: Cow::Borrowed("https://api-testnet.bybit.com"),
+            ws_endpoint: Cow::Borrowed("wss://stream-testnet.bybit.com/v5"),
             recv_window: 5000,
         }
     }
 
-    pub const fn set_recv_window(self, recv_window: u16) -> Self {
+    pub const fn set_recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+
+    /// Points the REST clients at `endpoint`, such as a proxy or a local mock.
+    pub fn set_rest_api_endpoint(self, endpoint: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            rest_api_endpoint: endpoint.into(),
+            ..self
+        }
+    }
+
+    /// Points websocket streams at `endpoint`, such as a proxy or a local mock.
+    pub fn set_ws_endpoint(self, endpoint: impl Into<Cow<'static, str>>) -> Self {
         Self {
-            recv_window,
+            ws_endpoint: endpoint.into(),
             ..self
         }
     }
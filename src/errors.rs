@@ -5,14 +5,376 @@ use serde::Deserialize;
 use std::fmt;
 use thiserror::Error;
 /// BybitContentError is a struct that represents the error returned by the Bybit API.
-/// It has two fields: code, which is an i16 representing the error code, and msg, which is a String
+/// It has two fields: code, which is an i32 holding the `retCode`, and msg, which is a String
 /// representing the error message.
 #[derive(Debug, Deserialize)]
 pub struct BybitContentError {
-    pub code: i16,
+    #[serde(alias = "retCode")]
+    pub code: i32,
+    #[serde(alias = "retMsg")]
     pub msg: String,
 }
 
+impl BybitContentError {
+    /// The `retCode` as a `BybitErrorCode`.
+    pub fn error_code(&self) -> BybitErrorCode {
+        BybitErrorCode::from_code(self.code)
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.error_code().is_retryable()
+    }
+}
+
+/// A documented V5 `retCode`, as found in `BybitContentError.code`.
+///
+/// Codes the crate does not know are kept as `Other`, so no code is lost.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BybitErrorCode {
+    /// `10000`: Server timeout, the request may or may not have been processed.
+    ServerTimeout,
+    /// `10001`: Request parameter error.
+    ParamsError,
+    /// `10002`: Request timestamp outside of `recv_window`.
+    InvalidTimestamp,
+    /// `10003`: API key is invalid.
+    InvalidApiKey,
+    /// `10004`: Signature does not match.
+    InvalidSignature,
+    /// `10005`: API key permissions do not allow the request.
+    PermissionDenied,
+    /// `10006`: Too many requests to the endpoint.
+    TooManyVisits,
+    /// `10007`: User authentication failed.
+    AuthenticationFailed,
+    /// `10008`: The user is banned.
+    UserBanned,
+    /// `10009`: The IP address is banned.
+    IpBanned,
+    /// `10010`: The IP address is not bound to the API key.
+    UnmatchedIp,
+    /// `10014`: Duplicate request.
+    DuplicateRequest,
+    /// `10016`: Internal server error.
+    ServerError,
+    /// `10017`: Route not found.
+    RouteNotFound,
+    /// `10018`: Too many requests from the IP address.
+    IpRateLimit,
+    /// `10024`: Compliance rules were triggered.
+    ComplianceRestricted,
+    /// `10027`: Trading is banned for the user.
+    TradingBanned,
+    /// `10029`: The symbol is not in the API key's whitelist.
+    SymbolNotWhitelisted,
+    /// `110001`: The order does not exist.
+    OrderNotFound,
+    /// `110003`: The order price is outside the allowed range.
+    PriceOutOfRange,
+    /// `110004`: Insufficient wallet balance.
+    InsufficientWalletBalance,
+    /// `110005`: The position status does not allow the request.
+    PositionStatusAbnormal,
+    /// `110006`: Estimated assets are insufficient.
+    InsufficientAssets,
+    /// `110007`: Insufficient available balance.
+    InsufficientAvailableBalance,
+    /// `110008`: The order was already filled or cancelled.
+    OrderFinished,
+    /// `110009`: Too many conditional orders.
+    TooManyStopOrders,
+    /// `110010`: The order was already cancelled.
+    OrderCancelled,
+    /// `110011`: The order would trigger liquidation.
+    WouldTriggerLiquidation,
+    /// `110012`: Insufficient available balance for the order.
+    InsufficientOrderBalance,
+    /// `110013`: The leverage is not allowed by the risk limit.
+    LeverageRiskLimit,
+    /// `110014`: Insufficient balance to add margin.
+    InsufficientMarginToAdd,
+    /// `110015`: The position is in cross margin mode.
+    CrossMarginMode,
+    /// `110017`: The reduce-only rule is not satisfied.
+    ReduceOnlyViolated,
+    /// `110020`: Too many active orders.
+    TooManyActiveOrders,
+    /// `110021`: The open interest limit was exceeded.
+    OpenInterestExceeded,
+    /// `110022`: The quantity is restricted.
+    QtyRestricted,
+    /// `110023`: Only reducing orders are allowed.
+    ReduceOnlyRestricted,
+    /// `110024`: A position exists, the position mode cannot be switched.
+    PositionExists,
+    /// `110025`: The position mode is already set.
+    PositionModeNotModified,
+    /// `110026`: The margin mode is already set.
+    MarginModeNotModified,
+    /// `110028`: Open orders exist, the position mode cannot be switched.
+    OpenOrdersExist,
+    /// `110043`: The leverage is already set.
+    LeverageNotModified,
+    /// `110044`: Insufficient available margin.
+    InsufficientAvailableMargin,
+    /// `110045`: Insufficient wallet balance for the margin.
+    InsufficientWalletMargin,
+    /// `110061`: Too many take profit and stop loss orders.
+    TooManyTpSlOrders,
+    /// `110072`: The `orderLinkId` is already used.
+    DuplicateOrderLinkId,
+    /// `110094`: The order value is below the lower limit.
+    OrderValueTooLow,
+    /// `131212`: Insufficient balance for the transfer.
+    AssetInsufficientBalance,
+    /// `170121`: Invalid symbol.
+    InvalidSymbol,
+    /// `170131`: Insufficient spot balance.
+    SpotInsufficientBalance,
+    /// `170134`: The order price has too many decimals.
+    SpotPriceTooPrecise,
+    /// `170136`: The order quantity is above the upper limit.
+    SpotQtyTooHigh,
+    /// `170137`: The order quantity has too many decimals.
+    SpotQtyTooPrecise,
+    /// `170140`: The order value is below the lower limit.
+    SpotValueTooLow,
+    /// `170141`: The `orderLinkId` is already used.
+    SpotDuplicateOrderLinkId,
+    /// `170213`: The spot order does not exist.
+    SpotOrderNotFound,
+    /// Any other `retCode`.
+    Other(i32),
+}
+
+/// The kind of problem a `BybitErrorCode` reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BybitErrorCategory {
+    /// Invalid credentials, signature, permissions or banned accounts.
+    Auth,
+    /// Too many requests, see `RateLimiter`.
+    RateLimit,
+    /// Malformed or stale requests, such as bad parameters or timestamps.
+    InvalidRequest,
+    /// Transient failures on Bybit's side.
+    Server,
+    /// Not enough balance or margin for the request.
+    InsufficientBalance,
+    /// The order was refused or cannot be amended or cancelled.
+    OrderRejected,
+    /// The request conflicts with the account's one-way or hedge position mode.
+    PositionModeMismatch,
+    /// Leverage, margin mode or position status does not allow the request.
+    Position,
+    /// A code the crate does not know.
+    Unknown,
+}
+
+impl BybitErrorCode {
+    pub fn from_code(code: i32) -> Self {
+        match code {
+            10000 => BybitErrorCode::ServerTimeout,
+            10001 => BybitErrorCode::ParamsError,
+            10002 => BybitErrorCode::InvalidTimestamp,
+            10003 => BybitErrorCode::InvalidApiKey,
+            10004 => BybitErrorCode::InvalidSignature,
+            10005 => BybitErrorCode::PermissionDenied,
+            10006 => BybitErrorCode::TooManyVisits,
+            10007 => BybitErrorCode::AuthenticationFailed,
+            10008 => BybitErrorCode::UserBanned,
+            10009 => BybitErrorCode::IpBanned,
+            10010 => BybitErrorCode::UnmatchedIp,
+            10014 => BybitErrorCode::DuplicateRequest,
+            10016 => BybitErrorCode::ServerError,
+            10017 => BybitErrorCode::RouteNotFound,
+            10018 => BybitErrorCode::IpRateLimit,
+            10024 => BybitErrorCode::ComplianceRestricted,
+            10027 => BybitErrorCode::TradingBanned,
+            10029 => BybitErrorCode::SymbolNotWhitelisted,
+            110001 => BybitErrorCode::OrderNotFound,
+            110003 => BybitErrorCode::PriceOutOfRange,
+            110004 => BybitErrorCode::InsufficientWalletBalance,
+            110005 => BybitErrorCode::PositionStatusAbnormal,
+            110006 => BybitErrorCode::InsufficientAssets,
+            110007 => BybitErrorCode::InsufficientAvailableBalance,
+            110008 => BybitErrorCode::OrderFinished,
+            110009 => BybitErrorCode::TooManyStopOrders,
+            110010 => BybitErrorCode::OrderCancelled,
+            110011 => BybitErrorCode::WouldTriggerLiquidation,
+            110012 => BybitErrorCode::InsufficientOrderBalance,
+            110013 => BybitErrorCode::LeverageRiskLimit,
+            110014 => BybitErrorCode::InsufficientMarginToAdd,
+            110015 => BybitErrorCode::CrossMarginMode,
+            110017 => BybitErrorCode::ReduceOnlyViolated,
+            110020 => BybitErrorCode::TooManyActiveOrders,
+            110021 => BybitErrorCode::OpenInterestExceeded,
+            110022 => BybitErrorCode::QtyRestricted,
+            110023 => BybitErrorCode::ReduceOnlyRestricted,
+            110024 => BybitErrorCode::PositionExists,
+            110025 => BybitErrorCode::PositionModeNotModified,
+            110026 => BybitErrorCode::MarginModeNotModified,
+            110028 => BybitErrorCode::OpenOrdersExist,
+            110043 => BybitErrorCode::LeverageNotModified,
+            110044 => BybitErrorCode::InsufficientAvailableMargin,
+            110045 => BybitErrorCode::InsufficientWalletMargin,
+            110061 => BybitErrorCode::TooManyTpSlOrders,
+            110072 => BybitErrorCode::DuplicateOrderLinkId,
+            110094 => BybitErrorCode::OrderValueTooLow,
+            131212 => BybitErrorCode::AssetInsufficientBalance,
+            170121 => BybitErrorCode::InvalidSymbol,
+            170131 => BybitErrorCode::SpotInsufficientBalance,
+            170134 => BybitErrorCode::SpotPriceTooPrecise,
+            170136 => BybitErrorCode::SpotQtyTooHigh,
+            170137 => BybitErrorCode::SpotQtyTooPrecise,
+            170140 => BybitErrorCode::SpotValueTooLow,
+            170141 => BybitErrorCode::SpotDuplicateOrderLinkId,
+            170213 => BybitErrorCode::SpotOrderNotFound,
+            code => BybitErrorCode::Other(code),
+        }
+    }
+
+    /// The numeric `retCode`.
+    pub fn code(&self) -> i32 {
+        match self {
+            BybitErrorCode::ServerTimeout => 10000,
+            BybitErrorCode::ParamsError => 10001,
+            BybitErrorCode::InvalidTimestamp => 10002,
+            BybitErrorCode::InvalidApiKey => 10003,
+            BybitErrorCode::InvalidSignature => 10004,
+            BybitErrorCode::PermissionDenied => 10005,
+            BybitErrorCode::TooManyVisits => 10006,
+            BybitErrorCode::AuthenticationFailed => 10007,
+            BybitErrorCode::UserBanned => 10008,
+            BybitErrorCode::IpBanned => 10009,
+            BybitErrorCode::UnmatchedIp => 10010,
+            BybitErrorCode::DuplicateRequest => 10014,
+            BybitErrorCode::ServerError => 10016,
+            BybitErrorCode::RouteNotFound => 10017,
+            BybitErrorCode::IpRateLimit => 10018,
+            BybitErrorCode::ComplianceRestricted => 10024,
+            BybitErrorCode::TradingBanned => 10027,
+            BybitErrorCode::SymbolNotWhitelisted => 10029,
+            BybitErrorCode::OrderNotFound => 110001,
+            BybitErrorCode::PriceOutOfRange => 110003,
+            BybitErrorCode::InsufficientWalletBalance => 110004,
+            BybitErrorCode::PositionStatusAbnormal => 110005,
+            BybitErrorCode::InsufficientAssets => 110006,
+            BybitErrorCode::InsufficientAvailableBalance => 110007,
+            BybitErrorCode::OrderFinished => 110008,
+            BybitErrorCode::TooManyStopOrders => 110009,
+            BybitErrorCode::OrderCancelled => 110010,
+            BybitErrorCode::WouldTriggerLiquidation => 110011,
+            BybitErrorCode::InsufficientOrderBalance => 110012,
+            BybitErrorCode::LeverageRiskLimit => 110013,
+            BybitErrorCode::InsufficientMarginToAdd => 110014,
+            BybitErrorCode::CrossMarginMode => 110015,
+            BybitErrorCode::ReduceOnlyViolated => 110017,
+            BybitErrorCode::TooManyActiveOrders => 110020,
+            BybitErrorCode::OpenInterestExceeded => 110021,
+            BybitErrorCode::QtyRestricted => 110022,
+            BybitErrorCode::ReduceOnlyRestricted => 110023,
+            BybitErrorCode::PositionExists => 110024,
+            BybitErrorCode::PositionModeNotModified => 110025,
+            BybitErrorCode::MarginModeNotModified => 110026,
+            BybitErrorCode::OpenOrdersExist => 110028,
+            BybitErrorCode::LeverageNotModified => 110043,
+            BybitErrorCode::InsufficientAvailableMargin => 110044,
+            BybitErrorCode::InsufficientWalletMargin => 110045,
+            BybitErrorCode::TooManyTpSlOrders => 110061,
+            BybitErrorCode::DuplicateOrderLinkId => 110072,
+            BybitErrorCode::OrderValueTooLow => 110094,
+            BybitErrorCode::AssetInsufficientBalance => 131212,
+            BybitErrorCode::InvalidSymbol => 170121,
+            BybitErrorCode::SpotInsufficientBalance => 170131,
+            BybitErrorCode::SpotPriceTooPrecise => 170134,
+            BybitErrorCode::SpotQtyTooHigh => 170136,
+            BybitErrorCode::SpotQtyTooPrecise => 170137,
+            BybitErrorCode::SpotValueTooLow => 170140,
+            BybitErrorCode::SpotDuplicateOrderLinkId => 170141,
+            BybitErrorCode::SpotOrderNotFound => 170213,
+            BybitErrorCode::Other(code) => *code,
+        }
+    }
+
+    pub fn category(&self) -> BybitErrorCategory {
+        use BybitErrorCode::*;
+        match self {
+            InvalidApiKey | InvalidSignature | PermissionDenied
+            | AuthenticationFailed | UserBanned | IpBanned | UnmatchedIp
+            | ComplianceRestricted | TradingBanned => BybitErrorCategory::Auth,
+            TooManyVisits | IpRateLimit => BybitErrorCategory::RateLimit,
+            ParamsError | InvalidTimestamp | DuplicateRequest | RouteNotFound
+            | SymbolNotWhitelisted | InvalidSymbol => BybitErrorCategory::InvalidRequest,
+            ServerTimeout | ServerError => BybitErrorCategory::Server,
+            InsufficientWalletBalance
+            | InsufficientAssets
+            | InsufficientAvailableBalance
+            | InsufficientOrderBalance
+            | InsufficientMarginToAdd
+            | InsufficientAvailableMargin
+            | InsufficientWalletMargin
+            | AssetInsufficientBalance
+            | SpotInsufficientBalance => BybitErrorCategory::InsufficientBalance,
+            OrderNotFound
+            | PriceOutOfRange
+            | OrderFinished
+            | TooManyStopOrders
+            | OrderCancelled
+            | WouldTriggerLiquidation
+            | ReduceOnlyViolated
+            | TooManyActiveOrders
+            | OpenInterestExceeded
+            | QtyRestricted
+            | ReduceOnlyRestricted
+            | TooManyTpSlOrders
+            | DuplicateOrderLinkId
+            | OrderValueTooLow
+            | SpotPriceTooPrecise
+            | SpotQtyTooHigh
+            | SpotQtyTooPrecise
+            | SpotValueTooLow
+            | SpotDuplicateOrderLinkId
+            | SpotOrderNotFound => BybitErrorCategory::OrderRejected,
+            PositionExists | PositionModeNotModified | OpenOrdersExist => {
+                BybitErrorCategory::PositionModeMismatch
+            }
+            PositionStatusAbnormal
+            | LeverageRiskLimit
+            | CrossMarginMode
+            | MarginModeNotModified
+            | LeverageNotModified => BybitErrorCategory::Position,
+            Other(_) => BybitErrorCategory::Unknown,
+        }
+    }
+
+    /// Returns `true` if the same request may succeed when sent again: rate limits
+    /// and transient server errors.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.category(),
+            BybitErrorCategory::RateLimit | BybitErrorCategory::Server
+        )
+    }
+}
+
+impl From<i32> for BybitErrorCode {
+    fn from(code: i32) -> Self {
+        BybitErrorCode::from_code(code)
+    }
+}
+
+/// How a `Transport` failed to get a response, which tells whether the request can
+/// have reached Bybit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransportErrorKind {
+    /// No connection could be established, so the request was never sent.
+    Connect,
+    /// No response arrived in time. The request may have been processed.
+    Timeout,
+    /// Any other failure, e.g. a connection dropped while reading the response.
+    Other,
+}
+
 /// BybitError is an enum that can hold any possible error that can occur during the execution of the program.
 /// It has several variants, each representing a different type of error.
 #[derive(Debug, Error)]
@@ -27,6 +389,70 @@ pub enum BybitError {
     #[error("Invalid Vec for Kline: {name} at {index} is missing")]
     KlineValueMissingError { index: usize, name: &'static str },
 
+    /// OrderBookSequenceGap variant that holds the symbol and the update ids involved.
+    /// This variant is used when a local order book misses a delta and has to be resynced.
+    #[error(
+        "Order book gap for {symbol}: expected update {expected}, received {received}"
+    )]
+    OrderBookSequenceGap {
+        symbol: String,
+        expected: u64,
+        received: u64,
+    },
+
+    /// TradeRequestRejected variant that holds the retCode and retMsg of a rejected trade stream request.
+    #[error("Trade request rejected with code {code}: {msg}")]
+    TradeRequestRejected { code: i64, msg: String },
+
+    /// RequestTimeout variant used when no response with the given reqId arrived in time.
+    #[error("Request {req_id} timed out")]
+    RequestTimeout { req_id: String },
+
+    /// HeartbeatTimeout variant used when a websocket stopped answering pings and is considered dead.
+    #[error("Connection is dead: {missed} pongs missed")]
+    HeartbeatTimeout { missed: u32 },
+
+    /// DuplicateSubscription variant that holds a topic which is already subscribed or requested twice.
+    #[error("Already subscribed to {0}")]
+    DuplicateSubscription(String),
+
+    /// NotSubscribed variant that holds a topic that cannot be unsubscribed because it is not active.
+    #[error("Not subscribed to {0}")]
+    NotSubscribed(String),
+
+    /// SubscriptionLimitExceeded variant used when a subscription would exceed Bybit's limits.
+    #[error("Subscription limit exceeded: {0}")]
+    SubscriptionLimitExceeded(String),
+
+    /// SubscriptionRejected variant that holds the message of a subscription the server refused.
+    #[error("Subscription rejected: {0}")]
+    SubscriptionRejected(String),
+
+    /// AuthFailed variant that holds the reason the server refused a websocket authentication.
+    #[error("Authentication failed: {0}")]
+    AuthFailed(String),
+
+    /// MissingTickerSnapshot variant that holds the symbol of a ticker delta received before any snapshot.
+    #[error("No ticker snapshot for {0}")]
+    MissingTickerSnapshot(String),
+
+    /// Deserialize variant that holds the path of the response field that could not be parsed,
+    /// e.g. `result.list[0].markPrice`, along with the serde_json error and the response body.
+    #[error("Failed to deserialize `{path}`: {source} with body: {body}")]
+    Deserialize {
+        path: String,
+        source: serde_json::Error,
+        body: String,
+    },
+
+    /// Transport variant for a REST request that got no response, whichever `Transport`
+    /// sent it.
+    #[error("Transport error ({kind:?}): {message}")]
+    Transport {
+        kind: TransportErrorKind,
+        message: String,
+    },
+
     /// Variants that hold the error returned by reqwest, serde_json, tokio_tungstenite, and std libraries.
     /// These variants are used when the respective library returns an error.
     #[error(transparent)]
@@ -47,8 +473,10 @@ pub enum BybitError {
     #[error(transparent)]
     Json(#[from] serde_json::Error),
 
+    /// Boxed because the websocket error would otherwise make every `BybitError`
+    /// several times larger than its other variants.
     #[error(transparent)]
-    Tungstenite(#[from] tokio_tungstenite::tungstenite::Error),
+    Tungstenite(Box<tokio_tungstenite::tungstenite::Error>),
 
     #[error(transparent)]
     TimestampError(#[from] std::time::SystemTimeError),
@@ -66,6 +494,10 @@ pub enum BybitError {
     #[error("Unauthorized")]
     Unauthorized,
 
+    /// ChannelClosed variant used when the receiving side of an event channel was dropped.
+    #[error("Channel receiver was dropped")]
+    ChannelClosed,
+
     /// StatusCode variant that holds the status code.
     #[error("Status Code {0}")]
     StatusCode(u16),
@@ -100,8 +532,67 @@ impl From<std::string::String> for BybitError {
     }
 }
 
+// Implement the From trait for tungstenite's Error and BybitError.
+// The error is boxed to keep `BybitError` small.
+impl From<tokio_tungstenite::tungstenite::Error> for BybitError {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        BybitError::Tungstenite(Box::new(err))
+    }
+}
+
+// Implement the From trait for tokio's SendError and BybitError.
+// Sending fails only when the receiver has been dropped, so the value is discarded.
+impl<T> From<tokio::sync::mpsc::error::SendError<T>> for BybitError {
+    fn from(_: tokio::sync::mpsc::error::SendError<T>) -> Self {
+        BybitError::ChannelClosed
+    }
+}
+
 impl BybitError {
     fn new(arg: String) -> Self {
         BybitError::Base(arg)
     }
+
+    /// The `retCode` Bybit answered with, for errors reported by the API.
+    pub fn error_code(&self) -> Option<BybitErrorCode> {
+        match self {
+            BybitError::BybitError(content) => Some(content.error_code()),
+            BybitError::TradeRequestRejected { code, .. } => {
+                i32::try_from(*code).ok().map(BybitErrorCode::from_code)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the request may succeed when sent again: connection
+    /// failures, timeouts, 5xx and 429 statuses, and retryable retCodes such as
+    /// `10006` (too many visits) and `10016` (server error).
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BybitError::ReqError(err) => err.is_connect() || err.is_timeout(),
+            BybitError::Transport { kind, .. } => {
+                matches!(
+                    kind,
+                    TransportErrorKind::Connect | TransportErrorKind::Timeout
+                )
+            }
+            BybitError::InternalServerError | BybitError::ServiceUnavailable => true,
+            BybitError::StatusCode(status) => matches!(status, 429 | 502 | 504),
+            _ => self.error_code().is_some_and(|code| code.is_retryable()),
+        }
+    }
+
+    /// Returns `true` if the failed request cannot have taken effect, so that even
+    /// a non-idempotent request can be sent again: the connection could not be
+    /// established, or Bybit rejected the request for exceeding the rate limit.
+    pub fn is_safe_to_resend(&self) -> bool {
+        match self {
+            BybitError::ReqError(err) => err.is_connect(),
+            BybitError::Transport { kind, .. } => *kind == TransportErrorKind::Connect,
+            BybitError::BybitError(content) => {
+                content.error_code() == BybitErrorCode::TooManyVisits
+            }
+            _ => false,
+        }
+    }
 }
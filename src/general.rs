@@ -1,7 +1,14 @@
 use crate::api::{Market, API};
 use crate::client::Client;
+use crate::clock::{local_micros, ClockSample};
 use crate::errors::BybitError;
 use crate::model::ServerTimeResponse;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Duration};
+
+/// Number of server time queries made by `General::sync_clock`. The one with the
+/// shortest round trip gives the estimate.
+const CLOCK_SYNC_SAMPLES: usize = 5;
 
 #[derive(Clone)]
 pub struct General {
@@ -39,4 +46,56 @@ impl General {
         // Return the ServerTime struct
         Ok(response)
     }
+
+    /// Queries the server time once, timing the round trip with the local clock.
+    pub async fn sample_clock(&self) -> Result<ClockSample, BybitError> {
+        let sent = local_micros();
+        let response = self.get_server_time().await?;
+        let received = local_micros();
+        Ok(ClockSample {
+            sent,
+            received,
+            server_nanos: response.result.time_nano,
+        })
+    }
+
+    /// Measures the offset between the local and the server clock and applies it
+    /// to the `ServerClock` of the client, so signed requests of every module
+    /// sharing it are stamped with the server time.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new offset in milliseconds, positive if the server clock is ahead.
+    pub async fn sync_clock(&self) -> Result<i64, BybitError> {
+        let mut best: Option<ClockSample> = None;
+        for _ in 0..CLOCK_SYNC_SAMPLES {
+            let sample = self.sample_clock().await?;
+            if best.is_none_or(|best| sample.round_trip() < best.round_trip()) {
+                best = Some(sample);
+            }
+        }
+        // The loop runs at least once, so a sample was taken
+        let offset = best.map_or(0, |sample| sample.offset());
+        self.client.clock.set_offset(offset);
+        Ok(offset)
+    }
+
+    /// Spawns a task that calls `sync_clock` now and then every `interval`,
+    /// logging failures and keeping the last offset until the next success.
+    ///
+    /// The task runs until the returned handle is aborted.
+    pub fn spawn_clock_sync(&self, interval: Duration) -> JoinHandle<()> {
+        let general = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match general.sync_clock().await {
+                    Ok(offset) => tracing::debug!("Server clock offset is {}ms", offset),
+                    Err(err) => {
+                        tracing::warn!("Failed to sync the server clock: {}", err)
+                    }
+                }
+                sleep(interval).await;
+            }
+        })
+    }
 }
@@ -1,16 +1,37 @@
+//! Bybit V5 API bindings.
+//!
+//! # Features
+//!
+//! - `decimal`: prices, quantities and balances are `rust_decimal::Decimal`s instead
+//!   of `f64`s, see [`model::Amount`]. Cargo unifies features across the whole build,
+//!   so if any crate in the dependency graph enables `decimal`, the public `Amount`
+//!   fields change type for every other crate using bybit as well. Code that has to
+//!   build either way should go through `Amount` rather than naming `f64`.
+//! - `mock`: in-process REST and websocket servers in `mock` for offline tests.
 #![deny(unused_crate_dependencies)]
 
+// Only there to turn on the `mock` feature for the crate's own tests.
+#[cfg(test)]
+use bybit as _;
+
 pub mod account;
 pub mod api;
 pub mod asset;
+pub mod channel;
 pub mod client;
+pub mod clock;
 pub mod config;
 pub mod errors;
 pub mod general;
 pub mod market;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod model;
 pub mod position;
+pub mod rate_limit;
+pub mod replay;
 pub mod trade;
+pub mod transport;
 pub mod util;
 pub mod ws;
 
@@ -107,6 +128,12 @@ pub mod test_utils {
         Some(json.secret)
     }
 
+    /// Parses a decimal literal into an [`Amount`](crate::model::Amount), so the same
+    /// test compiles with and without the `decimal` feature.
+    pub fn amount(s: &str) -> crate::model::Amount {
+        s.parse().unwrap()
+    }
+
     #[macro_export]
     macro_rules! enable_tracing {
         () => {
@@ -5,7 +5,7 @@ use crate::model::{
     Category, DeliveryPriceResponse, FundingHistoryRequest, FundingRateResponse,
     FuturesInstrumentsInfoResponse, FuturesTickersResponse, HistoricalVolatilityRequest,
     HistoricalVolatilityResponse, IndexPriceKlineResponse, InstrumentRequest, InsuranceResponse,
-    KlineRequest, KlineResponse, LongShortRatioResponse, MarkPriceKlineResponse,
+    KlineRequest, KlineResponse, LocalOrderBook, LongShortRatioResponse, MarkPriceKlineResponse,
     OpenInterestRequest, OpeninterestResponse, OptionsInstrument, OrderBookResponse,
     OrderbookRequest, PremiumIndexPriceKlineResponse, RecentTradesRequest, RecentTradesResponse,
     RiskLimitRequest, RiskLimitResponse, SpotInstrumentsInfoResponse, SpotTickersResponse,
@@ -399,6 +399,23 @@ impl MarketData {
         Ok(response)
     }
 
+    /// Resyncs a `LocalOrderBook` from the REST order book.
+    ///
+    /// Use this after `LocalOrderBook::apply_update` reports a sequence gap instead of
+    /// waiting for the next websocket snapshot. `limit` should match the depth of the
+    /// websocket topic feeding the book.
+    pub async fn resync_order_book(
+        &self,
+        book: &mut LocalOrderBook,
+        category: Category,
+        limit: Option<u64>,
+    ) -> Result<(), BybitError> {
+        let request = OrderbookRequest::new(&book.symbol, category, limit);
+        let response = self.get_depth(request).await?;
+        book.apply_snapshot(&response.result);
+        Ok(())
+    }
+
     /// Asynchronously retrieves spot tickers based on the provided symbol.
     ///
     /// # Arguments
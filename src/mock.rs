@@ -0,0 +1,1766 @@
+use crate::{
+    api::API,
+    config::Config,
+    errors::BybitError,
+    util::{
+        generate_random_uid,
+        get_timestamp,
+    },
+};
+use futures::{
+    SinkExt,
+    StreamExt,
+};
+use hex::encode as hex_encode;
+use hmac::{
+    Hmac,
+    Mac,
+};
+use serde_json::{
+    json,
+    Value,
+};
+use sha2::Sha256;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    net::SocketAddr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+use tokio::{
+    io::{
+        AsyncBufReadExt,
+        AsyncReadExt,
+        AsyncWriteExt,
+        BufReader,
+    },
+    net::{
+        TcpListener,
+        TcpStream,
+    },
+    sync::{
+        mpsc,
+        Notify,
+    },
+    task::{
+        JoinHandle,
+        JoinSet,
+    },
+    time::Duration,
+};
+use tokio_tungstenite::tungstenite::{
+    handshake::server::{
+        Request,
+        Response,
+    },
+    Message as WsMessage,
+};
+
+/// In-process websocket server that speaks enough of the Bybit V5 protocol to run
+/// `Stream` offline.
+///
+/// It answers auth requests, subscribe and unsubscribe requests, pings and
+/// trade-stream orders, and pushes scripted frames to the connections subscribed to
+/// a topic. Point `Config.ws_endpoint` at it with `MockWsServer::config`.
+///
+/// # Example
+///
+/// ```no_run
+/// use bybit::{api::Bybit, mock::MockWsServer, ws::Stream};
+/// use serde_json::json;
+///
+/// # async fn run() -> Result<(), bybit::errors::BybitError> {
+/// let server = MockWsServer::start().await?;
+/// let ws: Stream = Bybit::new_with_config(&server.config(), None, None);
+/// // ... subscribe to "orderbook.1.BTCUSDT" with `ws`, then:
+/// server.wait_subscribed("orderbook.1.BTCUSDT").await;
+/// server.push(
+///     "orderbook.1.BTCUSDT",
+///     "snapshot",
+///     json!({"s": "BTCUSDT", "b": [["60000", "1"]], "a": [["60001", "1"]], "u": 1, "seq": 1}),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockWsServer {
+    endpoint: String,
+    state: Arc<MockState>,
+    task: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct MockState {
+    inner: Mutex<MockInner>,
+    changed: Notify,
+}
+
+#[derive(Default)]
+struct MockInner {
+    reject_auth: Option<String>,
+    reject_orders: Option<(i64, String)>,
+    rejected_topics: HashSet<String>,
+    muted: HashMap<String, usize>,
+    delays: HashMap<String, Duration>,
+    next_id: u64,
+    connections: Vec<MockConnection>,
+    requests: Vec<Value>,
+}
+
+struct MockConnection {
+    id: u64,
+    path: String,
+    authed: bool,
+    topics: HashSet<String>,
+    sender: mpsc::UnboundedSender<Outbound>,
+}
+
+enum Outbound {
+    Frame(String),
+    Close,
+}
+
+impl MockWsServer {
+    /// Binds to a random local port and starts accepting connections.
+    pub async fn start() -> Result<Self, BybitError> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(MockState::default());
+        let task = tokio::spawn(Self::accept_loop(listener, state.clone()));
+        Ok(Self {
+            endpoint: format!("ws://{}", addr),
+            state,
+            task,
+        })
+    }
+
+    /// Websocket base URL of the server, to be used as `Config.ws_endpoint`.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Default config with `ws_endpoint` pointing at this server.
+    pub fn config(&self) -> Config {
+        Config::default().set_ws_endpoint(self.endpoint.clone())
+    }
+
+    /// Makes every following auth request fail with `reason`.
+    pub fn reject_auth(&self, reason: &str) {
+        self.state.inner.lock().unwrap().reject_auth = Some(reason.to_string());
+    }
+
+    /// Makes every following trade-stream order fail with `code` and `msg`. `None`
+    /// accepts orders again.
+    pub fn reject_orders(&self, rejection: Option<(i64, &str)>) {
+        self.state.inner.lock().unwrap().reject_orders =
+            rejection.map(|(code, msg)| (code, msg.to_string()));
+    }
+
+    /// Makes every following subscribe request that includes `topic` fail, as the
+    /// exchange does for unknown symbols.
+    pub fn reject_topic(&self, topic: &str) {
+        let mut inner = self.state.inner.lock().unwrap();
+        inner.rejected_topics.insert(topic.to_string());
+    }
+
+    /// Answers the next `after` requests with this `op`, such as `ping` or
+    /// `order.create`, then leaves the following ones unanswered.
+    pub fn mute(&self, op: &str, after: usize) {
+        self.state
+            .inner
+            .lock()
+            .unwrap()
+            .muted
+            .insert(op.to_string(), after);
+    }
+
+    /// Holds every following reply to `op` back for `delay`, letting later replies
+    /// overtake it.
+    pub fn delay(&self, op: &str, delay: Duration) {
+        self.state
+            .inner
+            .lock()
+            .unwrap()
+            .delays
+            .insert(op.to_string(), delay);
+    }
+
+    /// Every request received so far, in order, across all connections.
+    pub fn requests(&self) -> Vec<Value> {
+        self.state.inner.lock().unwrap().requests.clone()
+    }
+
+    /// Number of open connections.
+    pub fn connections(&self) -> usize {
+        self.state.inner.lock().unwrap().connections.len()
+    }
+
+    /// Waits until a connection is subscribed to `topic`.
+    pub async fn wait_subscribed(&self, topic: &str) {
+        loop {
+            let changed = self.state.changed.notified();
+            if self.subscribers(topic) > 0 {
+                return;
+            }
+            changed.await;
+        }
+    }
+
+    /// Number of connections subscribed to `topic`.
+    pub fn subscribers(&self, topic: &str) -> usize {
+        let inner = self.state.inner.lock().unwrap();
+        inner
+            .connections
+            .iter()
+            .filter(|connection| connection.topics.contains(topic))
+            .count()
+    }
+
+    /// Pushes a public frame with the given `type` (`snapshot` or `delta`) and
+    /// `data` to the subscribers of `topic`, such as `orderbook.50.BTCUSDT` or
+    /// `publicTrade.BTCUSDT`. Returns the number of connections it was sent to.
+    pub fn push(&self, topic: &str, event_type: &str, data: Value) -> usize {
+        let ts = get_timestamp();
+        let frame = json!({
+            "topic": topic,
+            "type": event_type,
+            "ts": ts,
+            "cts": ts,
+            "cs": ts,
+            "data": data,
+        });
+        self.push_raw(topic, &frame.to_string())
+    }
+
+    /// Pushes a private frame to the subscribers of `topic`, such as `order` or
+    /// `execution`. `data` is the array of updates.
+    pub fn push_private(&self, topic: &str, data: Value) -> usize {
+        let frame = json!({
+            "id": generate_random_uid(8),
+            "topic": topic,
+            "creationTime": get_timestamp(),
+            "data": data,
+        });
+        self.push_raw(topic, &frame.to_string())
+    }
+
+    /// Sends `frame` unchanged to the subscribers of `topic`.
+    pub fn push_raw(&self, topic: &str, frame: &str) -> usize {
+        let inner = self.state.inner.lock().unwrap();
+        inner
+            .connections
+            .iter()
+            .filter(|connection| connection.topics.contains(topic))
+            .filter(|connection| {
+                connection
+                    .sender
+                    .send(Outbound::Frame(frame.to_string()))
+                    .is_ok()
+            })
+            .count()
+    }
+
+    /// Closes every open connection, as the exchange does during maintenance.
+    pub fn disconnect_all(&self) {
+        let inner = self.state.inner.lock().unwrap();
+        for connection in &inner.connections {
+            let _ = connection.sender.send(Outbound::Close);
+        }
+    }
+
+    async fn accept_loop(listener: TcpListener, state: Arc<MockState>) {
+        let mut connections = JoinSet::new();
+        while let Ok((socket, addr)) = listener.accept().await {
+            connections.spawn(Self::serve(socket, addr, state.clone()));
+        }
+    }
+
+    async fn serve(socket: TcpStream, addr: SocketAddr, state: Arc<MockState>) {
+        let mut path = String::new();
+        // The error type is the HTTP response tungstenite's handshake callback requires.
+        #[allow(clippy::result_large_err)]
+        let callback = |request: &Request, response: Response| {
+            path = request.uri().path().to_string();
+            Ok(response)
+        };
+        let Ok(mut ws) = tokio_tungstenite::accept_hdr_async(socket, callback).await
+        else {
+            tracing::warn!("Mock handshake with {} failed", addr);
+            return;
+        };
+        let (sender, mut outbound) = mpsc::unbounded_channel();
+        let id = {
+            let mut inner = state.inner.lock().unwrap();
+            inner.next_id += 1;
+            let id = inner.next_id;
+            inner.connections.push(MockConnection {
+                id,
+                path: path.trim_start_matches("/v5").to_string(),
+                authed: false,
+                topics: HashSet::new(),
+                sender,
+            });
+            id
+        };
+        state.changed.notify_waiters();
+        loop {
+            tokio::select! {
+                msg = ws.next() => match msg {
+                    Some(Ok(WsMessage::Text(msg))) => {
+                        let Some(reply) = state.reply(id, &msg) else {
+                            continue;
+                        };
+                        state.changed.notify_waiters();
+                        if ws.send(WsMessage::Text(reply)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(_)) => {}
+                    _ => break,
+                },
+                outbound = outbound.recv() => match outbound {
+                    Some(Outbound::Frame(frame)) => {
+                        if ws.send(WsMessage::Text(frame)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Outbound::Close) | None => {
+                        let _ = ws.close(None).await;
+                        break;
+                    }
+                },
+            }
+        }
+        state
+            .inner
+            .lock()
+            .unwrap()
+            .connections
+            .retain(|connection| connection.id != id);
+        state.changed.notify_waiters();
+    }
+}
+
+impl MockState {
+    /// Builds the reply to a client request, updating the connection's state.
+    fn reply(&self, id: u64, msg: &str) -> Option<String> {
+        let request: Value = serde_json::from_str(msg).ok()?;
+        let mut inner = self.inner.lock().unwrap();
+        inner.requests.push(request.clone());
+        let op = request["op"].as_str().unwrap_or_default();
+        if let Some(remaining) = inner.muted.get_mut(op) {
+            if *remaining == 0 {
+                return None;
+            }
+            *remaining -= 1;
+        }
+        let delay = inner.delays.get(op).copied();
+        let rejected = request["args"].as_array().is_some_and(|args| {
+            args.iter()
+                .filter_map(Value::as_str)
+                .any(|topic| inner.rejected_topics.contains(topic))
+        });
+        let reject_auth = inner.reject_auth.clone();
+        let reject_orders = inner.reject_orders.clone();
+        let connection = inner.connections.iter_mut().find(|c| c.id == id)?;
+        let conn_id = format!("mock-{}", id);
+        let trade_stream = connection.path == "/trade";
+        let reply = match op {
+            "auth" => {
+                connection.authed = reject_auth.is_none();
+                let reason = reject_auth.unwrap_or_default();
+                if trade_stream {
+                    json!({
+                        "retCode": if connection.authed { 0 } else { 10004 },
+                        "retMsg": if connection.authed { "OK" } else { reason.as_str() },
+                        "op": "auth",
+                        "connId": conn_id,
+                    })
+                } else {
+                    json!({
+                        "success": connection.authed,
+                        "ret_msg": reason,
+                        "op": "auth",
+                        "conn_id": conn_id,
+                    })
+                }
+            }
+            "subscribe" | "unsubscribe" => {
+                let topics: Vec<String> = request["args"]
+                    .as_array()
+                    .map(|args| {
+                        args.iter()
+                            .filter_map(|arg| arg.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let allowed = connection.path != "/private" || connection.authed;
+                let allowed = allowed && !(op == "subscribe" && rejected);
+                if allowed {
+                    for topic in &topics {
+                        if op == "subscribe" {
+                            connection.topics.insert(topic.clone());
+                        } else {
+                            connection.topics.remove(topic);
+                        }
+                    }
+                }
+                if connection.path == "/public/option" {
+                    json!({
+                        "success": allowed,
+                        "conn_id": conn_id,
+                        "data": {"failTopics": [], "successTopics": topics},
+                        "type": "COMMAND_RESP",
+                    })
+                } else {
+                    json!({
+                        "success": allowed,
+                        "ret_msg": match (allowed, rejected) {
+                            (true, _) => "",
+                            (false, true) => "error:handler not found",
+                            (false, false) => "Request not authorized",
+                        },
+                        "conn_id": conn_id,
+                        "req_id": request["req_id"],
+                        "op": op,
+                    })
+                }
+            }
+            "ping" if trade_stream => json!({
+                "reqId": request["reqId"],
+                "retCode": 0,
+                "retMsg": "OK",
+                "op": "pong",
+                "data": [get_timestamp().to_string()],
+                "connId": conn_id,
+            }),
+            "ping" if connection.path == "/private" => json!({
+                "req_id": request["req_id"],
+                "op": "pong",
+                "args": [get_timestamp().to_string()],
+                "conn_id": conn_id,
+            }),
+            "ping" => json!({
+                "success": true,
+                "ret_msg": "pong",
+                "conn_id": conn_id,
+                "req_id": request["req_id"],
+                "op": "ping",
+            }),
+            "order.create" | "order.amend" | "order.cancel" if trade_stream => {
+                let (code, msg) = reject_orders.unwrap_or((0, "OK".to_string()));
+                let order = &request["args"][0];
+                let data = if code == 0 {
+                    json!({
+                        "orderId": order["orderId"]
+                            .as_str()
+                            .map_or_else(|| generate_random_uid(16), String::from),
+                        "orderLinkId": order["orderLinkId"].as_str().unwrap_or_default(),
+                    })
+                } else {
+                    json!({})
+                };
+                json!({
+                    "reqId": request["reqId"],
+                    "retCode": code,
+                    "retMsg": msg,
+                    "op": op,
+                    "data": data,
+                    "header": {
+                        "X-Bapi-Limit": "10",
+                        "X-Bapi-Limit-Status": "9",
+                        "X-Bapi-Limit-Reset-Timestamp": (get_timestamp() + 1000).to_string(),
+                        "Traceid": generate_random_uid(16),
+                        "Timenow": get_timestamp().to_string(),
+                    },
+                    "connId": conn_id,
+                })
+            }
+            _ => return None,
+        };
+        let Some(delay) = delay else {
+            return Some(reply.to_string());
+        };
+        let sender = connection.sender.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let _ = sender.send(Outbound::Frame(reply.to_string()));
+        });
+        None
+    }
+}
+
+impl Drop for MockWsServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Scripted reply of a `MockRestServer` route.
+#[derive(Clone, Debug)]
+pub enum MockResponse {
+    /// `200 OK` with this JSON body.
+    Json(Value),
+    /// `200 OK` with the V5 envelope carrying this `retCode` and `retMsg`, such as
+    /// `10006` for rate limits.
+    RetCode(i32, String),
+    /// This HTTP status with an empty body.
+    Status(u16),
+    /// This HTTP status with a raw body, e.g. one that is not valid JSON.
+    Raw(u16, String),
+}
+
+impl MockResponse {
+    /// `200 OK` with a successful V5 envelope around `result`.
+    pub fn ok(result: Value) -> Self {
+        MockResponse::Json(envelope(0, "OK", result))
+    }
+}
+
+/// A request received by a `MockRestServer`.
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    pub method: String,
+    /// Path without the query string, with duplicate leading slashes removed.
+    pub path: String,
+    pub query: String,
+    /// Headers with lowercase names.
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+/// In-process HTTP server that stands in for the Bybit V5 REST API.
+///
+/// Every route of `API` answers with a canned V5 response modelled on the
+/// examples of the V5 docs, and any other path with a successful envelope around
+/// an empty `result`. Routes outside `/v5/market` must carry `X-BAPI-*` headers
+/// signed with the server's key and secret, the same way `Client` signs them, or
+/// they fail with retCode `10003` or `10004`.
+///
+/// Point `Config.rest_api_endpoint` at it with `MockRestServer::config`.
+///
+/// # Example
+///
+/// ```no_run
+/// use bybit::{
+///     api::{Bybit, Market, API},
+///     general::General,
+///     mock::{MockResponse, MockRestServer},
+/// };
+///
+/// # async fn run() -> Result<(), bybit::errors::BybitError> {
+/// let server = MockRestServer::start().await?;
+/// let general: General = Bybit::new_with_config(
+///     &server.config(),
+///     Some(server.api_key().to_string()),
+///     Some(server.secret().to_string()),
+/// );
+/// server.respond_once(API::Market(Market::Time), MockResponse::Status(503));
+/// assert!(general.get_server_time().await.is_err());
+/// assert!(general.get_server_time().await.is_ok());
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockRestServer {
+    endpoint: String,
+    api_key: String,
+    secret: String,
+    state: Arc<Mutex<RestState>>,
+    task: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct RestState {
+    scripted: HashMap<String, VecDeque<MockResponse>>,
+    routes: HashMap<String, MockResponse>,
+    limits: HashMap<String, MockLimit>,
+    requests: Vec<MockRequest>,
+    /// Milliseconds the server clock is ahead of the local one.
+    clock_skew: i64,
+}
+
+impl RestState {
+    fn now(&self) -> u64 {
+        get_timestamp().saturating_add_signed(self.clock_skew)
+    }
+}
+
+/// Fixed-window quota of a route, reported through `X-Bapi-Limit-*` headers.
+struct MockLimit {
+    limit: u32,
+    window: u64,
+    used: u32,
+    reset_at: u64,
+}
+
+impl MockRestServer {
+    pub const DEFAULT_API_KEY: &'static str = "mock-api-key";
+    pub const DEFAULT_SECRET: &'static str = "mock-secret";
+
+    /// Starts a server that accepts `DEFAULT_API_KEY` and `DEFAULT_SECRET`.
+    pub async fn start() -> Result<Self, BybitError> {
+        Self::start_with_keys(Self::DEFAULT_API_KEY, Self::DEFAULT_SECRET).await
+    }
+
+    /// Starts a server that checks signatures against `api_key` and `secret`.
+    pub async fn start_with_keys(
+        api_key: &str,
+        secret: &str,
+    ) -> Result<Self, BybitError> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(RestState::default()));
+        let credentials = (api_key.to_string(), secret.to_string());
+        let task = tokio::spawn(Self::accept_loop(listener, state.clone(), credentials));
+        Ok(Self {
+            endpoint: format!("http://{}", addr),
+            api_key: api_key.to_string(),
+            secret: secret.to_string(),
+            state,
+            task,
+        })
+    }
+
+    /// Base URL of the server, to be used as `Config.rest_api_endpoint`.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Default config with `rest_api_endpoint` pointing at this server.
+    pub fn config(&self) -> Config {
+        Config::default().set_rest_api_endpoint(self.endpoint.clone())
+    }
+
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// Answers every following request to `route` with `response`.
+    pub fn respond(&self, route: API, response: MockResponse) {
+        let path = normalize_path(route.as_ref());
+        self.state.lock().unwrap().routes.insert(path, response);
+    }
+
+    /// Answers the next request to `route` with `response`. Queued responses are
+    /// used in order before falling back to `respond` or the canned response.
+    pub fn respond_once(&self, route: API, response: MockResponse) {
+        let path = normalize_path(route.as_ref());
+        let mut state = self.state.lock().unwrap();
+        state.scripted.entry(path).or_default().push_back(response);
+    }
+
+    /// Every request received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Limits `route` to `limit` requests per `window`. Responses then carry the
+    /// `X-Bapi-Limit-*` headers, and requests over the quota fail with retCode
+    /// `10006`.
+    pub fn set_rate_limit(&self, route: API, limit: u32, window: Duration) {
+        let path = normalize_path(route.as_ref());
+        let limit = MockLimit {
+            limit,
+            window: window.as_millis() as u64,
+            used: 0,
+            reset_at: 0,
+        };
+        self.state.lock().unwrap().limits.insert(path, limit);
+    }
+
+    /// Runs the server clock `skew` milliseconds ahead of the local one, or behind
+    /// if negative. Both the server time and the timestamp check of signed
+    /// requests follow it.
+    pub fn set_clock_skew(&self, skew: i64) {
+        self.state.lock().unwrap().clock_skew = skew;
+    }
+
+    /// Number of requests received for `route`.
+    pub fn hits(&self, route: API) -> usize {
+        let path = normalize_path(route.as_ref());
+        let state = self.state.lock().unwrap();
+        state.requests.iter().filter(|r| r.path == path).count()
+    }
+
+    async fn accept_loop(
+        listener: TcpListener,
+        state: Arc<Mutex<RestState>>,
+        credentials: (String, String),
+    ) {
+        let credentials = Arc::new(credentials);
+        let mut connections = JoinSet::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            connections.spawn(Self::serve(socket, state.clone(), credentials.clone()));
+        }
+    }
+
+    async fn serve(
+        socket: TcpStream,
+        state: Arc<Mutex<RestState>>,
+        credentials: Arc<(String, String)>,
+    ) {
+        let mut socket = BufReader::new(socket);
+        while let Ok(Some(request)) = read_request(&mut socket).await {
+            let (response, limit_headers) = {
+                let mut state = state.lock().unwrap();
+                state.requests.push(request.clone());
+                let (exceeded, limit_headers) = Self::consume_limit(&mut state, &request);
+                let response = if exceeded {
+                    MockResponse::RetCode(10006, "Too many visits!".into())
+                } else {
+                    Self::route(&mut state, &request, &credentials.0, &credentials.1)
+                };
+                (response, limit_headers)
+            };
+            let (status, body) = match response {
+                MockResponse::Json(body) => (200, body.to_string()),
+                MockResponse::RetCode(code, msg) => {
+                    (200, envelope(code, &msg, json!({})).to_string())
+                }
+                MockResponse::Status(status) => (status, String::new()),
+                MockResponse::Raw(status, body) => (status, body),
+            };
+            let head = format!(
+                "HTTP/1.1 {} {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n{}\r\n",
+                status,
+                reason(status),
+                body.len(),
+                limit_headers
+            );
+            let socket = socket.get_mut();
+            if socket.write_all(head.as_bytes()).await.is_err()
+                || socket.write_all(body.as_bytes()).await.is_err()
+            {
+                break;
+            }
+        }
+    }
+
+    /// Counts `request` against its route's quota. Returns whether the quota was
+    /// exceeded and the header lines reporting it.
+    fn consume_limit(state: &mut RestState, request: &MockRequest) -> (bool, String) {
+        let Some(limit) = state.limits.get_mut(&request.path) else {
+            return (false, String::new());
+        };
+        let now = get_timestamp();
+        if now >= limit.reset_at {
+            limit.used = 0;
+            limit.reset_at = now + limit.window;
+        }
+        limit.used += 1;
+        let headers = format!(
+            "x-bapi-limit: {}\r\nx-bapi-limit-status: {}\r\nx-bapi-limit-reset-timestamp: {}\r\n",
+            limit.limit,
+            limit.limit.saturating_sub(limit.used),
+            limit.reset_at
+        );
+        (limit.used > limit.limit, headers)
+    }
+
+    fn route(
+        state: &mut RestState,
+        request: &MockRequest,
+        api_key: &str,
+        secret: &str,
+    ) -> MockResponse {
+        let now = state.now();
+        if !request.path.starts_with("/v5/market/") {
+            if let Err(rejection) = check_signature(request, api_key, secret, now) {
+                return rejection;
+            }
+        }
+        if let Some(response) = state
+            .scripted
+            .get_mut(&request.path)
+            .and_then(VecDeque::pop_front)
+        {
+            return response;
+        }
+        if let Some(response) = state.routes.get(&request.path) {
+            return response.clone();
+        }
+        canned(&request.path, &request.query, now)
+    }
+}
+
+impl Drop for MockRestServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Verifies the `X-BAPI-*` headers the way Bybit does: an HMAC-SHA256 of
+/// `timestamp + api_key + recv_window + payload`, where the payload is the query
+/// string of a GET and the body of a POST, and a timestamp no older than
+/// `recv_window` and no more than a second ahead of the server time `now`.
+fn check_signature(
+    request: &MockRequest,
+    api_key: &str,
+    secret: &str,
+    now: u64,
+) -> Result<(), MockResponse> {
+    let header = |name: &str| request.headers.get(name).map(String::as_str);
+    if header("x-bapi-api-key") != Some(api_key) {
+        return Err(MockResponse::RetCode(10003, "API key is invalid.".into()));
+    }
+    let timestamp = header("x-bapi-timestamp").unwrap_or_default();
+    let recv_window = header("x-bapi-recv-window").unwrap_or_default();
+    let payload = if request.method == "GET" {
+        &request.query
+    } else {
+        &request.body
+    };
+    let origin = format!("{}{}{}{}", timestamp, api_key, recv_window, payload);
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(origin.as_bytes());
+    if header("x-bapi-sign") != Some(hex_encode(mac.finalize().into_bytes()).as_str()) {
+        return Err(MockResponse::RetCode(
+            10004,
+            format!("error sign! origin_string[{}]", origin),
+        ));
+    }
+    let (Ok(sent), Ok(window)) = (timestamp.parse::<u64>(), recv_window.parse::<u64>())
+    else {
+        return Err(MockResponse::RetCode(10001, "params error!".into()));
+    };
+    if sent + window < now || sent >= now + 1000 {
+        return Err(MockResponse::RetCode(
+            10002,
+            format!(
+                "invalid request, please check your server timestamp or recv_window \
+                 param. req_timestamp[{}],server_timestamp[{}],recv_window[{}]",
+                sent, now, window
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn envelope(code: i32, msg: &str, result: Value) -> Value {
+    json!({
+        "retCode": code,
+        "retMsg": msg,
+        "result": result,
+        "retExtInfo": {},
+        "time": get_timestamp(),
+    })
+}
+
+fn normalize_path(path: &str) -> String {
+    format!("/{}", path.trim_start_matches('/'))
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
+
+/// Reads one HTTP/1.1 request, or `None` once the client closed the connection.
+async fn read_request(
+    socket: &mut BufReader<TcpStream>,
+) -> Result<Option<MockRequest>, BybitError> {
+    let mut line = String::new();
+    if socket.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default();
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let (path, query) = (normalize_path(path), query.to_string());
+    let mut headers = HashMap::new();
+    loop {
+        line.clear();
+        if socket.read_line(&mut line).await? == 0 {
+            return Ok(None);
+        }
+        let Some((name, value)) = line.trim_end().split_once(':') else {
+            break;
+        };
+        headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+    }
+    let length = headers
+        .get("content-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    socket.read_exact(&mut body).await?;
+    Ok(Some(MockRequest {
+        method,
+        path,
+        query,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    }))
+}
+
+/// The order listed by the canned `/v5/order/realtime` response, the one placed by
+/// the canned `/v5/order/create` response.
+const OPEN_ORDER: &str = r#"{
+    "orderId": "1321003749386327552", "orderLinkId": "mock-order", "blockTradeId": "",
+    "symbol": "BTCUSDT", "price": "60000", "qty": "0.01", "side": "Buy",
+    "isLeverage": "", "positionIdx": 0, "orderStatus": "New", "cancelType": "UNKNOWN",
+    "rejectReason": "EC_NoError", "avgPrice": "0", "leavesQty": "0.01",
+    "leavesValue": "600", "cumExecQty": "0", "cumExecValue": "0", "cumExecFee": "0",
+    "timeInForce": "GTC", "orderType": "Limit", "stopOrderType": "", "orderIv": "",
+    "triggerPrice": "0", "takeProfit": "0", "stopLoss": "0", "tpTriggerBy": "",
+    "slTriggerBy": "", "triggerDirection": 0, "triggerBy": "",
+    "lastPriceOnCreated": "60000", "reduceOnly": false, "closeOnTrigger": false,
+    "smpType": "None", "smpGroup": 0, "smpOrderId": "", "tpslMode": "",
+    "tpLimitPrice": "0", "slLimitPrice": "0", "placeType": "",
+    "createdTime": "1684738540559", "updatedTime": "1684738540561"
+}"#;
+
+/// Canned response of a route: a successful envelope around `canned_result`.
+fn canned(path: &str, query: &str, now: u64) -> MockResponse {
+    let mut body = envelope(0, "OK", canned_result(path, query, now));
+    match path {
+        // The one route that answers with `category` next to a `result` array.
+        "/v5/market/historical-volatility" => body["category"] = json!("option"),
+        // Batch routes report the outcome of each order in `retExtInfo`.
+        "/v5/order/create-batch" | "/v5/order/amend-batch" | "/v5/order/cancel-batch" => {
+            body["retExtInfo"] = json!({ "list": [{ "code": 0, "msg": "OK" }] })
+        }
+        _ => {}
+    }
+    MockResponse::Json(body)
+}
+
+/// Value of the parameter `name` in a query string.
+fn query_param<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find_map(|(key, value)| (key == name).then_some(value))
+}
+
+/// Canned `result` of a route, modelled on the examples of the V5 docs.
+fn canned_result(path: &str, query: &str, now: u64) -> Value {
+    let spot =
+        query_param(query, "category").is_some_and(|c| c.eq_ignore_ascii_case("spot"));
+    match path {
+        "/v5/market/time" => json!({
+            "timeSecond": (now / 1000).to_string(),
+            "timeNano": (now * 1_000_000).to_string(),
+        }),
+        "/v5/market/kline" => json!({
+            "symbol": "BTCUSDT",
+            "category": "linear",
+            "list": [
+                ["1670608800000", "17071", "17073", "17027", "17055.5", "268611", "15.74462667"],
+                ["1670605200000", "17071.5", "17071.5", "17061", "17071", "4177", "0.24469757"],
+            ],
+        }),
+        "/v5/market/mark-price-kline"
+        | "/v5/market/index-price-kline"
+        | "/v5/market/premium-index-price-kline" => json!({
+            "symbol": "BTCUSDT",
+            "category": "linear",
+            "list": [
+                ["1670608800000", "17164.16", "17164.16", "17121.5", "17131.64"],
+                ["1670605200000", "17161.4", "17168.07", "17156.5", "17164.16"],
+            ],
+        }),
+        "/v5/market/instruments-info" if spot => json!({
+            "category": "spot",
+            "list": [{
+                "symbol": "BTCUSDT",
+                "baseCoin": "BTC",
+                "quoteCoin": "USDT",
+                "innovation": "0",
+                "status": "Trading",
+                "marginTrading": "both",
+                "lotSizeFilter": {
+                    "basePrecision": "0.000001",
+                    "quotePrecision": "0.00000001",
+                    "minOrderQty": "0.000048",
+                    "maxOrderQty": "71.73956243",
+                    "minOrderAmt": "1",
+                    "maxOrderAmt": "2000000",
+                },
+                "priceFilter": { "tickSize": "0.01" },
+                "riskParameters": { "limitParameter": "0.05", "marketParameter": "0.05" },
+            }],
+            "nextPageCursor": "",
+        }),
+        "/v5/market/instruments-info" => json!({
+            "category": "linear",
+            "list": [{
+                "symbol": "BTCUSDT",
+                "contractType": "LinearPerpetual",
+                "status": "Trading",
+                "baseCoin": "BTC",
+                "quoteCoin": "USDT",
+                "launchTime": "1585526400000",
+                "deliveryTime": "0",
+                "deliveryFeeRate": "",
+                "priceScale": "2",
+                "leverageFilter": {
+                    "minLeverage": "1",
+                    "maxLeverage": "100.00",
+                    "leverageStep": "0.01",
+                },
+                "priceFilter": { "minPrice": "0.10", "maxPrice": "199999.80", "tickSize": "0.10" },
+                "lotSizeFilter": {
+                    "maxOrderQty": "100.000",
+                    "maxMktOrderQty": "100.000",
+                    "minOrderQty": "0.001",
+                    "qtyStep": "0.001",
+                    "postOnlyMaxOrderQty": "1000.000",
+                    "minNotionalValue": "5",
+                },
+                "unifiedMarginTrade": true,
+                "fundingInterval": 480,
+                "settleCoin": "USDT",
+                "copyTrading": "both",
+                "upperFundingRate": "0.00375",
+                "lowerFundingRate": "-0.00375",
+            }],
+            "nextPageCursor": "",
+        }),
+        "/v5/market/orderbook" => json!({
+            "s": "BTCUSDT",
+            "b": [["65485.47", "47.081829"], ["65485.46", "0.2"]],
+            "a": [["65485.48", "1.520189"], ["65485.49", "0.3"]],
+            "ts": now,
+            "u": 5277055,
+            "seq": 7961638724u64,
+            "cts": now,
+        }),
+        "/v5/market/tickers" if spot => json!({
+            "category": "spot",
+            "list": [{
+                "symbol": "BTCUSDT",
+                "bid1Price": "65485.47",
+                "bid1Size": "47.081829",
+                "ask1Price": "65485.48",
+                "ask1Size": "1.520189",
+                "lastPrice": "65485.48",
+                "prevPrice24h": "64900.00",
+                "price24hPcnt": "0.009021",
+                "highPrice24h": "65800.00",
+                "lowPrice24h": "64500.00",
+                "turnover24h": "2500000000.00",
+                "volume24h": "38500.123",
+                "usdIndexPrice": "65480.00",
+            }],
+        }),
+        "/v5/market/tickers" => json!({
+            "category": "linear",
+            "list": [{
+                "symbol": "BTCUSDT",
+                "lastPrice": "65485.48",
+                "indexPrice": "65480.00",
+                "markPrice": "65483.12",
+                "prevPrice24h": "64900.00",
+                "price24hPcnt": "0.009021",
+                "highPrice24h": "65800.00",
+                "lowPrice24h": "64500.00",
+                "prevPrice1h": "65400.00",
+                "openInterest": "51000.123",
+                "openInterestValue": "3339660000.00",
+                "turnover24h": "2500000000.00",
+                "volume24h": "38500.123",
+                "fundingRate": "0.0001",
+                "nextFundingTime": "1700000000000",
+                "predictedDeliveryPrice": "",
+                "basisRate": "",
+                "deliveryFeeRate": "",
+                "deliveryTime": "0",
+                "ask1Size": "1.52",
+                "bid1Price": "65485.47",
+                "ask1Price": "65485.48",
+                "bid1Size": "47.08",
+                "basis": "",
+            }],
+        }),
+        "/v5/market/funding/history" => json!({
+            "category": "linear",
+            "list": [{
+                "symbol": "BTCUSDT",
+                "fundingRate": "0.0001",
+                "fundingRateTimestamp": "1700006400000",
+            }],
+        }),
+        "/v5/market/recent-trade" => json!({
+            "category": "linear",
+            "list": [{
+                "execId": "2100000000007764263",
+                "symbol": "BTCUSDT",
+                "price": "65485.48",
+                "size": "0.001",
+                "side": "Buy",
+                "time": "1700006400000",
+                "isBlockTrade": false,
+            }],
+        }),
+        "/v5/market/open-interest" => json!({
+            "symbol": "BTCUSDT",
+            "category": "linear",
+            "list": [{ "openInterest": "51000.123", "timestamp": "1700006400000" }],
+            "nextPageCursor": "",
+        }),
+        "/v5/market/historical-volatility" => json!([{
+            "period": 7,
+            "value": "0.27545620",
+            "time": "1700006400000",
+        }]),
+        "/v5/market/insurance" => json!({
+            "updatedTime": "1700006400000",
+            "list": [{ "coin": "BTC", "balance": "6590.41", "value": "431577430.57" }],
+        }),
+        "/v5/market/risk-limit" => json!({
+            "category": "linear",
+            "list": [{
+                "id": 1,
+                "symbol": "BTCUSDT",
+                "riskLimitValue": "2000000",
+                "maintenanceMargin": "0.005",
+                "initialMargin": "0.01",
+                "isLowestRisk": 1,
+                "maxLeverage": "100.00",
+            }],
+        }),
+        "/v5/market/delivery-price" => json!({
+            "category": "option",
+            "nextPageCursor": "",
+            "list": [{
+                "symbol": "BTC-27DEC24-70000-C",
+                "deliveryPrice": "94123.45",
+                "deliveryTime": "1735286400000",
+            }],
+        }),
+        "/v5/market/account-ratio" => json!({
+            "list": [{
+                "symbol": "BTCUSDT",
+                "buyRatio": "0.5245",
+                "sellRatio": "0.4755",
+                "timestamp": "1700006400000",
+            }],
+        }),
+        "/v5/order/create" | "/v5/order/amend" | "/v5/order/cancel" => json!({
+            "orderId": "1321003749386327552",
+            "orderLinkId": "mock-order",
+        }),
+        "/v5/order/realtime" | "/v5/order/history" => json!({
+            "category": "linear",
+            "list": [serde_json::from_str::<Value>(OPEN_ORDER).unwrap()],
+            "nextPageCursor": "",
+        }),
+        "/v5/order/cancel-all" => json!({
+            "list": [{ "orderId": "1321003749386327552", "orderLinkId": "mock-order" }],
+        }),
+        "/v5/execution/list" => json!({
+            "nextPageCursor": "",
+            "category": "linear",
+            "list": [{
+                "symbol": "BTCUSDT",
+                "orderType": "Limit",
+                "underlyingPrice": "",
+                "orderLinkId": "mock-order",
+                "side": "Buy",
+                "indexPrice": "",
+                "orderId": "1321003749386327552",
+                "stopOrderType": "",
+                "leavesQty": "0",
+                "execTime": "1684738540561",
+                "feeCurrency": "",
+                "isMaker": true,
+                "execFee": "0.12",
+                "feeRate": "0.0002",
+                "execId": "2100000000007764263",
+                "tradeIv": "",
+                "blockTradeId": "",
+                "markPrice": "60010.00",
+                "execPrice": "60000",
+                "markIv": "",
+                "orderQty": "0.01",
+                "orderPrice": "60000",
+                "execValue": "600",
+                "execType": "Trade",
+                "execQty": "0.01",
+                "closedSize": "",
+                "seq": 7961638724u64,
+            }],
+        }),
+        "/v5/order/create-batch" | "/v5/order/amend-batch" | "/v5/order/cancel-batch" => {
+            json!({
+                "list": [{
+                    "category": "linear",
+                    "symbol": "BTCUSDT",
+                    "orderId": "1321003749386327552",
+                    "orderLinkId": "mock-order",
+                    "createAt": "1684738540559",
+                }],
+            })
+        }
+        "/v5/order/spot-borrow-check" => json!({
+            "symbol": "BTCUSDT",
+            "side": "Buy",
+            "maxTradeQty": "6.6065",
+            "maxTradeAmount": "218986.2",
+            "spotMaxTradeQty": "0",
+            "spotMaxTradeAmount": "0",
+            "borrowCoin": "USDT",
+        }),
+        "/v5/order/disconnected-cancel-all" => json!({ "success": true }),
+        "/v5/position/list" => json!({
+            "list": [{
+                "positionIdx": 0,
+                "riskId": 1,
+                "riskLimitValue": "2000000",
+                "symbol": "BTCUSDT",
+                "side": "Buy",
+                "size": "0.01",
+                "avgPrice": "60000",
+                "positionValue": "600",
+                "tradeMode": 0,
+                "positionStatus": "Normal",
+                "autoAddMargin": 0,
+                "adlRankIndicator": 2,
+                "leverage": "10",
+                "positionBalance": "60",
+                "markPrice": "65483.12",
+                "liqPrice": "54321.00",
+                "bustPrice": "54000.00",
+                "positionMM": "3.3",
+                "positionIM": "60.36",
+                "tpslMode": "Full",
+                "takeProfit": "0",
+                "stopLoss": "0",
+                "trailingStop": "0",
+                "unrealisedPnl": "54.83",
+                "cumRealisedPnl": "-0.36",
+                "seq": 7961638724u64,
+                "isReduceOnly": false,
+                "mmrSysUpdateTime": "",
+                "leverageSysUpdatedTime": "",
+                "createdTime": "1684738540559",
+                "updatedTime": "1684738540561",
+            }],
+            "nextPageCursor": "",
+            "category": "linear",
+        }),
+        "/v5/position/set-risk-limit" => json!({
+            "riskId": 1,
+            "riskLimitValue": "2000000",
+            "category": "linear",
+        }),
+        "/v5/position/add-margin" => json!({
+            "category": "linear",
+            "symbol": "BTCUSDT",
+            "positionIdx": 0,
+            "riskId": 1,
+            "riskLimitValue": "2000000",
+            "size": "0.01",
+            "positionValue": "600",
+            "avgPrice": "60000",
+            "liqPrice": "53321.00",
+            "bustPrice": "53000.00",
+            "markPrice": "65483.12",
+            "leverage": "10",
+            "autoAddMargin": 0,
+            "positionStatus": "Normal",
+            "positionIM": "70.36",
+            "positionMM": "3.3",
+            "unrealisedPnl": "54.83",
+            "cumRealisedPnl": "-0.36",
+            "stopLoss": "0.00",
+            "takeProfit": "0.00",
+            "trailingStop": "0.00",
+            "createdTime": "1684738540559",
+            "updatedTime": "1684738540561",
+        }),
+        "/v5/position/closed-pnl" => json!({
+            "nextPageCursor": "",
+            "category": "linear",
+            "list": [{
+                "symbol": "BTCUSDT",
+                "orderType": "Market",
+                "leverage": "10",
+                "updatedTime": "1684738540561",
+                "side": "Sell",
+                "orderId": "1321003749386327553",
+                "closedPnl": "49.6",
+                "avgEntryPrice": "60000",
+                "qty": "0.01",
+                "cumEntryValue": "600",
+                "createdTime": "1684738540559",
+                "orderPrice": "64000",
+                "closedSize": "0.01",
+                "avgExitPrice": "65000",
+                "execType": "Trade",
+                "fillCount": "1",
+                "cumExitValue": "650",
+            }],
+        }),
+        "/v5/position/move-positions" => json!({
+            "blockTradeId": "e9bb926c95f54cf1ba3e315a58b8597b",
+            "status": "Processing",
+            "rejectParty": "",
+        }),
+        "/v5/position/move-history" => json!({
+            "list": [{
+                "blockTradeId": "e9bb926c95f54cf1ba3e315a58b8597b",
+                "category": "linear",
+                "orderId": "1321003749386327554",
+                "userId": 1,
+                "symbol": "BTCUSDT",
+                "side": "Buy",
+                "price": "60000",
+                "qty": "0.01",
+                "execFee": "0",
+                "status": "Filled",
+                "execId": "2100000000007764264",
+                "resultCode": 0,
+                "resultMessage": "",
+                "createdAt": 1684738540559u64,
+                "updatedAt": 1684738540561u64,
+                "rejectParty": "",
+            }],
+            "nextPageCursor": "",
+        }),
+        "/v5/position/set-leverage"
+        | "/v5/position/switch-isolated"
+        | "/v5/position/switch-mode"
+        | "/v5/position/trading-stop"
+        | "/v5/position/set-auto-add-margin"
+        | "/v5/account/set-collateral-switch" => json!({}),
+        "/v5/account/wallet-balance" => json!({
+            "list": [{
+                "accountIMRate": "0.0106",
+                "accountMMRate": "0.0055",
+                "totalEquity": "5670.15",
+                "totalWalletBalance": "5615.32",
+                "totalMarginBalance": "5670.15",
+                "totalAvailableBalance": "5609.79",
+                "totalPerpUPL": "54.83",
+                "totalInitialMargin": "60.36",
+                "totalMaintenanceMargin": "3.3",
+                "coin": [{
+                    "coin": "USDT",
+                    "equity": "5670.15",
+                    "usdValue": "5670.72",
+                    "walletBalance": "5615.32",
+                    "availableToWithdraw": "5554.96",
+                    "availableToBorrow": "",
+                    "borrowAmount": "0",
+                    "accruedInterest": "0",
+                    "totalOrderIM": "0",
+                    "totalPositionIM": "60.36",
+                    "totalPositionMM": "3.3",
+                    "unrealisedPnl": "54.83",
+                    "cumRealisedPnl": "-0.36",
+                    "bonus": "0",
+                    "collateralSwitch": true,
+                    "marginCollateral": true,
+                    "locked": "0",
+                    "spotHedgingQty": "0",
+                }],
+                "accountLTV": "0",
+                "accountType": "UNIFIED",
+            }],
+        }),
+        "/v5/account/upgrade-to-uta" => json!({
+            "unifiedUpdateStatus": "SUCCESS",
+            "unifiedUpdateMsg": { "msg": [] },
+        }),
+        "/v5/account/borrow-history" => json!({
+            "nextPageCursor": "",
+            "rows": [{
+                "borrowAmount": "1.06333265702840778",
+                "costExemption": "0",
+                "freeBorrowedAmount": "0",
+                "createdTime": 1697439900204u64,
+                "InterestBearingBorrowSize": "1.06333265702840778",
+                "currency": "USDT",
+                "unrealisedLoss": "0",
+                "hourlyBorrowRate": "0.000001216904",
+                "borrowCost": "0.00000129",
+            }],
+        }),
+        "/v5/account/quick-repayment" => json!({
+            "list": [{ "coin": "USDT", "repaymentQty": "1.06333265" }],
+        }),
+        "/v5/account/set-collateral-switch-batch" => json!({
+            "list": [
+                { "coin": "BTC", "collateralSwitch": "ON" },
+                { "coin": "ETH", "collateralSwitch": "OFF" },
+            ],
+        }),
+        "/v5/account/collateral-info" => json!({
+            "list": [{
+                "availableToBorrow": "3",
+                "freeBorrowingAmount": "",
+                "freeBorrowAmount": "0",
+                "maxBorrowingAmount": "3",
+                "hourlyBorrowRate": "0.00000147",
+                "borrowUsageRate": "0.01",
+                "collateralSwitch": true,
+                "borrowAmount": "0.03",
+                "borrowable": true,
+                "currency": "BTC",
+                "marginCollateral": true,
+                "freeBorrowingLimit": "0",
+                "collateralRatio": "0.95",
+            }],
+        }),
+        "/v5/asset/coin-greeks" => json!({
+            "list": [{
+                "baseCoin": "BTC",
+                "totalDelta": "0.00004001",
+                "totalGamma": "-0.00000009",
+                "totalVega": "-0.00039689",
+                "totalTheta": "0.01243824",
+            }],
+        }),
+        "/v5/account/fee-rate" => json!({
+            "list": [{
+                "symbol": "BTCUSDT",
+                "makerFeeRate": "0.0002",
+                "takerFeeRate": "0.00055",
+            }],
+        }),
+        "/v5/account/info" => json!({
+            "marginMode": "REGULAR_MARGIN",
+            "updatedTime": "1697078946000",
+            "unifiedMarginStatus": 4,
+            "dcpStatus": "OFF",
+            "timeWindow": 10,
+            "smpGroup": 0,
+            "isMasterTrader": false,
+            "spotHedgingStatus": "OFF",
+        }),
+        "/v5/account/transaction-log" => json!({
+            "nextPageCursor": "",
+            "list": [{
+                "id": "592324_BTCUSDT_161749",
+                "symbol": "BTCUSDT",
+                "side": "Buy",
+                "funding": "",
+                "orderLinkId": "mock-order",
+                "orderId": "1321003749386327552",
+                "fee": "0.12",
+                "change": "-0.12",
+                "cashFlow": "0",
+                "transactionTime": "1684738540561",
+                "type": "TRADE",
+                "feeRate": "0.0002",
+                "bonusChange": "",
+                "size": "0.01",
+                "qty": "0.01",
+                "cashBalance": "5615.32",
+                "currency": "USDT",
+                "category": "linear",
+                "tradePrice": "60000",
+                "tradeId": "2100000000007764263",
+            }],
+        }),
+        "/v5/account/smp-group" => json!({ "smpGroup": 0 }),
+        // Sic: the path `Account::SetMarginMode` resolves to.
+        "/v5/aaccount/set-margin-mode" => json!({ "reason": [] }),
+        "/v5/account/set-hedging-mode" => json!({ "success": true }),
+        "/v5/asset/exchange/order-record" => json!({
+            "orderBody": [{
+                "fromCoin": "BTC",
+                "fromAmount": "0.100000000000000000",
+                "toCoin": "ETH",
+                "toAmount": "1.385866230000000000",
+                "exchangeRate": "13.858662380000000000",
+                "createdTime": "1597991055",
+                "exchangeTxId": "153008513145",
+            }],
+            "nextPageCursor": "",
+        }),
+        "/v5/asset/delivery-record" | "/v5/asset/settlement-record" => json!({
+            "category": "option",
+            "list": [{
+                "symbol": "BTC-27DEC24-70000-C",
+                "side": "Buy",
+                "deliveryTime": 1735286400000u64,
+                "strike": "70000",
+                "fee": "0.00000000",
+                "position": "0.01",
+                "deliveryPrice": "94123.45",
+                "deliveryRpl": "241.23",
+            }],
+            "nextPageCursor": "",
+        }),
+        "/v5/asset/transfer/query-asset-info" => json!({
+            "spot": {
+                "status": "ACCOUNT_STATUS_NORMAL",
+                "assets": [{ "coin": "USDT", "frozen": "0", "free": "5615.32", "withdraw": "" }],
+            },
+        }),
+        "/v5/asset/transfer/inter-transfer" | "/v5/asset/transfer/universal-transfer" => {
+            json!({ "transferId": "42c0cfb0-6bca-c242-bc76-4e6df6cbcb16" })
+        }
+        "/v5/asset/transfer/query-inter-transfer-list"
+        | "/v5/asset/transfer/query-universal-transfer-list" => json!({
+            "list": [{
+                "transferId": "42c0cfb0-6bca-c242-bc76-4e6df6cbcb16",
+                "coin": "USDT",
+                "amount": "100",
+                "fromAccountType": "UNIFIED",
+                "toAccountType": "FUND",
+                "timestamp": "1684738540561",
+                "status": "SUCCESS",
+            }],
+            "nextPageCursor": "",
+        }),
+        "/v5/asset/transfer/query-transfer-coin-list" => {
+            json!({ "list": ["BTC", "USDT"] })
+        }
+        "/v5/asset/transfer/query-sub-member-list" => json!({
+            "subMemberIds": ["554117", "592324"],
+            "transferableSubMemberIds": ["554117"],
+        }),
+        "/v5/asset/transfer/query-account-coins-balance" => json!({
+            "memberId": "1234567",
+            "accountType": "FUND",
+            "balance": [{
+                "coin": "USDT",
+                "walletBalance": "5615.32",
+                "transferBalance": "5615.32",
+                "bonus": "0",
+            }],
+        }),
+        "/v5/asset/deposit/query-allowed-list" => json!({
+            "configList": [{
+                "coin": "USDT",
+                "chain": "ETH",
+                "coinShowName": "USDT",
+                "chainType": "ERC20",
+                "blockConfirmNumber": 6,
+                "minDepositAmount": "0",
+            }],
+            "nextPageCursor": "",
+        }),
+        "/v5/asset/deposit/query-record" => json!({
+            "rows": [{
+                "coin": "USDT",
+                "chain": "ETH",
+                "amount": "100",
+                "txID": "0xc3f3a4e1b0f1d6b1e6e4a4c8d5c0e0a0b9f8e7d6c5b4a3f2e1d0c9b8a7f6e5d4",
+                "status": 3,
+                "toAddress": "0x5b9f4a0c3f3bcbc0d0bd1c0d4c0d0bd1c0d4c0d0",
+                "tag": "",
+                "depositFee": "",
+                "successAt": "1684738540561",
+                "confirmations": "64",
+                "txIndex": "",
+                "blockHash": "",
+                "batchReleaseLimit": "-1",
+                "depositType": "0",
+            }],
+            "nextPageCursor": "",
+        }),
+        "/v5/asset/coin/query-info" => json!({
+            "rows": [{
+                "name": "USDT",
+                "coin": "USDT",
+                "remainAmount": "150000",
+                "chains": [{
+                    "chainType": "ERC20",
+                    "confirmation": "6",
+                    "withdrawFee": "4",
+                    "depositMin": "0",
+                    "withdrawMin": "10",
+                    "chain": "ETH",
+                    "chainDeposit": "1",
+                    "chainWithdraw": "1",
+                    "minAccuracy": "4",
+                    "withdrawPercentageFee": "0",
+                }],
+            }],
+        }),
+        "/v5/asset/withdraw/create" => json!({ "id": "10195" }),
+        "/v5/asset/withdraw/cancel" => json!({ "status": 1 }),
+        "/v5/asset/deposit/query-sub-member-address" => json!({
+            "coin": "USDT",
+            "chains": {
+                "chainType": "ERC20",
+                "addressDeposit": "0x5b9f4a0c3f3bcbc0d0bd1c0d4c0d0bd1c0d4c0d0",
+                "tagDeposit": "",
+                "chain": "ETH",
+                "batchReleaseLimit": "-1",
+            },
+        }),
+        "/v5/asset/exchange/quote-apply" => json!({
+            "quoteTxId": "10100108106409343501030232064",
+            "exchangeRate": "0.0000150553",
+            "fromCoin": "USDT",
+            "fromCoinType": "crypto",
+            "toCoin": "BTC",
+            "toCoinType": "crypto",
+            "fromAmount": "100",
+            "toAmount": "0.00150553",
+            "expiredTime": "1684738555561",
+            "requestId": "",
+        }),
+        "/v5/asset/exchange/convert-execute" => json!({
+            "quoteTxId": "10100108106409343501030232064",
+            "exchangeStatus": "processing",
+        }),
+        "/v5/asset/exchange/query-coin-list" => json!({
+            "coins": [{
+                "coin": "BTC",
+                "fullName": "BTC",
+                "icon": "",
+                "iconNight": "",
+                "accuracyLength": 8,
+                "coinType": "crypto",
+                "balance": "0",
+                "uBalance": "0",
+                "singleFromMinLimit": "0.0001",
+                "singleFromMaxLimit": "5",
+                "disableFrom": false,
+                "disableTo": false,
+                "timePeriod": 0,
+                "singleToMinLimit": "0",
+                "singleToMaxLimit": "0",
+                "dailyFromMinLimit": "0",
+                "dailyFromMaxLimit": "0",
+                "dailyToMinLimit": "0",
+                "dailyToMaxLimit": "0",
+                "disableUser": false,
+                "disableFromReason": "",
+                "disableToReason": "",
+            }],
+        }),
+        "/v5/spot-lever-token/info" => json!({
+            "list": [{
+                "ltCoin": "BTC3L",
+                "ltName": "3X Long",
+                "maxPurchase": "200000",
+                "minPurchase": "50",
+                "maxPurchaseDaily": "10000000",
+                "maxRedeem": "200000",
+                "minRedeem": "50",
+                "maxRedeemDaily": "10000000",
+                "purchaseFeeRate": "0.0005",
+                "redeemFeeRate": "0.0005",
+                "ltStatus": "1",
+                "fundFee": "0",
+                "fundFeeTime": "1684800000000",
+                "manageFeeRate": "0.00005",
+                "manageFeeTime": "1684800000000",
+                "value": "1",
+                "netValue": "3.1421",
+                "total": "1000000",
+            }],
+        }),
+        "/v5/spot-lever-token/reference" => json!({
+            "ltCoin": "BTC3L",
+            "nav": "3.1421",
+            "navTime": "1684738540561",
+            "circulation": "1000000",
+            "basket": "0.0151",
+            "leverage": "3.01",
+        }),
+        "/v5/spot-lever-token/purchase" | "/v5/spot-lever-token/redeem" => json!({
+            "ltCoin": "BTC3L",
+            "ltOrderStatus": "1",
+            "execQty": "50",
+            "execAmt": "15.9",
+            "amount": "50",
+            "purchaseId": "2611",
+            "serialNo": "mock-serial",
+            "valueCoin": "USDT",
+        }),
+        "/v5/spot-lever-token/order-record" => json!({
+            "list": [{
+                "ltCoin": "BTC3L",
+                "orderId": "2611",
+                "ltOrderType": 1,
+                "orderTime": 1684738540561u64,
+                "updateTime": 1684738540561u64,
+                "ltOrderStatus": "1",
+                "fee": "0.025",
+                "amount": "50",
+                "value": "15.9",
+                "valueCoin": "USDT",
+                "serialNo": "mock-serial",
+            }],
+        }),
+        "/v5/spot-margin-trade/switch-mode" => json!({ "spotMarginMode": "1" }),
+        "/v5/spot-margin-trade/set-leverage" | "/v5/spot-cross-margin-trade/switch" => {
+            json!({ "switchStatus": 1 })
+        }
+        "/v5/spot-margin-trade/state" => json!({
+            "spotLeverage": "10",
+            "spotMarginMode": "1",
+            "effectiveLeverage": "1",
+        }),
+        "/v5/spot-cross-margin-trade/pledge-token" => json!({
+            "list": [{ "coin": "USDT", "conversionRate": "1", "liquidationOrder": 1 }],
+        }),
+        "/v5/spot-cross-margin-trade/borrow-token" => json!({
+            "list": [{ "coin": "USDT", "borrowingAccuracy": 4, "repaymentAccuracy": 4 }],
+        }),
+        // Shared by `SpotMargin::LoanInfo` and `SpotMargin::Borrow`.
+        "/v5/spot-cross-margin-trade/loan" => json!({ "transactId": "14143" }),
+        "/v5/spot-cross-margin-trade/account" => json!({
+            "acctBalanceSum": "5670.15",
+            "debtBalanceSum": "0",
+            "loanAccountList": [{
+                "free": "5615.32",
+                "interest": "0",
+                "loan": "0",
+                "remainAmount": "0",
+                "locked": "0",
+                "tokenId": "USDT",
+                "total": "5615.32",
+            }],
+            "riskRate": "0",
+            "status": 1,
+            "switchStatus": 1,
+        }),
+        "/v5/spot-cross-margin-trade/repay" => json!({ "repayId": "12128" }),
+        "/v5/spot-cross-margin-trade/orders" => json!({
+            "list": [{
+                "accountId": "1234567",
+                "coin": "USDT",
+                "createdTime": 1684738540561u64,
+                "id": "14143",
+                "interestAmount": "0",
+                "loanAmount": "100",
+                "remainAmount": "0",
+                "status": 2,
+                "type": 1,
+            }],
+        }),
+        "/v5/spot-cross-margin-trade/repay-history" => json!({
+            "list": [{
+                "accountId": "1234567",
+                "coin": "USDT",
+                "repaidAmount": "100",
+                "repayId": "12128",
+                "repayMarginOrderId": "",
+                "repayTime": 1684738540561u64,
+                "transactIds": [{ "repaidInterest": "0", "repaidPrincipal": "100", "repaidSerialNumber": "14143", "transactId": "14143" }],
+            }],
+        }),
+        _ => json!({}),
+    }
+}
@@ -1,7 +1,10 @@
 #![allow(unused_imports)]
 use crate::{
     account::QuotaAccountType,
-    errors::BybitError,
+    errors::{
+        BybitContentError,
+        BybitError,
+    },
 };
 use serde::{
     Deserialize,
@@ -9,34 +12,83 @@ use serde::{
 };
 use serde_json::{
     from_value,
+    value::RawValue,
     Value,
 };
 use std::{
     borrow::Cow,
-    collections::BTreeMap,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    sync::{
+        Arc,
+        RwLock,
+    },
 };
 use thiserror::Error;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct Empty {}
+/// The number type of every price, quantity and balance in requests and responses.
+///
+/// Bybit sends these as decimal strings. By default they are parsed into `f64`,
+/// which cannot hold most of them exactly. With the `decimal` feature they are
+/// `rust_decimal::Decimal`s instead, which keep every digit, so a value
+/// serializes back to the same string it was read from.
+#[cfg(not(feature = "decimal"))]
+pub type Amount = f64;
+
+/// The number type of every price, quantity and balance in requests and responses.
+///
+/// The `decimal` feature is enabled, so values are exact `rust_decimal::Decimal`s
+/// that serialize back to the same string they were read from.
+#[cfg(feature = "decimal")]
+pub type Amount = rust_decimal::Decimal;
 
-/// ----------------------------------------
-///  RESPONSE STRUCTS FOR MARKET REQUESTS
-/// ----------------------------------------
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Empty {}
 
+/// The envelope shared by every V5 REST response.
+///
+/// `result` holds the endpoint specific payload and `ret_ext_info` the extra
+/// information some endpoints return, such as the per-order results of batch
+/// requests. Responses of endpoints that omit `retExtInfo` or `time` get their
+/// defaults.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct ServerTimeResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
+pub struct ApiResponse<T, E = Empty> {
+    pub ret_code: i32,
     pub ret_msg: String,
-    pub result: ServerTime,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
+    pub result: T,
+    #[serde(default)]
+    pub ret_ext_info: E,
+    #[serde(default)]
     pub time: u64,
 }
 
+impl<T, E> ApiResponse<T, E> {
+    /// Returns `true` if the `retCode` is `0`.
+    pub fn is_success(&self) -> bool {
+        self.ret_code == 0
+    }
+
+    /// Returns the `result`, or the `retCode` and `retMsg` as an error if the
+    /// request failed.
+    pub fn into_result(self) -> Result<T, BybitError> {
+        if self.is_success() {
+            Ok(self.result)
+        } else {
+            Err(BybitError::BybitError(BybitContentError {
+                code: self.ret_code,
+                msg: self.ret_msg,
+            }))
+        }
+    }
+}
+
+/// ----------------------------------------
+///  RESPONSE STRUCTS FOR MARKET REQUESTS
+/// ----------------------------------------
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerTime {
@@ -46,6 +98,8 @@ pub struct ServerTime {
     pub time_nano: u64,
 }
 
+pub type ServerTimeResponse = ApiResponse<ServerTime>;
+
 #[derive(Clone, Default)]
 pub struct KlineRequest<'a> {
     pub category: Option<Category>,
@@ -78,18 +132,7 @@ impl<'a> KlineRequest<'a> {
         }
     }
 }
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct KlineResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: KlineSummary,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type KlineResponse = ApiResponse<KlineSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -104,26 +147,21 @@ pub struct KlineSummary {
 pub struct Kline {
     #[serde(with = "string_to_u64")]
     pub start_time: u64,
-    pub open_price: String,
-    pub high_price: String,
-    pub low_price: String,
-    pub close_price: String,
-    pub volume: String,
-    pub quote_asset_volume: String,
+    #[serde(with = "string_to_float")]
+    pub open_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub high_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub low_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub close_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub volume: Amount,
+    #[serde(with = "string_to_float")]
+    pub quote_asset_volume: Amount,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct MarkPriceKlineResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: MarkPriceKlineSummary,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type MarkPriceKlineResponse = ApiResponse<MarkPriceKlineSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -138,24 +176,17 @@ pub struct MarkPriceKlineSummary {
 pub struct MarkPriceKline {
     #[serde(with = "string_to_u64")]
     pub start_time: u64,
-    pub open_price: String,
-    pub high_price: String,
-    pub low_price: String,
-    pub close_price: String,
+    #[serde(with = "string_to_float")]
+    pub open_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub high_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub low_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub close_price: Amount,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct IndexPriceKlineResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: IndexPriceKlineSummary,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type IndexPriceKlineResponse = ApiResponse<IndexPriceKlineSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -170,24 +201,17 @@ pub struct IndexPriceKlineSummary {
 pub struct IndexPriceKline {
     #[serde(with = "string_to_u64")]
     pub start_time: u64,
-    pub open_price: String,
-    pub high_price: String,
-    pub low_price: String,
-    pub close_price: String,
+    #[serde(with = "string_to_float")]
+    pub open_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub high_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub low_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub close_price: Amount,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct PremiumIndexPriceKlineResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: PremiumIndexPriceKlineSummary,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type PremiumIndexPriceKlineResponse = ApiResponse<PremiumIndexPriceKlineSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -202,10 +226,14 @@ pub struct PremiumIndexPriceKlineSummary {
 pub struct PremiumIndexPriceKline {
     #[serde(with = "string_to_u64")]
     pub start_time: u64,
-    pub open_price: String,
-    pub high_price: String,
-    pub low_price: String,
-    pub close_price: String,
+    #[serde(with = "string_to_float")]
+    pub open_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub high_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub low_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub close_price: Amount,
 }
 
 #[derive(Clone, Default)]
@@ -237,18 +265,7 @@ impl<'a> InstrumentRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct FuturesInstrumentsInfoResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: FuturesInstrumentsInfo,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type FuturesInstrumentsInfoResponse = ApiResponse<FuturesInstrumentsInfo>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -302,18 +319,7 @@ pub struct FuturesInstrument {
     // pub pre_listing_info: PreListingInfo,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct SpotInstrumentsInfoResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: SpotInstrumentsInfo,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type SpotInstrumentsInfoResponse = ApiResponse<SpotInstrumentsInfo>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -417,12 +423,12 @@ pub struct LeverageFilter {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceFilter {
-    #[serde(rename = "minPrice")]
-    pub min_price: Option<String>,
-    #[serde(rename = "maxPrice")]
-    pub max_price: Option<String>,
+    #[serde(rename = "minPrice", default, with = "empty_string_as_none")]
+    pub min_price: Option<Amount>,
+    #[serde(rename = "maxPrice", default, with = "empty_string_as_none")]
+    pub max_price: Option<Amount>,
     #[serde(rename = "tickSize", with = "string_to_float")]
-    pub tick_size: f64,
+    pub tick_size: Amount,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -431,30 +437,63 @@ pub struct LotSizeFilter {
     #[serde(
         rename = "basePrecision",
         skip_serializing_if = "Option::is_none",
-        default
+        default,
+        with = "empty_string_as_none"
+    )]
+    pub base_precision: Option<Amount>,
+    #[serde(
+        rename = "quotePrecision",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "empty_string_as_none"
+    )]
+    pub quote_precision: Option<Amount>,
+    #[serde(
+        rename = "maxMktOrderQty",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "empty_string_as_none"
     )]
-    pub base_precision: Option<String>,
-    #[serde(rename = "quotePrecision", skip_serializing_if = "Option::is_none")]
-    pub quote_precision: Option<String>,
-    #[serde(rename = "maxMktOrderQty", skip_serializing_if = "Option::is_none")]
-    pub max_mkt_order_qty: Option<String>,
+    pub max_mkt_order_qty: Option<Amount>,
     #[serde(rename = "minOrderQty", with = "string_to_float")]
-    pub min_order_qty: f64,
+    pub min_order_qty: Amount,
     #[serde(rename = "maxOrderQty", with = "string_to_float")]
-    pub max_order_qty: f64,
-    #[serde(rename = "minOrderAmt", skip_serializing_if = "Option::is_none")]
-    pub min_order_amt: Option<String>,
-    #[serde(rename = "maxOrderAmt", skip_serializing_if = "Option::is_none")]
-    pub max_order_amt: Option<String>,
-    #[serde(rename = "qtyStep", skip_serializing_if = "Option::is_none")]
-    pub qty_step: Option<String>,
+    pub max_order_qty: Amount,
+    #[serde(
+        rename = "minOrderAmt",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "empty_string_as_none"
+    )]
+    pub min_order_amt: Option<Amount>,
+    #[serde(
+        rename = "maxOrderAmt",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "empty_string_as_none"
+    )]
+    pub max_order_amt: Option<Amount>,
+    #[serde(
+        rename = "qtyStep",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "empty_string_as_none"
+    )]
+    pub qty_step: Option<Amount>,
     #[serde(
         rename = "postOnlyMaxOrderQty",
-        skip_serializing_if = "Option::is_none"
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "empty_string_as_none"
+    )]
+    pub post_only_max_order_qty: Option<Amount>,
+    #[serde(
+        rename = "minNotionalValue",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "empty_string_as_none"
     )]
-    pub post_only_max_order_qty: Option<String>,
-    #[serde(rename = "minNotionalValue", skip_serializing_if = "Option::is_none")]
-    pub min_notional_value: Option<String>,
+    pub min_notional_value: Option<Amount>,
 }
 
 #[derive(Clone, Default)]
@@ -481,18 +520,7 @@ impl<'a> OrderbookRequest<'a> {
         }
     }
 }
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct OrderBookResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: OrderBook,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type OrderBookResponse = ApiResponse<OrderBook>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -513,56 +541,246 @@ pub struct OrderBook {
 #[serde(rename_all = "camelCase")]
 pub struct Ask {
     #[serde(with = "string_to_float")]
-    pub price: f64,
+    pub price: Amount,
     #[serde(with = "string_to_float")]
-    pub qty: f64,
+    pub qty: Amount,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Bid {
     #[serde(with = "string_to_float")]
-    pub price: f64,
+    pub price: Amount,
     #[serde(with = "string_to_float")]
-    pub qty: f64,
+    pub qty: Amount,
 }
 
 impl Bid {
-    pub fn new(price: f64, qty: f64) -> Bid {
+    pub fn new(price: Amount, qty: Amount) -> Bid {
         Bid { price, qty }
     }
 }
 impl Ask {
-    pub fn new(price: f64, qty: f64) -> Ask {
+    pub fn new(price: Amount, qty: Amount) -> Ask {
         Ask { price, qty }
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct FuturesTickersResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: FuturesTickers,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
+/// Price used as a ladder key in `LocalOrderBook`.
+///
+/// Exchange prices are never NaN, so ordering them with `f64::total_cmp` is exact.
+#[derive(Clone, Copy, Debug)]
+struct PriceKey(Amount);
+
+impl PartialEq for PriceKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
 }
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct SpotTickersResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: SpotTickers,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
+
+impl Eq for PriceKey {}
+
+impl PartialOrd for PriceKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PriceKey {
+    #[cfg(not(feature = "decimal"))]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+
+    #[cfg(feature = "decimal")]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+/// A locally maintained order book built from `orderbook.{depth}.{symbol}` messages.
+///
+/// The book is seeded from a websocket snapshot or a REST `OrderBook` and then kept
+/// up to date by applying deltas in `update_id` order. A delta whose `update_id`
+/// does not follow the last applied one marks the book as out of sync; further
+/// deltas are rejected until a new snapshot is applied.
+#[derive(Clone, Debug, Default)]
+pub struct LocalOrderBook {
+    pub symbol: String,
+    asks: BTreeMap<PriceKey, Amount>,
+    bids: BTreeMap<PriceKey, Amount>,
+    update_id: u64,
+    seq: u64,
+    timestamp: u64,
+    synced: bool,
+}
+
+impl LocalOrderBook {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_uppercase(),
+            ..Default::default()
+        }
+    }
+
+    /// Builds a book from a REST order book snapshot.
+    pub fn from_snapshot(book: &OrderBook) -> Self {
+        let mut local = Self::new(&book.symbol);
+        local.apply_snapshot(book);
+        local
+    }
+
+    /// Replaces the whole book with a REST order book snapshot.
+    ///
+    /// Websocket deltas with an `update_id` at or below the snapshot's are skipped
+    /// afterwards, so the REST call can be made while the websocket keeps streaming.
+    pub fn apply_snapshot(&mut self, book: &OrderBook) {
+        self.reset(book.update_id, self.seq, book.timestamp);
+        self.insert_levels(&book.asks, &book.bids);
+    }
+
+    /// Applies a websocket `snapshot` or `delta` message to the book.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BybitError::OrderBookSequenceGap` when a delta does not directly
+    /// follow the last applied update, or when the book is waiting for a resync.
+    /// The book stays unusable until the next websocket snapshot or a call to
+    /// `apply_snapshot`.
+    pub fn apply_update(&mut self, update: &OrderBookUpdate) -> Result<(), BybitError> {
+        let data = &update.data;
+        if !self.symbol.is_empty() && self.symbol != data.symbol {
+            return Err(BybitError::Base(format!(
+                "Order book update for {} applied to {}",
+                data.symbol, self.symbol
+            )));
+        }
+        // `u == 1` means the service restarted and the message is a fresh snapshot.
+        if update.event_type == "snapshot" || data.update_id == 1 {
+            self.symbol.clone_from(&data.symbol);
+            self.reset(data.update_id, data.seq, update.timestamp);
+            self.insert_levels(&data.asks, &data.bids);
+            return Ok(());
+        }
+        if self.synced && data.update_id <= self.update_id {
+            // Already covered by the snapshot we resynced from.
+            return Ok(());
+        }
+        if !self.synced || data.update_id != self.update_id + 1 {
+            self.synced = false;
+            return Err(BybitError::OrderBookSequenceGap {
+                symbol: data.symbol.clone(),
+                expected: self.update_id + 1,
+                received: data.update_id,
+            });
+        }
+        self.insert_levels(&data.asks, &data.bids);
+        self.update_id = data.update_id;
+        self.seq = data.seq;
+        self.timestamp = update.timestamp;
+        Ok(())
+    }
+
+    fn reset(&mut self, update_id: u64, seq: u64, timestamp: u64) {
+        self.asks.clear();
+        self.bids.clear();
+        self.update_id = update_id;
+        self.seq = seq;
+        self.timestamp = timestamp;
+        self.synced = true;
+    }
+
+    fn insert_levels(&mut self, asks: &[Ask], bids: &[Bid]) {
+        for ask in asks {
+            Self::set_level(&mut self.asks, ask.price, ask.qty);
+        }
+        for bid in bids {
+            Self::set_level(&mut self.bids, bid.price, bid.qty);
+        }
+    }
+
+    fn set_level(ladder: &mut BTreeMap<PriceKey, Amount>, price: Amount, qty: Amount) {
+        if qty == Amount::default() {
+            ladder.remove(&PriceKey(price));
+        } else {
+            ladder.insert(PriceKey(price), qty);
+        }
+    }
+
+    /// Returns `false` once a sequence gap has been detected and the book needs a
+    /// fresh snapshot.
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    pub fn update_id(&self) -> u64 {
+        self.update_id
+    }
+
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn best_bid(&self) -> Option<Bid> {
+        self.bids
+            .iter()
+            .next_back()
+            .map(|(price, qty)| Bid::new(price.0, *qty))
+    }
+
+    pub fn best_ask(&self) -> Option<Ask> {
+        self.asks
+            .iter()
+            .next()
+            .map(|(price, qty)| Ask::new(price.0, *qty))
+    }
+
+    pub fn mid_price(&self) -> Option<Amount> {
+        Some((self.best_ask()?.price + self.best_bid()?.price) / Amount::from(2))
+    }
+
+    pub fn spread(&self) -> Option<Amount> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Returns the resting quantity at `price` on the given side, or zero if the
+    /// level is empty or the side is unknown.
+    pub fn depth_at(&self, side: Side, price: Amount) -> Amount {
+        let ladder = match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+            Side::Unknown(_) => return Amount::default(),
+        };
+        ladder.get(&PriceKey(price)).copied().unwrap_or_default()
+    }
+
+    /// Returns up to `n` bids, best (highest) price first.
+    pub fn top_bids(&self, n: usize) -> Vec<Bid> {
+        self.bids
+            .iter()
+            .rev()
+            .take(n)
+            .map(|(price, qty)| Bid::new(price.0, *qty))
+            .collect()
+    }
+
+    /// Returns up to `n` asks, best (lowest) price first.
+    pub fn top_asks(&self, n: usize) -> Vec<Ask> {
+        self.asks
+            .iter()
+            .take(n)
+            .map(|(price, qty)| Ask::new(price.0, *qty))
+            .collect()
+    }
 }
 
+pub type FuturesTickersResponse = ApiResponse<FuturesTickers>;
+pub type SpotTickersResponse = ApiResponse<SpotTickers>;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct FuturesTickers {
@@ -582,49 +800,63 @@ pub struct SpotTickers {
 pub struct FuturesTicker {
     pub symbol: String,
     #[serde(with = "string_to_float")]
-    pub last_price: f64,
+    pub last_price: Amount,
     #[serde(with = "string_to_float")]
-    pub index_price: f64,
+    pub index_price: Amount,
     #[serde(with = "string_to_float")]
-    pub mark_price: f64,
+    pub mark_price: Amount,
     #[serde(rename = "prevPrice24h", with = "string_to_float")]
-    pub prev_price_24h: f64,
+    pub prev_price_24h: Amount,
     #[serde(rename = "price24hPcnt", with = "string_to_float")]
-    pub daily_change_percentage: f64,
+    pub daily_change_percentage: Amount,
     #[serde(rename = "highPrice24h", with = "string_to_float")]
-    pub high_24h: f64,
+    pub high_24h: Amount,
     #[serde(rename = "lowPrice24h", with = "string_to_float")]
-    pub low_24h: f64,
+    pub low_24h: Amount,
     #[serde(rename = "prevPrice1h", with = "string_to_float")]
-    pub prev_price_1h: f64,
+    pub prev_price_1h: Amount,
     #[serde(with = "string_to_float")]
-    pub open_interest: f64,
+    pub open_interest: Amount,
     #[serde(with = "string_to_float")]
-    pub open_interest_value: f64,
-    #[serde(rename = "turnover24h")]
-    pub turnover_24h: String,
-    #[serde(rename = "volume24h")]
-    pub volume_24h: String,
-    pub funding_rate: String,
+    pub open_interest_value: Amount,
+    #[serde(rename = "turnover24h", with = "string_to_float")]
+    pub turnover_24h: Amount,
+    #[serde(rename = "volume24h", with = "string_to_float")]
+    pub volume_24h: Amount,
+    #[serde(default, with = "empty_string_as_none")]
+    pub funding_rate: Option<Amount>,
     #[serde(rename = "nextFundingTime", with = "string_to_u64")]
     pub next_funding_time: u64,
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub predicted_delivery_price: String,
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub basis_rate: String,
-    pub delivery_fee_rate: String,
+    #[serde(
+        default,
+        with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub predicted_delivery_price: Option<Amount>,
+    #[serde(
+        default,
+        with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub basis_rate: Option<Amount>,
+    #[serde(default, with = "empty_string_as_none")]
+    pub delivery_fee_rate: Option<Amount>,
     #[serde(rename = "deliveryTime", with = "string_to_u64")]
     pub delivery_time: u64,
     #[serde(rename = "ask1Size", with = "string_to_float")]
-    pub ask_size: f64,
+    pub ask_size: Amount,
     #[serde(rename = "bid1Price", with = "string_to_float")]
-    pub bid_price: f64,
+    pub bid_price: Amount,
     #[serde(rename = "ask1Price", with = "string_to_float")]
-    pub ask_price: f64,
+    pub ask_price: Amount,
     #[serde(rename = "bid1Size", with = "string_to_float")]
-    pub bid_size: f64,
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub basis: String,
+    pub bid_size: Amount,
+    #[serde(
+        default,
+        with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub basis: Option<Amount>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -632,29 +864,29 @@ pub struct FuturesTicker {
 pub struct SpotTicker {
     pub symbol: String,
     #[serde(rename = "bid1Price", with = "string_to_float")]
-    pub bid_price: f64,
+    pub bid_price: Amount,
     #[serde(rename = "bid1Size", with = "string_to_float")]
-    pub bid_size: f64,
+    pub bid_size: Amount,
     #[serde(rename = "ask1Price", with = "string_to_float")]
-    pub ask_price: f64,
+    pub ask_price: Amount,
     #[serde(rename = "ask1Size", with = "string_to_float")]
-    pub ask_size: f64,
+    pub ask_size: Amount,
     #[serde(with = "string_to_float")]
-    pub last_price: f64,
+    pub last_price: Amount,
     #[serde(rename = "prevPrice24h", with = "string_to_float")]
-    pub prev_price_24h: f64,
+    pub prev_price_24h: Amount,
     #[serde(rename = "price24hPcnt", with = "string_to_float")]
-    pub daily_change_percentage: f64,
+    pub daily_change_percentage: Amount,
     #[serde(rename = "highPrice24h", with = "string_to_float")]
-    pub high_24h: f64,
+    pub high_24h: Amount,
     #[serde(rename = "lowPrice24h", with = "string_to_float")]
-    pub low_24h: f64,
-    #[serde(rename = "turnover24h")]
-    pub turnover_24h: String,
-    #[serde(rename = "volume24h")]
-    pub volume_24h: String,
-    #[serde(rename = "usdIndexPrice")]
-    pub usd_index_price: String,
+    pub low_24h: Amount,
+    #[serde(rename = "turnover24h", with = "string_to_float")]
+    pub turnover_24h: Amount,
+    #[serde(rename = "volume24h", with = "string_to_float")]
+    pub volume_24h: Amount,
+    #[serde(rename = "usdIndexPrice", default, with = "empty_string_as_none")]
+    pub usd_index_price: Option<Amount>,
 }
 
 #[derive(Clone, Default)]
@@ -686,18 +918,7 @@ impl<'a> FundingHistoryRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct FundingRateResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: FundingRateSummary,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type FundingRateResponse = ApiResponse<FundingRateSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -711,7 +932,7 @@ pub struct FundingRateSummary {
 pub struct FundingRate {
     pub symbol: String,
     #[serde(rename = "fundingRate", with = "string_to_float")]
-    pub funding_rate: f64,
+    pub funding_rate: Amount,
     #[serde(rename = "fundingRateTimestamp", with = "string_to_u64")]
     pub funding_rate_timestamp: u64,
 }
@@ -742,18 +963,7 @@ impl<'a> RecentTradesRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct RecentTradesResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: RecentTrades,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type RecentTradesResponse = ApiResponse<RecentTrades>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -769,9 +979,9 @@ pub struct RecentTrade {
     pub exec_id: String,
     pub symbol: String,
     #[serde(with = "string_to_float")]
-    pub price: f64,
+    pub price: Amount,
     #[serde(rename = "size", with = "string_to_float")]
-    pub qty: f64,
+    pub qty: Amount,
     pub side: String,
     #[serde(rename = "time")]
     pub timestamp: String,
@@ -811,18 +1021,7 @@ impl<'a> OpenInterestRequest<'a> {
         }
     }
 }
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct OpeninterestResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: OpenInterestSummary,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type OpeninterestResponse = ApiResponse<OpenInterestSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -838,7 +1037,7 @@ pub struct OpenInterestSummary {
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterest {
     #[serde(rename = "openInterest", with = "string_to_float")]
-    pub open_interest: f64,
+    pub open_interest: Amount,
     #[serde(with = "string_to_u64")]
     pub timestamp: u64,
 }
@@ -874,7 +1073,7 @@ impl<'a> HistoricalVolatilityRequest<'a> {
 #[serde(rename_all = "camelCase")]
 pub struct HistoricalVolatilityResponse {
     #[serde(rename = "retCode")]
-    pub ret_code: i16,
+    pub ret_code: i32,
     #[serde(rename = "retMsg")]
     pub ret_msg: String,
     pub category: String,
@@ -887,23 +1086,12 @@ pub struct HistoricalVolatilityResponse {
 pub struct HistoricalVolatility {
     pub period: u64,
     #[serde(with = "string_to_float")]
-    pub value: f64,
+    pub value: Amount,
     #[serde(rename = "time", with = "string_to_u64")]
     pub timestamp: u64,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct InsuranceResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: InsuranceSummary,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type InsuranceResponse = ApiResponse<InsuranceSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -918,8 +1106,9 @@ pub struct InsuranceSummary {
 pub struct Insurance {
     pub coin: String,
     #[serde(with = "string_to_float")]
-    pub balance: f64,
-    pub value: String,
+    pub balance: Amount,
+    #[serde(with = "string_to_float")]
+    pub value: Amount,
 }
 
 #[derive(Clone, Default)]
@@ -940,17 +1129,7 @@ impl<'a> RiskLimitRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct RiskLimitResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: RiskLimitSummary,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type RiskLimitResponse = ApiResponse<RiskLimitSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct RiskLimitSummary {
@@ -963,26 +1142,18 @@ pub struct RiskLimit {
     pub id: u64,
     pub symbol: String,
     #[serde(rename = "riskLimitValue", with = "string_to_float")]
-    pub risk_limit_value: f64,
+    pub risk_limit_value: Amount,
     #[serde(rename = "maintenanceMargin", with = "string_to_float")]
-    pub maintainence_margin: f64,
+    pub maintainence_margin: Amount,
     #[serde(rename = "initialMargin", with = "string_to_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Amount,
     #[serde(rename = "isLowestRisk")]
     pub is_lowest_risk: u8,
     #[serde(rename = "maxLeverage")]
     pub max_leverage: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct DeliveryPriceResponse {
-    pub ret_code: i16,
-    pub ret_msg: String,
-    pub result: DeliveryPriceSummary,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type DeliveryPriceResponse = ApiResponse<DeliveryPriceSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -997,23 +1168,13 @@ pub struct DeliveryPriceSummary {
 #[serde(rename_all = "camelCase")]
 pub struct DeliveryPrice {
     pub symbol: String,
-    pub delivery_price: String,
+    #[serde(with = "string_to_float")]
+    pub delivery_price: Amount,
     #[serde(with = "string_to_u64")]
     pub delivery_time: u64,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct LongShortRatioResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: LongShortRatioSummary,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type LongShortRatioResponse = ApiResponse<LongShortRatioSummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -1027,9 +1188,9 @@ pub struct LongShortRatio {
     #[serde(rename = "symbol")]
     pub symbol: String,
     #[serde(rename = "buyRatio", with = "string_to_float")]
-    pub buy_ratio: f64,
+    pub buy_ratio: Amount,
     #[serde(rename = "sellRatio", with = "string_to_float")]
-    pub sell_ratio: f64,
+    pub sell_ratio: Amount,
     #[serde(rename = "timestamp", with = "string_to_u64")]
     pub timestamp: u64,
 }
@@ -1037,7 +1198,7 @@ pub struct LongShortRatio {
 /// --------------------------------------------------
 ///  REQUEST & RESPONSE STRUCTS FOR TRADE
 /// --------------------------------------------------
-#[derive(Clone, Copy, Default, Serialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize)]
 pub enum Category {
     Spot,
     #[default]
@@ -1056,86 +1217,295 @@ impl Category {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Default)]
-pub enum Side {
-    #[default]
-    Buy,
-    Sell,
-}
+/// Declares an enum for a string valued Bybit parameter.
+///
+/// Each variant is mapped to the string Bybit uses for it. Any other string parses
+/// into an extra `Unknown` variant instead of failing, so values added by Bybit later
+/// do not break deserialization, and are sent back unchanged.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// A value this version of the crate does not know about.
+            Unknown(String),
+        }
 
-impl Side {
-    pub fn as_str(&self) -> &str {
-        match self {
-            Side::Buy => "Buy",
-            Side::Sell => "Sell",
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(value.to_string()),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok($name::from(value))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok($name::from(value.as_str()))
+            }
         }
+    };
+}
+
+string_enum! {
+    #[derive(Default)]
+    pub enum Side {
+        #[default]
+        Buy => "Buy",
+        Sell => "Sell",
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Default)]
-pub enum OrderType {
-    Limit,
+string_enum! {
+    #[derive(Default)]
+    pub enum OrderType {
+        Limit => "Limit",
+        #[default]
+        Market => "Market",
+    }
+}
+
+string_enum! {
+    #[derive(Default)]
+    pub enum TimeInForce {
+        #[default]
+        GTC => "GTC",
+        IOC => "IOC",
+        FOK => "FOK",
+        PostOnly => "PostOnly",
+    }
+}
+
+string_enum! {
+    /// The state of an order, `orderStatus`.
+    pub enum OrderState {
+        Created => "Created",
+        New => "New",
+        Rejected => "Rejected",
+        PartiallyFilled => "PartiallyFilled",
+        PartiallyFilledCanceled => "PartiallyFilledCanceled",
+        Filled => "Filled",
+        Cancelled => "Cancelled",
+        /// A conditional order waiting for its trigger price.
+        Untriggered => "Untriggered",
+        Triggered => "Triggered",
+        Deactivated => "Deactivated",
+        Active => "Active",
+    }
+}
+
+string_enum! {
+    /// The kind of a conditional or TP/SL order, `stopOrderType`.
+    pub enum StopOrderType {
+        TakeProfit => "TakeProfit",
+        StopLoss => "StopLoss",
+        TrailingStop => "TrailingStop",
+        Stop => "Stop",
+        PartialTakeProfit => "PartialTakeProfit",
+        PartialStopLoss => "PartialStopLoss",
+        TpslOrder => "tpslOrder",
+        OcoOrder => "OcoOrder",
+        MmRateClose => "MmRateClose",
+        BidirectionalTpslOrder => "BidirectionalTpslOrder",
+    }
+}
+
+string_enum! {
+    /// The price that fires a trigger, `triggerBy`, `tpTriggerBy` and `slTriggerBy`.
+    #[derive(Default)]
+    pub enum TriggerBy {
+        #[default]
+        LastPrice => "LastPrice",
+        IndexPrice => "IndexPrice",
+        MarkPrice => "MarkPrice",
+    }
+}
+
+string_enum! {
+    /// Whether TP/SL apply to the whole position or to a part of it, `tpslMode`.
+    pub enum TpSlMode {
+        Full => "Full",
+        Partial => "Partial",
+    }
+}
+
+string_enum! {
+    /// Self match prevention, `smpType`.
+    #[derive(Default)]
+    pub enum SmpType {
+        #[default]
+        None => "None",
+        CancelMaker => "CancelMaker",
+        CancelTaker => "CancelTaker",
+        CancelBoth => "CancelBoth",
+    }
+}
+
+string_enum! {
+    /// The kind of spot order to place, query or cancel, `orderFilter`.
+    #[derive(Default)]
+    pub enum OrderFilter {
+        #[default]
+        Order => "Order",
+        TpslOrder => "tpslOrder",
+        StopOrder => "StopOrder",
+        OcoOrder => "OcoOrder",
+        BidirectionalTpslOrder => "BidirectionalTpslOrder",
+    }
+}
+
+string_enum! {
+    /// The direction of the price move that produced a trade, `tickDirection`.
+    pub enum TickDirection {
+        PlusTick => "PlusTick",
+        ZeroPlusTick => "ZeroPlusTick",
+        MinusTick => "MinusTick",
+        ZeroMinusTick => "ZeroMinusTick",
+    }
+}
+
+/// The position an order belongs to, `positionIdx`.
+///
+/// Bybit sends it as an integer. Values other than `0`, `1` and `2` are kept in
+/// `Unknown` as their decimal string.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PositionIdx {
+    /// The position in one-way mode.
     #[default]
-    Market,
+    OneWay,
+    /// The buy side position in hedge mode.
+    BuyHedge,
+    /// The sell side position in hedge mode.
+    SellHedge,
+    /// A value this version of the crate does not know about.
+    Unknown(String),
 }
 
-impl OrderType {
+impl PositionIdx {
     pub fn as_str(&self) -> &str {
         match self {
-            OrderType::Limit => "Limit",
-            OrderType::Market => "Market",
+            PositionIdx::OneWay => "0",
+            PositionIdx::BuyHedge => "1",
+            PositionIdx::SellHedge => "2",
+            PositionIdx::Unknown(value) => value,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Default)]
-pub enum TimeInForce {
-    #[default]
-    GTC,
-    IOC,
-    FOK,
-    PostOnly,
+impl From<u8> for PositionIdx {
+    fn from(value: u8) -> Self {
+        PositionIdx::from(value.to_string().as_str())
+    }
 }
 
-impl TimeInForce {
-    pub fn as_str(&self) -> &str {
-        match self {
-            TimeInForce::GTC => "GTC",
-            TimeInForce::IOC => "IOC",
-            TimeInForce::FOK => "FOK",
-            TimeInForce::PostOnly => "PostOnly",
+impl From<&str> for PositionIdx {
+    fn from(value: &str) -> Self {
+        match value {
+            "0" => PositionIdx::OneWay,
+            "1" => PositionIdx::BuyHedge,
+            "2" => PositionIdx::SellHedge,
+            _ => PositionIdx::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for PositionIdx {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for PositionIdx {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.as_str().parse::<i64>() {
+            Ok(value) => serializer.serialize_i64(value),
+            Err(_) => serializer.serialize_str(self.as_str()),
         }
     }
 }
+
+impl<'de> Deserialize<'de> for PositionIdx {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Int(i64),
+            Str(String),
+        }
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Int(value) => PositionIdx::from(value.to_string().as_str()),
+            Raw::Str(value) => PositionIdx::from(value.as_str()),
+        })
+    }
+}
 #[derive(Clone, Default, Serialize)]
 pub struct OrderRequest<'a> {
-    pub category: Category,                 // String
-    pub symbol: Cow<'a, str>,               // String
-    pub is_leverage: Option<bool>,          // Integer
-    pub side: Side,                         // String
-    pub order_type: OrderType,              // String
-    pub qty: f64,                           // String
-    pub market_unit: Option<f64>,           // String
-    pub price: Option<f64>,                 // String
-    pub trigger_direction: Option<bool>,    // String
-    pub order_filter: Option<Cow<'a, str>>, // String
-    pub trigger_price: Option<f64>,
-    pub trigger_by: Option<Cow<'a, str>>,    // String
-    pub order_iv: Option<f64>,               // String
-    pub time_in_force: Option<Cow<'a, str>>, // String
-    pub position_idx: Option<u8>,
+    pub category: Category,                // String
+    pub symbol: Cow<'a, str>,              // String
+    pub is_leverage: Option<bool>,         // Integer
+    pub side: Side,                        // String
+    pub order_type: OrderType,             // String
+    pub qty: Amount,                       // String
+    pub market_unit: Option<Amount>,       // String
+    pub price: Option<Amount>,             // String
+    pub trigger_direction: Option<bool>,   // String
+    pub order_filter: Option<OrderFilter>, // String
+    pub trigger_price: Option<Amount>,
+    pub trigger_by: Option<TriggerBy>,      // String
+    pub order_iv: Option<Amount>,           // String
+    pub time_in_force: Option<TimeInForce>, // String
+    pub position_idx: Option<PositionIdx>,
     pub order_link_id: Option<Cow<'a, str>>,
-    pub take_profit: Option<f64>,
-    pub stop_loss: Option<f64>,
-    pub tp_trigger_by: Option<Cow<'a, str>>,
-    pub sl_trigger_by: Option<Cow<'a, str>>,
+    pub take_profit: Option<Amount>,
+    pub stop_loss: Option<Amount>,
+    pub tp_trigger_by: Option<TriggerBy>,
+    pub sl_trigger_by: Option<TriggerBy>,
     pub reduce_only: Option<bool>,
     pub close_on_trigger: Option<bool>,
-    pub smp_type: Option<Cow<'a, str>>,
+    pub smp_type: Option<SmpType>,
     pub mmp: Option<bool>,
-    pub tpsl_mode: Option<Cow<'a, str>>,
-    pub tp_limit_price: Option<f64>,
-    pub sl_limit_price: Option<f64>,
+    pub tpsl_mode: Option<TpSlMode>,
+    pub tp_limit_price: Option<Amount>,
+    pub sl_limit_price: Option<Amount>,
     pub tp_order_type: Option<Cow<'a, str>>,
     pub sl_order_type: Option<Cow<'a, str>>,
 }
@@ -1148,7 +1518,7 @@ impl<'a> OrderRequest<'a> {
             is_leverage: None,
             side: Side::default(),
             order_type: OrderType::Market,
-            qty: 0.00,
+            qty: Amount::default(),
             market_unit: None,
             price: None,
             trigger_direction: None,
@@ -1180,28 +1550,28 @@ impl<'a> OrderRequest<'a> {
         leverage: Option<bool>,
         side: Side,
         order_type: OrderType,
-        qty: f64,
-        market_unit: Option<f64>,
-        price: Option<f64>,
+        qty: Amount,
+        market_unit: Option<Amount>,
+        price: Option<Amount>,
         trigger_direction: Option<bool>,
-        order_filter: Option<&'a str>,
-        trigger_price: Option<f64>,
-        trigger_by: Option<&'a str>,
-        order_iv: Option<f64>,
-        time_in_force: Option<&'a str>,
-        position_idx: Option<u8>,
+        order_filter: Option<OrderFilter>,
+        trigger_price: Option<Amount>,
+        trigger_by: Option<TriggerBy>,
+        order_iv: Option<Amount>,
+        time_in_force: Option<TimeInForce>,
+        position_idx: Option<PositionIdx>,
         order_link_id: Option<&'a str>,
-        take_profit: Option<f64>,
-        stop_loss: Option<f64>,
-        tp_trigger_by: Option<&'a str>,
-        sl_trigger_by: Option<&'a str>,
+        take_profit: Option<Amount>,
+        stop_loss: Option<Amount>,
+        tp_trigger_by: Option<TriggerBy>,
+        sl_trigger_by: Option<TriggerBy>,
         reduce_only: Option<bool>,
         close_on_trigger: Option<bool>,
-        smp_type: Option<&'a str>,
+        smp_type: Option<SmpType>,
         mmp: Option<bool>,
-        tpsl_mode: Option<&'a str>,
-        tp_limit_price: Option<f64>,
-        sl_limit_price: Option<f64>,
+        tpsl_mode: Option<TpSlMode>,
+        tp_limit_price: Option<Amount>,
+        sl_limit_price: Option<Amount>,
         tp_order_type: Option<&'a str>,
         sl_order_type: Option<&'a str>,
     ) -> Self {
@@ -1215,22 +1585,22 @@ impl<'a> OrderRequest<'a> {
             market_unit,
             price,
             trigger_direction,
-            order_filter: order_filter.map(Cow::Borrowed),
+            order_filter,
             trigger_price,
-            trigger_by: trigger_by.map(Cow::Borrowed),
+            trigger_by,
             order_iv,
-            time_in_force: time_in_force.map(Cow::Borrowed),
+            time_in_force,
             position_idx,
             order_link_id: order_link_id.map(Cow::Borrowed),
             take_profit,
             stop_loss,
-            tp_trigger_by: tp_trigger_by.map(Cow::Borrowed),
-            sl_trigger_by: sl_trigger_by.map(Cow::Borrowed),
+            tp_trigger_by,
+            sl_trigger_by,
             reduce_only,
             close_on_trigger,
-            smp_type: smp_type.map(Cow::Borrowed),
+            smp_type,
             mmp,
-            tpsl_mode: tpsl_mode.map(Cow::Borrowed),
+            tpsl_mode,
             tp_limit_price,
             sl_limit_price,
             tp_order_type: tp_order_type.map(Cow::Borrowed),
@@ -1240,10 +1610,10 @@ impl<'a> OrderRequest<'a> {
     pub fn spot_limit_with_market_tpsl(
         symbol: &'a str,
         side: Side,
-        qty: f64,
-        price: f64,
-        tp: f64,
-        sl: f64,
+        qty: Amount,
+        price: Amount,
+        tp: Amount,
+        sl: Amount,
     ) -> Self {
         Self {
             category: Category::Spot,
@@ -1252,7 +1622,7 @@ impl<'a> OrderRequest<'a> {
             order_type: OrderType::Limit,
             qty,
             price: Some(price),
-            time_in_force: Some(Cow::Borrowed(TimeInForce::PostOnly.as_str())),
+            time_in_force: Some(TimeInForce::PostOnly),
             take_profit: Some(tp),
             stop_loss: Some(sl),
             tp_order_type: Some(Cow::Borrowed("Market")),
@@ -1263,10 +1633,10 @@ impl<'a> OrderRequest<'a> {
     pub fn spot_limit_with_limit_tpsl(
         symbol: &'a str,
         side: Side,
-        qty: f64,
-        price: f64,
-        tp: f64,
-        sl: f64,
+        qty: Amount,
+        price: Amount,
+        tp: Amount,
+        sl: Amount,
     ) -> Self {
         Self {
             category: Category::Spot,
@@ -1275,7 +1645,7 @@ impl<'a> OrderRequest<'a> {
             order_type: OrderType::Limit,
             qty,
             price: Some(price),
-            time_in_force: Some(Cow::Borrowed(TimeInForce::PostOnly.as_str())),
+            time_in_force: Some(TimeInForce::PostOnly),
             take_profit: Some(tp),
             stop_loss: Some(sl),
             tp_limit_price: Some(tp),
@@ -1285,7 +1655,12 @@ impl<'a> OrderRequest<'a> {
             ..Self::default()
         }
     }
-    pub fn spot_postonly(symbol: &'a str, side: Side, qty: f64, price: f64) -> Self {
+    pub fn spot_postonly(
+        symbol: &'a str,
+        side: Side,
+        qty: Amount,
+        price: Amount,
+    ) -> Self {
         Self {
             category: Category::Spot,
             symbol: Cow::Borrowed(symbol),
@@ -1293,15 +1668,15 @@ impl<'a> OrderRequest<'a> {
             order_type: OrderType::Limit,
             qty,
             price: Some(price),
-            time_in_force: Some(Cow::Borrowed(TimeInForce::PostOnly.as_str())),
+            time_in_force: Some(TimeInForce::PostOnly),
             ..Self::default()
         }
     }
     pub fn spot_tpsl(
         symbol: &'a str,
         side: Side,
-        price: f64,
-        qty: f64,
+        price: Amount,
+        qty: Amount,
         order_link_id: Option<&'a str>,
     ) -> Self {
         Self {
@@ -1311,13 +1686,13 @@ impl<'a> OrderRequest<'a> {
             order_type: OrderType::Limit,
             qty,
             price: Some(price),
-            time_in_force: Some(Cow::Borrowed(TimeInForce::GTC.as_str())),
+            time_in_force: Some(TimeInForce::GTC),
             order_link_id: order_link_id.map(Cow::Borrowed),
-            order_filter: Some(Cow::Borrowed("tpslOrder")),
+            order_filter: Some(OrderFilter::TpslOrder),
             ..Self::default()
         }
     }
-    pub fn spot_margin(symbol: &'a str, side: Side, qty: f64, price: f64) -> Self {
+    pub fn spot_margin(symbol: &'a str, side: Side, qty: Amount, price: Amount) -> Self {
         Self {
             category: Category::Spot,
             symbol: Cow::Borrowed(symbol),
@@ -1325,20 +1700,20 @@ impl<'a> OrderRequest<'a> {
             order_type: OrderType::Market,
             qty,
             price: Some(price),
-            time_in_force: Some(Cow::Borrowed(TimeInForce::PostOnly.as_str())),
+            time_in_force: Some(TimeInForce::PostOnly),
             is_leverage: Some(true),
             ..Self::default()
         }
     }
 
-    pub fn spot_market(symbol: &'a str, side: Side, qty: f64) -> Self {
+    pub fn spot_market(symbol: &'a str, side: Side, qty: Amount) -> Self {
         Self {
             category: Category::Spot,
             symbol: Cow::Borrowed(symbol),
             side,
             order_type: OrderType::Market,
             qty,
-            time_in_force: Some(Cow::Borrowed(TimeInForce::IOC.as_str())),
+            time_in_force: Some(TimeInForce::IOC),
             ..Self::default()
         }
     }
@@ -1346,10 +1721,10 @@ impl<'a> OrderRequest<'a> {
     pub fn futures_limit_with_market_tpsl(
         symbol: &'a str,
         side: Side,
-        qty: f64,
-        price: f64,
-        tp: f64,
-        sl: f64,
+        qty: Amount,
+        price: Amount,
+        tp: Amount,
+        sl: Amount,
     ) -> Self {
         Self {
             category: Category::Linear,
@@ -1358,11 +1733,11 @@ impl<'a> OrderRequest<'a> {
             order_type: OrderType::Limit,
             qty,
             price: Some(price),
-            time_in_force: Some(Cow::Borrowed(TimeInForce::PostOnly.as_str())),
+            time_in_force: Some(TimeInForce::PostOnly),
             reduce_only: Some(false),
             take_profit: Some(tp),
             stop_loss: Some(sl),
-            tpsl_mode: Some(Cow::Borrowed("Full")),
+            tpsl_mode: Some(TpSlMode::Full),
             tp_order_type: Some(Cow::Borrowed("Market")),
             sl_order_type: Some(Cow::Borrowed("Market")),
             ..Self::default()
@@ -1372,10 +1747,10 @@ impl<'a> OrderRequest<'a> {
     pub fn futures_limit_with_limit_tpsl(
         symbol: &'a str,
         side: Side,
-        qty: f64,
-        price: f64,
-        tp: f64,
-        sl: f64,
+        qty: Amount,
+        price: Amount,
+        tp: Amount,
+        sl: Amount,
     ) -> Self {
         Self {
             category: Category::Linear,
@@ -1384,11 +1759,11 @@ impl<'a> OrderRequest<'a> {
             order_type: OrderType::Limit,
             qty,
             price: Some(price),
-            time_in_force: Some(Cow::Borrowed(TimeInForce::PostOnly.as_str())),
+            time_in_force: Some(TimeInForce::PostOnly),
             reduce_only: Some(false),
             take_profit: Some(tp),
             stop_loss: Some(sl),
-            tpsl_mode: Some(Cow::Borrowed("Partial")),
+            tpsl_mode: Some(TpSlMode::Partial),
             tp_order_type: Some(Cow::Borrowed("Limit")),
             sl_order_type: Some(Cow::Borrowed("Limit")),
             tp_limit_price: Some(tp),
@@ -1397,14 +1772,14 @@ impl<'a> OrderRequest<'a> {
         }
     }
 
-    pub fn futures_market(symbol: &'a str, side: Side, qty: f64) -> Self {
+    pub fn futures_market(symbol: &'a str, side: Side, qty: Amount) -> Self {
         Self {
             category: Category::Linear,
             symbol: Cow::Borrowed(symbol),
             side,
             order_type: OrderType::Market,
             qty,
-            time_in_force: Some(Cow::Borrowed(TimeInForce::IOC.as_str())),
+            time_in_force: Some(TimeInForce::IOC),
             reduce_only: Some(false),
             ..Self::default()
         }
@@ -1413,8 +1788,8 @@ impl<'a> OrderRequest<'a> {
     pub fn futures_close_limit(
         symbol: &'a str,
         side: Side,
-        qty: f64,
-        price: f64,
+        qty: Amount,
+        price: Amount,
         order_link_id: &'a str,
     ) -> Self {
         Self {
@@ -1424,35 +1799,27 @@ impl<'a> OrderRequest<'a> {
             order_type: OrderType::Limit,
             qty,
             price: Some(price),
-            time_in_force: Some(Cow::Borrowed(TimeInForce::GTC.as_str())),
+            time_in_force: Some(TimeInForce::GTC),
             order_link_id: Some(Cow::Borrowed(order_link_id)),
             reduce_only: Some(true),
             ..Self::default()
         }
     }
 
-    pub fn futures_market_close(symbol: &'a str, side: Side, qty: f64) -> Self {
+    pub fn futures_market_close(symbol: &'a str, side: Side, qty: Amount) -> Self {
         Self {
             category: Category::Linear,
             symbol: Cow::Borrowed(symbol),
             side,
             order_type: OrderType::Market,
             qty,
-            time_in_force: Some(Cow::Borrowed(TimeInForce::IOC.as_str())),
+            time_in_force: Some(TimeInForce::IOC),
             reduce_only: Some(true),
             ..Self::default()
         }
     }
 }
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct AmendOrderResponse {
-    pub ret_code: i16,
-    pub ret_msg: String,
-    pub result: OrderStatus,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type AmendOrderResponse = ApiResponse<OrderIds>;
 
 #[derive(Clone, Default, Serialize)]
 pub struct AmendOrderRequest<'a> {
@@ -1460,18 +1827,18 @@ pub struct AmendOrderRequest<'a> {
     pub symbol: Cow<'a, str>, // String
     pub order_id: Option<Cow<'a, str>>,
     pub order_link_id: Option<Cow<'a, str>>,
-    pub order_iv: Option<f64>, // String
-    pub trigger_price: Option<f64>,
-    pub qty: f64,           // String
-    pub price: Option<f64>, // String
-    pub tpsl_mode: Option<Cow<'a, str>>,
-    pub take_profit: Option<f64>,
-    pub stop_loss: Option<f64>,
-    pub tp_trigger_by: Option<Cow<'a, str>>,
-    pub sl_trigger_by: Option<Cow<'a, str>>,
-    pub trigger_by: Option<Cow<'a, str>>, // String
-    pub tp_limit_price: Option<f64>,
-    pub sl_limit_price: Option<f64>,
+    pub order_iv: Option<Amount>, // String
+    pub trigger_price: Option<Amount>,
+    pub qty: Amount,           // String
+    pub price: Option<Amount>, // String
+    pub tpsl_mode: Option<TpSlMode>,
+    pub take_profit: Option<Amount>,
+    pub stop_loss: Option<Amount>,
+    pub tp_trigger_by: Option<TriggerBy>,
+    pub sl_trigger_by: Option<TriggerBy>,
+    pub trigger_by: Option<TriggerBy>, // String
+    pub tp_limit_price: Option<Amount>,
+    pub sl_limit_price: Option<Amount>,
 }
 
 impl<'a> AmendOrderRequest<'a> {
@@ -1483,7 +1850,7 @@ impl<'a> AmendOrderRequest<'a> {
             order_link_id: None,
             order_iv: None,
             trigger_price: None,
-            qty: 0.00,
+            qty: Amount::default(),
             price: None,
             tpsl_mode: None,
             take_profit: None,
@@ -1500,18 +1867,18 @@ impl<'a> AmendOrderRequest<'a> {
         symbol: &'a str,
         order_id: Option<&'a str>,
         order_link_id: Option<&'a str>,
-        order_iv: Option<f64>,
-        trigger_price: Option<f64>,
-        qty: f64,
-        price: Option<f64>,
-        tpsl_mode: Option<&'a str>,
-        take_profit: Option<f64>,
-        stop_loss: Option<f64>,
-        tp_trigger_by: Option<&'a str>,
-        sl_trigger_by: Option<&'a str>,
-        trigger_by: Option<&'a str>,
-        tp_limit_price: Option<f64>,
-        sl_limit_price: Option<f64>,
+        order_iv: Option<Amount>,
+        trigger_price: Option<Amount>,
+        qty: Amount,
+        price: Option<Amount>,
+        tpsl_mode: Option<TpSlMode>,
+        take_profit: Option<Amount>,
+        stop_loss: Option<Amount>,
+        tp_trigger_by: Option<TriggerBy>,
+        sl_trigger_by: Option<TriggerBy>,
+        trigger_by: Option<TriggerBy>,
+        tp_limit_price: Option<Amount>,
+        sl_limit_price: Option<Amount>,
     ) -> Self {
         Self {
             category,
@@ -1522,12 +1889,12 @@ impl<'a> AmendOrderRequest<'a> {
             trigger_price,
             qty,
             price,
-            tpsl_mode: tpsl_mode.map(Cow::Borrowed),
+            tpsl_mode,
             take_profit,
             stop_loss,
-            tp_trigger_by: tp_trigger_by.map(Cow::Borrowed),
-            sl_trigger_by: sl_trigger_by.map(Cow::Borrowed),
-            trigger_by: trigger_by.map(Cow::Borrowed),
+            tp_trigger_by,
+            sl_trigger_by,
+            trigger_by,
             tp_limit_price,
             sl_limit_price,
         }
@@ -1540,18 +1907,10 @@ pub struct CancelOrderRequest<'a> {
     pub symbol: Cow<'a, str>,
     pub order_id: Option<Cow<'a, str>>,
     pub order_link_id: Option<Cow<'a, str>>,
-    pub order_filter: Option<Cow<'a, str>>,
+    pub order_filter: Option<OrderFilter>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct CancelOrderResponse {
-    pub ret_code: i16,
-    pub ret_msg: String,
-    pub result: OrderStatus,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type CancelOrderResponse = ApiResponse<OrderIds>;
 
 #[derive(Clone, Default)]
 pub struct OpenOrdersRequest<'a> {
@@ -1562,7 +1921,7 @@ pub struct OpenOrdersRequest<'a> {
     pub order_id: Option<Cow<'a, str>>,
     pub order_link_id: Option<Cow<'a, str>>,
     pub open_only: Option<usize>,
-    pub order_filter: Option<Cow<'a, str>>,
+    pub order_filter: Option<OrderFilter>,
     pub limit: Option<usize>,
 }
 
@@ -1589,7 +1948,7 @@ impl<'a> OpenOrdersRequest<'a> {
         order_id: Option<&'a str>,
         order_link_id: Option<&'a str>,
         open_only: usize,
-        order_filter: Option<&'a str>,
+        order_filter: Option<OrderFilter>,
         limit: Option<usize>,
     ) -> Self {
         Self {
@@ -1603,43 +1962,29 @@ impl<'a> OpenOrdersRequest<'a> {
                 0 | 1 | 2 => Some(open_only),
                 _ => None,
             },
-            order_filter: order_filter.map(Cow::Borrowed),
+            order_filter,
             limit,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct OpenOrdersResponse {
-    pub ret_code: i16,
-    pub ret_msg: String,
-    pub result: OrderHistory,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type OpenOrdersResponse = ApiResponse<OrderHistory>;
 
+/// The ids of a placed, amended or cancelled order.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct OrderStatus {
+pub struct OrderIds {
     #[serde(rename = "orderId")]
     pub order_id: String,
     #[serde(rename = "orderLinkId")]
     pub order_link_id: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct OrderResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: OrderStatus,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type OrderResponse = ApiResponse<OrderIds>;
+
+/// The old name of [`OrderIds`].
+#[deprecated(note = "renamed to `OrderIds`, the order state enum is `OrderState`")]
+pub type OrderStatus = OrderIds;
 
 #[derive(Clone, Default)]
 pub struct OrderHistoryRequest<'a> {
@@ -1649,8 +1994,8 @@ pub struct OrderHistoryRequest<'a> {
     pub settle_coin: Option<Cow<'a, str>>,
     pub order_id: Option<Cow<'a, str>>,
     pub order_link_id: Option<Cow<'a, str>>,
-    pub order_filter: Option<Cow<'a, str>>,
-    pub order_status: Option<Cow<'a, str>>,
+    pub order_filter: Option<OrderFilter>,
+    pub order_status: Option<OrderState>,
     pub start_time: Option<Cow<'a, str>>,
     pub end_time: Option<Cow<'a, str>>,
     pub limit: Option<u64>,
@@ -1679,8 +2024,8 @@ impl<'a> OrderHistoryRequest<'a> {
         settle_coin: Option<&'a str>,
         order_id: Option<&'a str>,
         order_link_id: Option<&'a str>,
-        order_filter: Option<&'a str>,
-        order_status: Option<&'a str>,
+        order_filter: Option<OrderFilter>,
+        order_status: Option<OrderState>,
         start_time: Option<&'a str>,
         end_time: Option<&'a str>,
         limit: Option<u64>,
@@ -1692,8 +2037,8 @@ impl<'a> OrderHistoryRequest<'a> {
             settle_coin: settle_coin.map(Cow::Borrowed),
             order_id: order_id.map(Cow::Borrowed),
             order_link_id: order_link_id.map(Cow::Borrowed),
-            order_filter: order_filter.map(Cow::Borrowed),
-            order_status: order_status.map(Cow::Borrowed),
+            order_filter,
+            order_status,
             start_time: start_time.map(Cow::Borrowed),
             end_time: end_time.map(Cow::Borrowed),
             limit,
@@ -1701,18 +2046,7 @@ impl<'a> OrderHistoryRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct OrderHistoryResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: OrderHistory,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type OrderHistoryResponse = ApiResponse<OrderHistory>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -1734,72 +2068,77 @@ pub struct Orders {
     pub block_trade_id: String,
     pub symbol: String,
     #[serde(with = "string_to_float")]
-    pub price: f64,
+    pub price: Amount,
     #[serde(with = "string_to_float")]
-    pub qty: f64,
+    pub qty: Amount,
     pub side: Side,
     #[serde(rename = "isLeverage", skip_serializing_if = "String::is_empty")]
     pub is_leverage: String,
     #[serde(rename = "positionIdx")]
-    pub position_idx: i32,
+    pub position_idx: PositionIdx,
     #[serde(rename = "orderStatus")]
-    pub order_status: String,
+    pub order_status: OrderState,
     #[serde(rename = "cancelType")]
     pub cancel_type: String,
     #[serde(rename = "rejectReason")]
     pub reject_reason: String,
     #[serde(rename = "avgPrice", with = "string_to_float")]
-    pub avg_price: f64,
+    pub avg_price: Amount,
     #[serde(rename = "leavesQty", with = "string_to_float")]
-    pub leaves_qty: f64,
+    pub leaves_qty: Amount,
     #[serde(rename = "leavesValue", with = "string_to_float")]
-    pub leaves_value: f64,
+    pub leaves_value: Amount,
     #[serde(rename = "cumExecQty", with = "string_to_float")]
-    pub cum_exec_qty: f64,
+    pub cum_exec_qty: Amount,
     #[serde(rename = "cumExecValue", with = "string_to_float")]
-    pub cum_exec_value: f64,
+    pub cum_exec_value: Amount,
     #[serde(rename = "cumExecFee", with = "string_to_float")]
-    pub cum_exec_fee: f64,
+    pub cum_exec_fee: Amount,
     #[serde(rename = "timeInForce")]
-    pub time_in_force: String,
+    pub time_in_force: TimeInForce,
     #[serde(rename = "orderType")]
     pub order_type: OrderType,
-    #[serde(rename = "stopOrderType")]
-    pub stop_order_type: String,
+    #[serde(rename = "stopOrderType", default, with = "empty_string_as_none")]
+    pub stop_order_type: Option<StopOrderType>,
     #[serde(rename = "orderIv", skip_serializing_if = "String::is_empty")]
     pub order_iv: String,
     #[serde(rename = "triggerPrice", with = "string_to_float")]
-    pub trigger_price: f64,
+    pub trigger_price: Amount,
     #[serde(rename = "takeProfit", with = "string_to_float")]
-    pub take_profit: f64,
+    pub take_profit: Amount,
     #[serde(rename = "stopLoss", with = "string_to_float")]
-    pub stop_loss: f64,
-    #[serde(rename = "tpTriggerBy")]
-    pub tp_trigger_by: String,
-    #[serde(rename = "slTriggerBy")]
-    pub sl_trigger_by: String,
+    pub stop_loss: Amount,
+    #[serde(rename = "tpTriggerBy", default, with = "empty_string_as_none")]
+    pub tp_trigger_by: Option<TriggerBy>,
+    #[serde(rename = "slTriggerBy", default, with = "empty_string_as_none")]
+    pub sl_trigger_by: Option<TriggerBy>,
     #[serde(rename = "triggerDirection")]
     pub trigger_direction: i32,
-    #[serde(rename = "triggerBy")]
-    pub trigger_by: String,
+    #[serde(rename = "triggerBy", default, with = "empty_string_as_none")]
+    pub trigger_by: Option<TriggerBy>,
     #[serde(rename = "lastPriceOnCreated", with = "string_to_float")]
-    pub last_price_on_created: f64,
+    pub last_price_on_created: Amount,
     #[serde(rename = "reduceOnly")]
     pub reduce_only: bool,
     #[serde(rename = "closeOnTrigger")]
     pub close_on_trigger: bool,
     #[serde(rename = "smpType")]
-    pub smp_type: String,
+    pub smp_type: SmpType,
     #[serde(rename = "smpGroup")]
     pub smp_group: i32,
     #[serde(rename = "smpOrderId", skip_serializing_if = "String::is_empty")]
     pub smp_order_id: String,
-    #[serde(rename = "tpslMode", skip_serializing_if = "String::is_empty")]
-    pub tpsl_mode: String,
+    #[serde(
+        rename = "tpslMode",
+        default,
+        with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tpsl_mode: Option<TpSlMode>,
     #[serde(rename = "tpLimitPrice", with = "string_to_float")]
-    pub tp_limit_price: f64,
+    pub tp_limit_price: Amount,
     #[serde(rename = "slLimitPrice", with = "string_to_float")]
-    pub sl_limit_price: f64,
+    pub sl_limit_price: Amount,
     #[serde(rename = "placeType", skip_serializing_if = "String::is_empty")]
     pub place_type: String,
     #[serde(with = "string_to_u64")]
@@ -1814,8 +2153,8 @@ pub struct CancelallRequest<'a> {
     pub symbol: &'a str,
     pub base_coin: Option<&'a str>,
     pub settle_coin: Option<&'a str>,
-    pub order_filter: Option<&'a str>,
-    pub stop_order_type: Option<&'a str>,
+    pub order_filter: Option<OrderFilter>,
+    pub stop_order_type: Option<StopOrderType>,
 }
 
 impl<'a> CancelallRequest<'a> {
@@ -1834,8 +2173,8 @@ impl<'a> CancelallRequest<'a> {
         symbol: &'a str,
         base_coin: Option<&'a str>,
         settle_coin: Option<&'a str>,
-        order_filter: Option<&'a str>,
-        stop_order_type: Option<&'a str>,
+        order_filter: Option<OrderFilter>,
+        stop_order_type: Option<StopOrderType>,
     ) -> Self {
         Self {
             category,
@@ -1848,34 +2187,15 @@ impl<'a> CancelallRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct CancelallResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: CancelledList,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type CancelallResponse = ApiResponse<CancelledList>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelledList {
-    pub list: Vec<OrderStatus>,
+    pub list: Vec<OrderIds>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct TradeHistoryResponse {
-    pub ret_code: i16,
-    pub ret_msg: String,
-    pub result: TradeHistorySummary,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type TradeHistoryResponse = ApiResponse<TradeHistorySummary>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -1890,34 +2210,36 @@ pub struct TradeHistorySummary {
 pub struct TradeHistory {
     pub symbol: String,
     #[serde(rename = "orderType")]
-    pub order_type: String,
+    pub order_type: OrderType,
     #[serde(
         rename = "underlyingPrice",
         default,
-        skip_serializing_if = "String::is_empty"
+        with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
     )]
-    pub underlying_price: String,
+    pub underlying_price: Option<Amount>,
     #[serde(
         rename = "orderLinkId",
         default,
         skip_serializing_if = "String::is_empty"
     )]
     pub order_link_id: String,
-    pub side: String,
+    pub side: Side,
     #[serde(
         rename = "indexPrice",
         default,
-        skip_serializing_if = "String::is_empty"
+        with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
     )]
-    pub index_price: String,
+    pub index_price: Option<Amount>,
     #[serde(rename = "orderId")]
     pub order_id: String,
-    #[serde(rename = "stopOrderType")]
-    pub stop_order_type: String,
-    #[serde(rename = "leavesQty")]
-    pub leaves_qty: String,
-    #[serde(rename = "execTime")]
-    pub exec_time: String,
+    #[serde(rename = "stopOrderType", default, with = "empty_string_as_none")]
+    pub stop_order_type: Option<StopOrderType>,
+    #[serde(rename = "leavesQty", with = "string_to_float")]
+    pub leaves_qty: Amount,
+    #[serde(rename = "execTime", with = "string_to_u64")]
+    pub exec_time: u64,
     #[serde(
         rename = "feeCurrency",
         default,
@@ -1926,42 +2248,53 @@ pub struct TradeHistory {
     pub fee_currency: String,
     #[serde(rename = "isMaker")]
     pub is_maker: bool,
-    #[serde(rename = "execFee")]
-    pub exec_fee: String,
-    #[serde(rename = "feeRate")]
-    pub fee_rate: String,
+    #[serde(rename = "execFee", with = "string_to_float")]
+    pub exec_fee: Amount,
+    #[serde(rename = "feeRate", with = "string_to_float")]
+    pub fee_rate: Amount,
     #[serde(rename = "execId")]
     pub exec_id: String,
-    #[serde(rename = "tradeIv", default, skip_serializing_if = "String::is_empty")]
-    pub trade_iv: String,
+    #[serde(
+        rename = "tradeIv",
+        default,
+        with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub trade_iv: Option<Amount>,
     #[serde(
         rename = "blockTradeId",
         default,
         skip_serializing_if = "String::is_empty"
     )]
     pub block_trade_id: String,
-    #[serde(rename = "markPrice")]
-    pub mark_price: String,
-    #[serde(rename = "execPrice")]
-    pub exec_price: String,
-    #[serde(rename = "markIv", default, skip_serializing_if = "String::is_empty")]
-    pub mark_iv: String,
-    #[serde(rename = "orderQty")]
-    pub order_qty: String,
-    #[serde(rename = "orderPrice")]
-    pub order_price: String,
-    #[serde(rename = "execValue")]
-    pub exec_value: String,
+    #[serde(rename = "markPrice", with = "string_to_float")]
+    pub mark_price: Amount,
+    #[serde(rename = "execPrice", with = "string_to_float")]
+    pub exec_price: Amount,
+    #[serde(
+        rename = "markIv",
+        default,
+        with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mark_iv: Option<Amount>,
+    #[serde(rename = "orderQty", with = "string_to_float")]
+    pub order_qty: Amount,
+    #[serde(rename = "orderPrice", with = "string_to_float")]
+    pub order_price: Amount,
+    #[serde(rename = "execValue", with = "string_to_float")]
+    pub exec_value: Amount,
     #[serde(rename = "execType")]
     pub exec_type: String,
-    #[serde(rename = "execQty")]
-    pub exec_qty: String,
+    #[serde(rename = "execQty", with = "string_to_float")]
+    pub exec_qty: Amount,
     #[serde(
         rename = "closedSize",
         default,
-        skip_serializing_if = "String::is_empty"
+        with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
     )]
-    pub closed_size: String,
+    pub closed_size: Option<Amount>,
     pub seq: u64,
 }
 
@@ -2030,18 +2363,7 @@ impl<'a> BatchPlaceRequest<'a> {
         BatchPlaceRequest { category, requests }
     }
 }
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct BatchPlaceResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: BatchedOrderList,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: OrderConfirmationList,
-    pub time: u64,
-}
+pub type BatchPlaceResponse = ApiResponse<BatchedOrderList, OrderConfirmationList>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -2062,7 +2384,7 @@ pub struct BatchedOrder {
     pub create_at: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderConfirmationList {
     pub list: Vec<OrderConfirmation>,
@@ -2071,7 +2393,7 @@ pub struct OrderConfirmationList {
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderConfirmation {
-    pub code: i16,
+    pub code: i32,
     pub msg: String,
 }
 
@@ -2090,18 +2412,7 @@ impl<'a> BatchAmendRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct BatchAmendResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: AmendedOrderList,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: OrderConfirmationList,
-    pub time: u64,
-}
+pub type BatchAmendResponse = ApiResponse<AmendedOrderList, OrderConfirmationList>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -2135,18 +2446,7 @@ impl<'a> BatchCancelRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct BatchCancelResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: CanceledOrderList,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: OrderConfirmationList,
-    pub time: u64,
-}
+pub type BatchCancelResponse = ApiResponse<CanceledOrderList, OrderConfirmationList>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -2206,14 +2506,7 @@ impl<'a> PositionRequest<'a> {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct InfoResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: InfoResult,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type InfoResponse = ApiResponse<InfoResult>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct InfoResult {
@@ -2227,18 +2520,18 @@ pub struct InfoResult {
 #[serde(rename_all = "camelCase")]
 pub struct PositionInfo {
     #[serde(rename = "positionIdx")]
-    pub position_idx: i32,
+    pub position_idx: PositionIdx,
     pub risk_id: i32,
     #[serde(rename = "riskLimitValue", with = "string_to_float")]
-    pub risk_limit_value: f64,
+    pub risk_limit_value: Amount,
     pub symbol: String,
     pub side: String,
     #[serde(with = "string_to_float")]
-    pub size: f64,
+    pub size: Amount,
     #[serde(with = "string_to_float")]
-    pub avg_price: f64,
+    pub avg_price: Amount,
     #[serde(rename = "positionValue", with = "string_to_float")]
-    pub position_value: f64,
+    pub position_value: Amount,
     #[serde(rename = "tradeMode")]
     pub trade_mode: i32,
     #[serde(rename = "positionStatus")]
@@ -2248,39 +2541,46 @@ pub struct PositionInfo {
     #[serde(rename = "adlRankIndicator")]
     pub adl_rank_indicator: i32,
     #[serde(with = "string_to_float")]
-    pub leverage: f64,
+    pub leverage: Amount,
     #[serde(rename = "positionBalance", with = "string_to_float")]
-    pub position_balance: f64,
-    #[serde(rename = "markPrice")]
-    pub mark_price: String,
-    #[serde(rename = "liqPrice")]
-    pub liq_price: String,
-    #[serde(rename = "bustPrice")]
-    pub bust_price: String,
+    pub position_balance: Amount,
+    #[serde(rename = "markPrice", with = "string_to_float")]
+    pub mark_price: Amount,
+    #[serde(rename = "liqPrice", default, with = "empty_string_as_none")]
+    pub liq_price: Option<Amount>,
+    #[serde(rename = "bustPrice", default, with = "empty_string_as_none")]
+    pub bust_price: Option<Amount>,
     #[serde(rename = "positionMM", with = "string_to_float")]
-    pub position_mm: f64,
+    pub position_mm: Amount,
     #[serde(rename = "positionIM", with = "string_to_float")]
-    pub position_im: f64,
-    #[serde(rename = "tpslMode")]
-    pub tpsl_mode: String,
-    pub take_profit: String,
-    pub stop_loss: String,
-    pub trailing_stop: String,
+    pub position_im: Amount,
+    #[serde(rename = "tpslMode", default, with = "empty_string_as_none")]
+    pub tpsl_mode: Option<TpSlMode>,
+    #[serde(with = "string_to_float")]
+    pub take_profit: Amount,
+    #[serde(with = "string_to_float")]
+    pub stop_loss: Amount,
+    #[serde(with = "string_to_float")]
+    pub trailing_stop: Amount,
     #[serde(rename = "unrealisedPnl", with = "string_to_float")]
-    pub unrealised_pnl: f64,
+    pub unrealised_pnl: Amount,
     #[serde(rename = "cumRealisedPnl", with = "string_to_float")]
-    pub cum_realised_pnl: f64,
+    pub cum_realised_pnl: Amount,
     pub seq: u64,
     #[serde(rename = "isReduceOnly")]
     pub is_reduce_only: bool,
-    #[serde(rename = "mmrSysUpdateTime")]
-    pub mmr_sys_update_time: String,
-    #[serde(rename = "leverageSysUpdatedTime")]
-    pub leverage_sys_updated_time: String,
-    #[serde(rename = "createdTime")]
-    pub created_time: String,
-    #[serde(rename = "updatedTime")]
-    pub updated_time: String,
+    #[serde(rename = "mmrSysUpdateTime", default, with = "empty_string_as_none")]
+    pub mmr_sys_update_time: Option<u64>,
+    #[serde(
+        rename = "leverageSysUpdatedTime",
+        default,
+        with = "empty_string_as_none"
+    )]
+    pub leverage_sys_updated_time: Option<u64>,
+    #[serde(rename = "createdTime", with = "string_to_u64")]
+    pub created_time: u64,
+    #[serde(rename = "updatedTime", with = "string_to_u64")]
+    pub updated_time: u64,
 }
 
 #[derive(Clone, Default)]
@@ -2303,18 +2603,7 @@ impl<'a> LeverageRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct LeverageResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i32,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: Empty, // Assuming result is an empty struct as per provided JSON
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty, /* Assuming retExtInfo is an empty struct as per provided JSON */
-    pub time: u64,
-}
+pub type LeverageResponse = ApiResponse<Empty>;
 
 #[derive(Default, Clone)]
 pub struct ChangeMarginRequest<'a> {
@@ -2347,18 +2636,7 @@ impl<'a> ChangeMarginRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct ChangeMarginResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i32,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: Empty, // Assuming result is an empty struct as per provided JSON
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty, /* Assuming retExtInfo is an empty struct as per provided JSON */
-    pub time: u64,
-}
+pub type ChangeMarginResponse = ApiResponse<Empty>;
 
 #[derive(Clone, Default)]
 pub struct MarginModeRequest<'a> {
@@ -2387,18 +2665,7 @@ impl<'a> MarginModeRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct MarginModeResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i32,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: Empty, // Assuming result is an empty struct as per provided JSON
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty, /* Assuming retExtInfo is an empty struct as per provided JSON */
-    pub time: u64,
-}
+pub type MarginModeResponse = ApiResponse<Empty>;
 
 #[derive(Clone, Default)]
 pub struct SetRiskLimit<'a> {
@@ -2427,18 +2694,7 @@ impl<'a> SetRiskLimit<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct SetRiskLimitResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i32,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: SetRiskLimitResult,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty, // Assuming retExtInfo is a JSON value as per provided JSON
-    pub time: u64,
-}
+pub type SetRiskLimitResponse = ApiResponse<SetRiskLimitResult>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct SetRiskLimitResult {
@@ -2453,17 +2709,17 @@ pub struct SetRiskLimitResult {
 pub struct TradingStopRequest<'a> {
     pub category: Category,
     pub symbol: Cow<'a, str>,
-    pub take_profit: Option<f64>,
-    pub stop_loss: Option<f64>,
+    pub take_profit: Option<Amount>,
+    pub stop_loss: Option<Amount>,
     pub tp_trigger_by: Option<Cow<'a, str>>,
     pub sl_trigger_by: Option<Cow<'a, str>>,
     pub tpsl_mode: Option<Cow<'a, str>>,
     pub tp_order_type: Option<OrderType>,
     pub sl_order_type: Option<OrderType>,
-    pub tp_size: Option<f64>,
-    pub sl_size: Option<f64>,
-    pub tp_limit_price: Option<f64>,
-    pub sl_limit_price: Option<f64>,
+    pub tp_size: Option<Amount>,
+    pub sl_size: Option<Amount>,
+    pub tp_limit_price: Option<Amount>,
+    pub sl_limit_price: Option<Amount>,
     pub position_idx: i32,
 }
 
@@ -2471,17 +2727,17 @@ impl<'a> TradingStopRequest<'a> {
     pub fn new(
         category: Category,
         symbol: &'a str,
-        take_profit: Option<f64>,
-        stop_loss: Option<f64>,
+        take_profit: Option<Amount>,
+        stop_loss: Option<Amount>,
         tp_trigger_by: Option<&'a str>,
         sl_trigger_by: Option<&'a str>,
         tpsl_mode: Option<&'a str>,
         tp_order_type: Option<OrderType>,
         sl_order_type: Option<OrderType>,
-        tp_size: Option<f64>,
-        sl_size: Option<f64>,
-        tp_limit_price: Option<f64>,
-        sl_limit_price: Option<f64>,
+        tp_size: Option<Amount>,
+        sl_size: Option<Amount>,
+        tp_limit_price: Option<Amount>,
+        sl_limit_price: Option<Amount>,
         position_idx: i32,
     ) -> Self {
         Self {
@@ -2522,18 +2778,7 @@ impl<'a> TradingStopRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct TradingStopResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i32,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: Empty, // Assuming result is an empty struct as per provided JSON
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty, /* Assuming retExtInfo is an empty struct as per provided JSON */
-    pub time: u64,
-}
+pub type TradingStopResponse = ApiResponse<Empty>;
 
 #[derive(Clone, Default)]
 pub struct AddMarginRequest<'a> {
@@ -2562,24 +2807,13 @@ impl<'a> AddMarginRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct AddMarginResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i32,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: Empty, // Assuming result is an empty struct as per provided JSON
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty, /* Assuming retExtInfo is an empty struct as per provided JSON */
-    pub time: u64,
-}
+pub type AddMarginResponse = ApiResponse<Empty>;
 
 #[derive(Clone, Default)]
 pub struct AddReduceMarginRequest<'a> {
     pub category: Category,
     pub symbol: Cow<'a, str>,
-    pub margin: f64,
+    pub margin: Amount,
     pub position_idx: Option<i32>,
 }
 
@@ -2587,7 +2821,7 @@ impl<'a> AddReduceMarginRequest<'a> {
     pub fn new(
         category: Category,
         symbol: &'a str,
-        margin: f64,
+        margin: Amount,
         position_idx: Option<i32>,
     ) -> Self {
         Self {
@@ -2598,71 +2832,62 @@ impl<'a> AddReduceMarginRequest<'a> {
         }
     }
     pub fn default() -> AddReduceMarginRequest<'a> {
-        AddReduceMarginRequest::new(Category::Linear, "BTCUSDT", 1.0, None)
+        AddReduceMarginRequest::new(Category::Linear, "BTCUSDT", Amount::from(1), None)
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct AddReduceMarginResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i32,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: AddReduceMarginResult,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty, /* Assuming retExtInfo is an empty struct as per provided JSON */
-    pub time: u64,
-}
+pub type AddReduceMarginResponse = ApiResponse<AddReduceMarginResult>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct AddReduceMarginResult {
     pub category: String,
     pub symbol: String,
-    pub position_idx: i32,
+    pub position_idx: PositionIdx,
     #[serde(rename = "riskId")]
     pub risk_id: i32,
-    #[serde(rename = "riskLimitValue")]
-    pub risk_limit_value: String,
-    pub size: String,
-    #[serde(rename = "positionValue")]
-    pub position_value: String,
-    #[serde(rename = "avgPrice")]
-    pub avg_price: String,
-    #[serde(rename = "liqPrice")]
-    pub liq_price: String,
-    #[serde(rename = "bustPrice")]
-    pub bust_price: String,
-    #[serde(rename = "markPrice")]
-    pub mark_price: String,
-    pub leverage: String,
+    #[serde(rename = "riskLimitValue", with = "string_to_float")]
+    pub risk_limit_value: Amount,
+    #[serde(with = "string_to_float")]
+    pub size: Amount,
+    #[serde(rename = "positionValue", with = "string_to_float")]
+    pub position_value: Amount,
+    #[serde(rename = "avgPrice", with = "string_to_float")]
+    pub avg_price: Amount,
+    #[serde(rename = "liqPrice", default, with = "empty_string_as_none")]
+    pub liq_price: Option<Amount>,
+    #[serde(rename = "bustPrice", default, with = "empty_string_as_none")]
+    pub bust_price: Option<Amount>,
+    #[serde(rename = "markPrice", with = "string_to_float")]
+    pub mark_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub leverage: Amount,
     #[serde(rename = "autoAddMargin")]
     pub auto_add_margin: i32,
     #[serde(rename = "positionStatus")]
     pub position_status: String,
-    #[serde(rename = "positionIM")]
-    pub position_im: String,
-    #[serde(rename = "positionMM")]
-    pub position_mm: String,
-    #[serde(rename = "unrealisedPnl")]
-    pub unrealised_pnl: String,
-    #[serde(rename = "cumRealisedPnl")]
-    pub cum_realised_pnl: String,
-    #[serde(rename = "stopLoss")]
-    pub stop_loss: String,
-    #[serde(rename = "takeProfit")]
-    pub take_profit: String,
-    #[serde(rename = "trailingStop")]
-    pub trailing_stop: String,
-    #[serde(rename = "createdTime")]
-    pub created_time: String,
-    #[serde(rename = "updatedTime")]
-    pub updated_time: String,
-}
-
-#[derive(Clone, Default)]
-pub struct ClosedPnlRequest<'a> {
+    #[serde(rename = "positionIM", with = "string_to_float")]
+    pub position_im: Amount,
+    #[serde(rename = "positionMM", with = "string_to_float")]
+    pub position_mm: Amount,
+    #[serde(rename = "unrealisedPnl", with = "string_to_float")]
+    pub unrealised_pnl: Amount,
+    #[serde(rename = "cumRealisedPnl", with = "string_to_float")]
+    pub cum_realised_pnl: Amount,
+    #[serde(rename = "stopLoss", with = "string_to_float")]
+    pub stop_loss: Amount,
+    #[serde(rename = "takeProfit", with = "string_to_float")]
+    pub take_profit: Amount,
+    #[serde(rename = "trailingStop", with = "string_to_float")]
+    pub trailing_stop: Amount,
+    #[serde(rename = "createdTime", with = "string_to_u64")]
+    pub created_time: u64,
+    #[serde(rename = "updatedTime", with = "string_to_u64")]
+    pub updated_time: u64,
+}
+
+#[derive(Clone, Default)]
+pub struct ClosedPnlRequest<'a> {
     pub category: Category,
     pub symbol: Option<Cow<'a, str>>,
     pub start_time: Option<Cow<'a, str>>,
@@ -2691,15 +2916,7 @@ impl<'a> ClosedPnlRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct ClosedPnlResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: ClosedPnlResult,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type ClosedPnlResponse = ApiResponse<ClosedPnlResult>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -2715,27 +2932,32 @@ pub struct ClosedPnlResult {
 pub struct ClosedPnlItem {
     pub symbol: String,
     pub order_type: String,
-    pub leverage: String,
-    pub updated_time: String,
+    #[serde(with = "string_to_float")]
+    pub leverage: Amount,
+    #[serde(with = "string_to_u64")]
+    pub updated_time: u64,
     pub side: String,
     pub order_id: String,
     #[serde(with = "string_to_float")]
-    pub closed_pnl: f64,
+    pub closed_pnl: Amount,
     #[serde(rename = "avgEntryPrice", with = "string_to_float")]
-    pub avg_entry_price: f64,
-    pub qty: String,
+    pub avg_entry_price: Amount,
     #[serde(with = "string_to_float")]
-    pub cum_entry_value: f64,
-    pub created_time: String,
+    pub qty: Amount,
     #[serde(with = "string_to_float")]
-    pub order_price: f64,
-    pub closed_size: String,
+    pub cum_entry_value: Amount,
+    #[serde(with = "string_to_u64")]
+    pub created_time: u64,
+    #[serde(with = "string_to_float")]
+    pub order_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub closed_size: Amount,
     #[serde(rename = "avgExitPrice", with = "string_to_float")]
-    pub avg_exit_price: f64,
+    pub avg_exit_price: Amount,
     pub exec_type: String,
     pub fill_count: String,
     #[serde(with = "string_to_float")]
-    pub cum_exit_value: f64,
+    pub cum_exit_value: Amount,
 }
 
 #[derive(Clone, Default, Serialize)]
@@ -2749,9 +2971,9 @@ pub struct MovePositionRequest<'a> {
 pub struct PositionItem<'a> {
     pub category: Category,
     pub symbol: Cow<'a, str>,
-    pub price: f64,
+    pub price: Amount,
     pub side: Side,
-    pub qty: f64,
+    pub qty: Amount,
 }
 
 impl<'a> MovePositionRequest<'a> {
@@ -2770,9 +2992,9 @@ impl<'a> PositionItem<'a> {
     pub fn new(
         category: Category,
         symbol: &'a str,
-        price: f64,
+        price: Amount,
         side: Side,
-        qty: f64,
+        qty: Amount,
     ) -> Self {
         Self {
             category,
@@ -2783,18 +3005,20 @@ impl<'a> PositionItem<'a> {
         }
     }
     pub fn default() -> PositionItem<'a> {
-        PositionItem::new(Category::Linear, "BTCUSDT", 0.0, Side::Buy, 0.0)
+        PositionItem::new(
+            Category::Linear,
+            "BTCUSDT",
+            Amount::default(),
+            Side::Buy,
+            Amount::default(),
+        )
     }
 }
 
-#[derive(Deserialize, Serialize, Clone, Debug)]
-pub struct MovePositionResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: MovePositionResult,
-}
+pub type MovePositionResponse = ApiResponse<MovePositionResult>;
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct MovePositionResult {
     pub block_trade_id: String,
     pub status: String,
@@ -2837,18 +3061,7 @@ impl<'a> MoveHistoryRequest<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct MoveHistoryResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i16,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: MoveHistoryResult,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type MoveHistoryResponse = ApiResponse<MoveHistoryResult>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -2870,10 +3083,12 @@ pub struct MoveHistoryEntry {
     pub user_id: u64,
     pub symbol: String,
     pub side: String,
-    pub price: String,
-    pub qty: String,
-    #[serde(rename = "execFee")]
-    pub exec_fee: String,
+    #[serde(with = "string_to_float")]
+    pub price: Amount,
+    #[serde(with = "string_to_float")]
+    pub qty: Amount,
+    #[serde(rename = "execFee", with = "string_to_float")]
+    pub exec_fee: Amount,
     pub status: String,
     #[serde(rename = "execId")]
     pub exec_id: String,
@@ -2895,36 +3110,14 @@ pub struct MoveHistoryEntry {
 //
 // = = = = = = = = = = = = = = = = = = ==  = = = = ==  = = == = =  = = = =
 
-#[derive(Deserialize, Serialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct WalletResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i32,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: WalletList,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type WalletResponse = ApiResponse<WalletList>;
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct WalletList {
     pub list: Vec<WalletData>,
 }
 
-#[derive(Deserialize, Serialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct UTAResponse {
-    #[serde(rename = "retCode")]
-    pub ret_code: i32,
-    #[serde(rename = "retMsg")]
-    pub ret_msg: String,
-    pub result: UTAUpdateStatus,
-    #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type UTAResponse = ApiResponse<UTAUpdateStatus>;
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -2967,15 +3160,7 @@ impl<'a> BorrowHistoryRequest<'a> {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct BorrowHistoryResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: BorrowHistory,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type BorrowHistoryResponse = ApiResponse<BorrowHistory>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -2987,34 +3172,26 @@ pub struct BorrowHistory {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct BorrowHistoryEntry {
-    #[serde(rename = "borrowAmount")]
-    pub borrow_amount: String,
+    #[serde(rename = "borrowAmount", with = "string_to_float")]
+    pub borrow_amount: Amount,
     #[serde(rename = "costExemption")]
     pub cost_exemption: String,
-    #[serde(rename = "freeBorrowedAmount")]
-    pub free_borrowed_amount: String,
+    #[serde(rename = "freeBorrowedAmount", with = "string_to_float")]
+    pub free_borrowed_amount: Amount,
     #[serde(rename = "createdTime")]
     pub created_time: u64,
-    #[serde(rename = "InterestBearingBorrowSize")]
-    pub interest_bearing_borrow_size: String,
+    #[serde(rename = "InterestBearingBorrowSize", with = "string_to_float")]
+    pub interest_bearing_borrow_size: Amount,
     pub currency: String,
-    #[serde(rename = "unrealisedLoss")]
-    pub unrealised_loss: String,
+    #[serde(rename = "unrealisedLoss", with = "string_to_float")]
+    pub unrealised_loss: Amount,
     #[serde(rename = "hourlyBorrowRate")]
     pub hourly_borrow_rate: String,
-    #[serde(rename = "borrowCost")]
-    pub borrow_cost: String,
+    #[serde(rename = "borrowCost", with = "string_to_float")]
+    pub borrow_cost: Amount,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct RepayLiabilityResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: LiabilityQty,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type RepayLiabilityResponse = ApiResponse<LiabilityQty>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LiabilityQty {
@@ -3025,28 +3202,13 @@ pub struct LiabilityQty {
 #[serde(rename_all = "camelCase")]
 pub struct LiabilityQtyData {
     pub coin: String,
-    pub repayment_qty: String,
+    #[serde(with = "string_to_float")]
+    pub repayment_qty: Amount,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct SetCollateralCoinResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: Empty,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type SetCollateralCoinResponse = ApiResponse<Empty>;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct BatchSetCollateralCoinResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: SwitchList,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type BatchSetCollateralCoinResponse = ApiResponse<SwitchList>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -3061,15 +3223,7 @@ pub struct SwitchListData {
     pub collateral_switch: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct CollateralInfoResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: CollateralInfoList,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type CollateralInfoResponse = ApiResponse<CollateralInfoList>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -3080,42 +3234,34 @@ pub struct CollateralInfoList {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CollateralInfo {
-    #[serde(rename = "availableToBorrow")]
-    pub available_to_borrow: String,
-    #[serde(rename = "freeBorrowingAmount")]
-    pub free_borrowing_amount: String,
-    #[serde(rename = "freeBorrowAmount")]
-    pub free_borrow_amount: String,
-    #[serde(rename = "maxBorrowingAmount")]
-    pub max_borrowing_amount: String,
+    #[serde(rename = "availableToBorrow", with = "string_to_float")]
+    pub available_to_borrow: Amount,
+    #[serde(rename = "freeBorrowingAmount", default, with = "empty_string_as_none")]
+    pub free_borrowing_amount: Option<Amount>,
+    #[serde(rename = "freeBorrowAmount", with = "string_to_float")]
+    pub free_borrow_amount: Amount,
+    #[serde(rename = "maxBorrowingAmount", with = "string_to_float")]
+    pub max_borrowing_amount: Amount,
     #[serde(rename = "hourlyBorrowRate")]
     pub hourly_borrow_rate: String,
     #[serde(rename = "borrowUsageRate")]
     pub borrow_usage_rate: String,
     #[serde(rename = "collateralSwitch")]
     pub collateral_switch: bool,
-    #[serde(rename = "borrowAmount")]
-    pub borrow_amount: String,
+    #[serde(rename = "borrowAmount", with = "string_to_float")]
+    pub borrow_amount: Amount,
     #[serde(rename = "borrowable")]
     pub borrowable: bool,
     pub currency: String,
     #[serde(rename = "marginCollateral")]
     pub margin_collateral: bool,
-    #[serde(rename = "freeBorrowingLimit")]
-    pub free_borrowing_limit: String,
+    #[serde(rename = "freeBorrowingLimit", with = "string_to_float")]
+    pub free_borrowing_limit: Amount,
     #[serde(rename = "collateralRatio")]
     pub collateral_ratio: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct FeeRateResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: FeeRateList,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type FeeRateResponse = ApiResponse<FeeRateList>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -3131,15 +3277,7 @@ pub struct FeeRate {
     pub taker_fee_rate: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct AccountInfoResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: AccountInfo,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type AccountInfoResponse = ApiResponse<AccountInfo>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -3199,23 +3337,33 @@ pub struct TransactionLogEntry {
     pub id: String,
     pub symbol: String,
     pub side: String,
-    pub funding: Option<String>,
+    #[serde(default, with = "empty_string_as_none")]
+    pub funding: Option<Amount>,
     pub order_link_id: Option<String>,
     pub order_id: String,
-    pub fee: String,
-    pub change: String,
-    pub cash_flow: String,
+    #[serde(default, with = "empty_string_as_none")]
+    pub fee: Option<Amount>,
+    #[serde(with = "string_to_float")]
+    pub change: Amount,
+    #[serde(with = "string_to_float")]
+    pub cash_flow: Amount,
     pub transaction_time: String,
+    #[serde(rename = "type")]
     pub type_field: String,
     #[serde(rename = "feeRate")]
     pub fee_rate: String,
-    pub bonus_change: Option<String>,
-    pub size: String,
-    pub qty: String,
-    pub cash_balance: String,
+    #[serde(default, with = "empty_string_as_none")]
+    pub bonus_change: Option<Amount>,
+    #[serde(default, with = "empty_string_as_none")]
+    pub size: Option<Amount>,
+    #[serde(default, with = "empty_string_as_none")]
+    pub qty: Option<Amount>,
+    #[serde(with = "string_to_float")]
+    pub cash_balance: Amount,
     pub currency: String,
     pub category: String,
-    pub trade_price: String,
+    #[serde(default, with = "empty_string_as_none")]
+    pub trade_price: Option<Amount>,
     pub trade_id: String,
 }
 
@@ -3226,25 +3374,9 @@ pub struct TransactionLogResult {
     pub list: Vec<TransactionLogEntry>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct TransactionLogResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: TransactionLogResult,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type TransactionLogResponse = ApiResponse<TransactionLogResult>;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct SmpResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: SmpResult,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type SmpResponse = ApiResponse<SmpResult>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -3252,15 +3384,7 @@ pub struct SmpResult {
     pub smp_group: u8,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct SetMarginModeResponse {
-    pub ret_code: i32,
-    pub ret_msg: String,
-    pub result: MarginModeResult,
-    pub ret_ext_info: Empty,
-    pub time: u64,
-}
+pub type SetMarginModeResponse = ApiResponse<MarginModeResult>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -3335,8 +3459,270 @@ pub enum WebsocketEvents {
     Wallet(WalletEvent),
     TradeStream(TradeStreamEvent),
     FastExecEvent(FastExecution),
+    OptionTickerEvent(OptionTickerUpdate),
+    OptionTradeEvent(OptionTradeUpdate),
+    /// A linear or inverse ticker delta, carrying only the fields that changed.
+    /// Merge it with `TickerCache` to get the complete ticker.
+    #[serde(skip_deserializing)]
+    TickerDeltaEvent(TickerDelta),
+    /// Emitted by the stream itself after a dropped connection has been
+    /// re-established and every subscription replayed. It never comes from
+    /// the exchange, so it is not deserialized.
+    #[serde(skip_deserializing)]
+    Reconnected(ReconnectEvent),
+    /// The connection was authenticated.
+    #[serde(skip_deserializing)]
+    AuthOk,
+    /// The server refused the credentials; holds its reason.
+    #[serde(skip_deserializing)]
+    AuthFailed(String),
+    /// A subscribe request was acknowledged; holds the subscribed topics.
+    #[serde(skip_deserializing)]
+    Subscribed(Vec<String>),
+    /// A subscribe request was refused; holds the server's reason.
+    #[serde(skip_deserializing)]
+    SubscribeFailed(String),
+    /// A well-formed frame whose `topic` or `op` is not handled, such as a
+    /// subscription ack. Holds the raw frame.
+    #[serde(skip_deserializing)]
+    Unknown(String),
+    /// A frame that could not be parsed into the type its `topic` or `op` calls for.
+    #[serde(skip_deserializing)]
+    ParseError(FrameError),
+}
+
+impl WebsocketEvents {
+    /// Parses a websocket frame by dispatching on its `topic` prefix, or on `op` for
+    /// trade stream responses, straight into the matching event type.
+    ///
+    /// Unlike deserializing the untagged enum, every frame yields an event: frames
+    /// that fail to parse come back as `ParseError` and unhandled ones as `Unknown`.
+    pub fn from_frame(frame: &str) -> WebsocketEvents {
+        match Frame::parse(frame) {
+            Ok(frame) => Self::from_parsed_frame(&frame),
+            Err(e) => WebsocketEvents::ParseError(FrameError {
+                error: e.to_string(),
+                frame: frame.to_string(),
+            }),
+        }
+    }
+
+    /// Like `from_frame`, for a frame whose envelope has already been read, so only
+    /// the payload is parsed.
+    pub fn from_parsed_frame(parsed: &Frame<'_>) -> WebsocketEvents {
+        let (frame, envelope) = (parsed.raw, &parsed.envelope);
+        if let Some(event) = Self::control_event(envelope, frame, |_| None) {
+            return event;
+        }
+        let Some(topic) = envelope.topic.as_deref() else {
+            return match envelope.op.as_deref() {
+                Some("order.create" | "order.amend" | "order.cancel") => {
+                    Self::parse(frame, WebsocketEvents::TradeStream)
+                }
+                _ => WebsocketEvents::Unknown(frame.to_string()),
+            };
+        };
+        let mut parts = topic.split('.');
+        match (parts.next(), parts.next()) {
+            (Some("orderbook"), _) => Self::parse(frame, WebsocketEvents::OrderBookEvent),
+            (Some("publicTrade"), _) if envelope.is_option() => {
+                Self::parse(frame, WebsocketEvents::OptionTradeEvent)
+            }
+            (Some("publicTrade"), _) => Self::parse(frame, WebsocketEvents::TradeEvent),
+            (Some("tickers"), _) if envelope.is_option() => {
+                Self::parse(frame, WebsocketEvents::OptionTickerEvent)
+            }
+            (Some("tickers"), _) if envelope.kind.as_deref() == Some("delta") => {
+                Self::parse(frame, WebsocketEvents::TickerDeltaEvent)
+            }
+            (Some("tickers"), _) => Self::parse(frame, WebsocketEvents::TickerEvent),
+            (Some("liquidation"), _) => {
+                Self::parse(frame, WebsocketEvents::LiquidationEvent)
+            }
+            (Some("kline"), _) => Self::parse(frame, WebsocketEvents::KlineEvent),
+            (Some("position"), _) => Self::parse(frame, WebsocketEvents::PositionEvent),
+            (Some("execution"), Some("fast")) => {
+                Self::parse(frame, WebsocketEvents::FastExecEvent)
+            }
+            (Some("execution"), _) => Self::parse(frame, WebsocketEvents::ExecutionEvent),
+            (Some("order"), _) => Self::parse(frame, WebsocketEvents::OrderEvent),
+            (Some("wallet"), _) => Self::parse(frame, WebsocketEvents::Wallet),
+            _ => WebsocketEvents::Unknown(frame.to_string()),
+        }
+    }
+
+    /// Parses auth replies and subscribe acks into control events, returning `None`
+    /// for any other frame.
+    ///
+    /// Public and private acks do not echo the subscribed topics, so `topics` is
+    /// asked for the topics of the request with the ack's `req_id`.
+    pub(crate) fn control_frame<F>(
+        frame: &Frame<'_>,
+        topics: F,
+    ) -> Option<WebsocketEvents>
+    where
+        F: FnOnce(&str) -> Option<Vec<String>>,
+    {
+        Self::control_event(&frame.envelope, frame.raw, topics)
+    }
+
+    fn control_event<F>(
+        envelope: &FrameEnvelope,
+        frame: &str,
+        topics: F,
+    ) -> Option<WebsocketEvents>
+    where
+        F: FnOnce(&str) -> Option<Vec<String>>,
+    {
+        let op = envelope.op.as_deref();
+        // Option acks carry no `op`, only a `COMMAND_RESP` type.
+        if !matches!(op, Some("auth" | "subscribe"))
+            && envelope.kind.as_deref() != Some("COMMAND_RESP")
+        {
+            return None;
+        }
+        let reply: Value = serde_json::from_str(frame).ok()?;
+        let success = reply["success"]
+            .as_bool()
+            .unwrap_or_else(|| reply["retCode"].as_i64() == Some(0));
+        let reason = reply["ret_msg"]
+            .as_str()
+            .or_else(|| reply["retMsg"].as_str())
+            .unwrap_or_default()
+            .to_string();
+        if op == Some("auth") {
+            return Some(if success {
+                WebsocketEvents::AuthOk
+            } else {
+                WebsocketEvents::AuthFailed(reason)
+            });
+        }
+        let strings = |value: &Value| -> Vec<String> {
+            value
+                .as_array()
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(|v| v.as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let failed = strings(&reply["data"]["failTopics"]);
+        if !failed.is_empty() {
+            return Some(WebsocketEvents::SubscribeFailed(format!(
+                "failed topics: {}",
+                failed.join(", ")
+            )));
+        }
+        if !success {
+            return Some(WebsocketEvents::SubscribeFailed(reason));
+        }
+        let mut subscribed = strings(&reply["data"]["successTopics"]);
+        if subscribed.is_empty() {
+            subscribed = reply["req_id"]
+                .as_str()
+                .and_then(topics)
+                .unwrap_or_default();
+        }
+        Some(WebsocketEvents::Subscribed(subscribed))
+    }
+
+    fn parse<'de, T, F>(frame: &'de str, variant: F) -> WebsocketEvents
+    where
+        T: Deserialize<'de>,
+        F: FnOnce(T) -> WebsocketEvents,
+    {
+        let mut deserializer = serde_json::Deserializer::from_str(frame);
+        match serde_path_to_error::deserialize(&mut deserializer) {
+            Ok(event) => variant(event),
+            // Reads e.g. `data[0].markPrice: invalid float literal`
+            Err(e) => WebsocketEvents::ParseError(FrameError {
+                error: e.to_string(),
+                frame: frame.to_string(),
+            }),
+        }
+    }
 }
 
+/// A websocket text frame with its envelope read: the `topic`, `op` and `type`
+/// fields that decide which event it parses into.
+///
+/// The event loop parses every frame into a `Frame` once, and hands it to both the
+/// control frame check and `WebSocketHandler::handle_frame`, so routing a frame
+/// never parses it twice.
+pub struct Frame<'a> {
+    raw: &'a str,
+    envelope: FrameEnvelope<'a>,
+}
+
+impl<'a> Frame<'a> {
+    /// Reads the envelope of `raw`, borrowing `data` without parsing it.
+    pub fn parse(raw: &'a str) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            raw,
+            envelope: serde_json::from_str(raw)?,
+        })
+    }
+
+    /// The raw frame.
+    pub fn as_str(&self) -> &'a str {
+        self.raw
+    }
+
+    pub fn topic(&self) -> Option<&str> {
+        self.envelope.topic.as_deref()
+    }
+
+    pub fn op(&self) -> Option<&str> {
+        self.envelope.op.as_deref()
+    }
+}
+
+#[derive(Deserialize)]
+struct FrameEnvelope<'a> {
+    #[serde(borrow, default)]
+    topic: Option<Cow<'a, str>>,
+    #[serde(borrow, default)]
+    op: Option<Cow<'a, str>>,
+    #[serde(rename = "type", borrow, default)]
+    kind: Option<Cow<'a, str>>,
+    #[serde(borrow, default)]
+    data: Option<&'a RawValue>,
+}
+
+impl FrameEnvelope<'_> {
+    /// Whether `data` holds option tickers, which carry the underlying price, or
+    /// option trades, which carry the mark IV.
+    fn is_option(&self) -> bool {
+        let Some(data) = self.data else {
+            return false;
+        };
+        let data = data.get();
+        has_key(data, "underlyingPrice") || has_key(data, "mIv")
+    }
+}
+
+/// Whether the JSON text `json` has an object key named `key`, found by scanning
+/// for the quoted name followed by a colon instead of parsing.
+fn has_key(json: &str, key: &str) -> bool {
+    let quoted = format!("\"{}\"", key);
+    json.match_indices(&quoted)
+        .any(|(at, _)| json[at + quoted.len()..].trim_start().starts_with(':'))
+}
+
+/// A websocket frame that could not be parsed, reported as `WebsocketEvents::ParseError`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FrameError {
+    /// The serde error, naming the line and column that failed.
+    pub error: String,
+    /// The raw frame.
+    pub frame: String,
+}
+
+unsafe impl Send for FrameError {}
+unsafe impl Sync for FrameError {}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum Tickers {
@@ -3371,6 +3757,23 @@ pub struct PongData {
 unsafe impl Send for PongData {}
 unsafe impl Sync for PongData {}
 
+/// Describes a reconnect performed by a `Stream` running with a reconnect policy.
+///
+/// Messages published while the socket was down are lost, so consumers should
+/// treat this as a possible gap in the feed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReconnectEvent {
+    /// Number of connection attempts it took to get the socket back.
+    pub attempt: u32,
+    /// The error that caused the previous connection to drop.
+    pub reason: String,
+    /// Local timestamp in milliseconds at which the connection was restored.
+    pub timestamp: u64,
+}
+
+unsafe impl Send for ReconnectEvent {}
+unsafe impl Sync for ReconnectEvent {}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TradeStreamEvent {
@@ -3379,7 +3782,7 @@ pub struct TradeStreamEvent {
     pub ret_code: i32,
     pub ret_msg: String,
     pub op: String,
-    pub data: OrderStatus,
+    pub data: OrderIds,
     pub header: Header,
     pub conn_id: String,
 }
@@ -3443,11 +3846,11 @@ pub struct WsTrade {
     #[serde(rename = "S")]
     pub side: String,
     #[serde(rename = "v", with = "string_to_float")]
-    pub volume: f64,
+    pub volume: Amount,
     #[serde(rename = "p", with = "string_to_float")]
-    pub price: f64,
+    pub price: Amount,
     #[serde(rename = "L")]
-    pub tick_direction: String,
+    pub tick_direction: TickDirection,
     #[serde(rename = "i")]
     pub id: String,
     #[serde(rename = "BT")]
@@ -3457,6 +3860,50 @@ pub struct WsTrade {
 unsafe impl Send for WsTrade {}
 unsafe impl Sync for WsTrade {}
 
+/// Public trades of the option stream. Option order books share the `OrderBookUpdate` format.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OptionTradeUpdate {
+    #[serde(rename = "topic")]
+    pub topic: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    #[serde(rename = "ts")]
+    pub timestamp: u64,
+    pub data: Vec<OptionTrade>,
+}
+
+unsafe impl Send for OptionTradeUpdate {}
+unsafe impl Sync for OptionTradeUpdate {}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OptionTrade {
+    #[serde(rename = "T")]
+    pub timestamp: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "v", with = "string_to_float")]
+    pub volume: Amount,
+    #[serde(rename = "p", with = "string_to_float")]
+    pub price: Amount,
+    #[serde(rename = "i")]
+    pub id: String,
+    #[serde(rename = "BT")]
+    pub buyer_is_maker: bool,
+    #[serde(rename = "mP", with = "string_to_float")]
+    pub mark_price: Amount,
+    #[serde(rename = "iP", with = "string_to_float")]
+    pub index_price: Amount,
+    #[serde(rename = "mIv", with = "string_to_float")]
+    pub mark_iv: Amount,
+    #[serde(rename = "iv", with = "string_to_float")]
+    pub iv: Amount,
+}
+
+unsafe impl Send for OptionTrade {}
+unsafe impl Sync for OptionTrade {}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct WsTicker {
     pub topic: String,
@@ -3474,73 +3921,231 @@ unsafe impl Sync for WsTicker {}
 pub struct LinearTickerData {
     pub symbol: String,
     #[serde(rename = "tickDirection")]
-    pub tick_direction: String,
-    #[serde(rename = "price24hPcnt")]
-    pub price_24h_pcnt: String,
-    #[serde(rename = "lastPrice")]
-    pub last_price: String,
-    #[serde(rename = "prevPrice24h")]
-    pub prev_price_24h: String,
-    #[serde(rename = "highPrice24h")]
-    pub high_price_24h: String,
-    #[serde(rename = "lowPrice24h")]
-    pub low_price_24h: String,
-    #[serde(rename = "prevPrice1h")]
-    pub prev_price_1h: String,
-    #[serde(rename = "markPrice")]
-    pub mark_price: String,
-    #[serde(rename = "indexPrice")]
-    pub index_price: String,
-    #[serde(rename = "openInterest")]
-    pub open_interest: String,
-    #[serde(rename = "openInterestValue")]
-    pub open_interest_value: String,
-    #[serde(rename = "turnover24h")]
-    pub turnover_24h: String,
-    #[serde(rename = "volume24h")]
-    pub volume_24h: String,
-    #[serde(rename = "nextFundingTime")]
-    pub next_funding_time: String,
-    #[serde(rename = "fundingRate")]
-    pub funding_rate: String,
-    #[serde(rename = "bid1Price")]
-    pub bid_price: String,
-    #[serde(rename = "bid1Size")]
-    pub bid_size: String,
-    #[serde(rename = "ask1Price")]
-    pub ask_price: String,
-    #[serde(rename = "ask1Size")]
-    pub ask_size: String,
+    pub tick_direction: TickDirection,
+    #[serde(rename = "price24hPcnt", with = "string_to_float")]
+    pub price_24h_pcnt: Amount,
+    #[serde(rename = "lastPrice", with = "string_to_float")]
+    pub last_price: Amount,
+    #[serde(rename = "prevPrice24h", with = "string_to_float")]
+    pub prev_price_24h: Amount,
+    #[serde(rename = "highPrice24h", with = "string_to_float")]
+    pub high_price_24h: Amount,
+    #[serde(rename = "lowPrice24h", with = "string_to_float")]
+    pub low_price_24h: Amount,
+    #[serde(rename = "prevPrice1h", with = "string_to_float")]
+    pub prev_price_1h: Amount,
+    #[serde(rename = "markPrice", with = "string_to_float")]
+    pub mark_price: Amount,
+    #[serde(rename = "indexPrice", with = "string_to_float")]
+    pub index_price: Amount,
+    #[serde(rename = "openInterest", with = "string_to_float")]
+    pub open_interest: Amount,
+    #[serde(rename = "openInterestValue", with = "string_to_float")]
+    pub open_interest_value: Amount,
+    #[serde(rename = "turnover24h", with = "string_to_float")]
+    pub turnover_24h: Amount,
+    #[serde(rename = "volume24h", with = "string_to_float")]
+    pub volume_24h: Amount,
+    #[serde(rename = "nextFundingTime", with = "string_to_u64")]
+    pub next_funding_time: u64,
+    #[serde(rename = "fundingRate", default, with = "empty_string_as_none")]
+    pub funding_rate: Option<Amount>,
+    #[serde(rename = "bid1Price", with = "string_to_float")]
+    pub bid_price: Amount,
+    #[serde(rename = "bid1Size", with = "string_to_float")]
+    pub bid_size: Amount,
+    #[serde(rename = "ask1Price", with = "string_to_float")]
+    pub ask_price: Amount,
+    #[serde(rename = "ask1Size", with = "string_to_float")]
+    pub ask_size: Amount,
 }
 
 unsafe impl Send for LinearTickerData {}
 unsafe impl Sync for LinearTickerData {}
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TickerDelta {
+    pub topic: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// The changed fields, keyed like `LinearTickerData`. Always holds `symbol`.
+    pub data: serde_json::Map<String, Value>,
+    pub cs: u64,
+    pub ts: u64,
+}
+
+unsafe impl Send for TickerDelta {}
+unsafe impl Sync for TickerDelta {}
+
+/// Latest complete linear and inverse tickers, kept by merging ticker deltas into
+/// the last snapshot of each symbol.
+///
+/// Clones share the same state, so one clone can be read with `get` while a
+/// stream updates another.
+#[derive(Clone, Debug, Default)]
+pub struct TickerCache {
+    tickers: Arc<RwLock<HashMap<String, LinearTickerData>>>,
+}
+
+impl TickerCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the state of the snapshot's symbol.
+    pub fn apply_snapshot(&self, ticker: &LinearTickerData) {
+        self.tickers
+            .write()
+            .unwrap()
+            .insert(ticker.symbol.clone(), ticker.clone());
+    }
+
+    /// Merges `delta` into the last snapshot of its symbol and returns the result.
+    ///
+    /// Fails if no snapshot of the symbol has been applied yet.
+    pub fn apply_delta(
+        &self,
+        delta: &TickerDelta,
+    ) -> Result<LinearTickerData, BybitError> {
+        let symbol = delta
+            .data
+            .get("symbol")
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                BybitError::Base(format!("Ticker delta on {} has no symbol", delta.topic))
+            })?;
+        let mut tickers = self.tickers.write().unwrap();
+        let ticker = tickers
+            .get_mut(symbol)
+            .ok_or_else(|| BybitError::MissingTickerSnapshot(symbol.to_string()))?;
+        let mut merged = serde_json::to_value(&*ticker)?;
+        if let Some(fields) = merged.as_object_mut() {
+            fields.extend(delta.data.clone());
+        }
+        *ticker = from_value(merged)?;
+        Ok(ticker.clone())
+    }
+
+    /// Applies a `TickerEvent` with linear data or a `TickerDeltaEvent`, returning
+    /// the complete ticker. Other events return `None`.
+    pub fn apply(
+        &self,
+        event: &WebsocketEvents,
+    ) -> Option<Result<LinearTickerData, BybitError>> {
+        match event {
+            WebsocketEvents::TickerEvent(WsTicker {
+                data: Tickers::Linear(ticker),
+                ..
+            }) => {
+                self.apply_snapshot(ticker);
+                Some(Ok(ticker.clone()))
+            }
+            WebsocketEvents::TickerDeltaEvent(delta) => Some(self.apply_delta(delta)),
+            _ => None,
+        }
+    }
+
+    /// Current state of `symbol`, if a snapshot of it has been applied.
+    pub fn get(&self, symbol: &str) -> Option<LinearTickerData> {
+        self.tickers.read().unwrap().get(symbol).cloned()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SpotTickerData {
     #[serde(rename = "symbol")]
     pub symbol: String,
-    #[serde(rename = "lastPrice")]
-    pub last_price: String,
-    #[serde(rename = "highPrice24h")]
-    pub high_price_24h: String,
-    #[serde(rename = "lowPrice24h")]
-    pub low_price_24h: String,
-    #[serde(rename = "prevPrice24h")]
-    pub prev_price_24h: String,
-    #[serde(rename = "volume24h")]
-    pub volume_24h: String,
-    #[serde(rename = "turnover24h")]
-    pub turnover_24h: String,
-    #[serde(rename = "price24hPcnt")]
-    pub price_24h_pcnt: String,
-    #[serde(rename = "usdIndexPrice")]
-    pub usd_index_price: String,
+    #[serde(rename = "lastPrice", with = "string_to_float")]
+    pub last_price: Amount,
+    #[serde(rename = "highPrice24h", with = "string_to_float")]
+    pub high_price_24h: Amount,
+    #[serde(rename = "lowPrice24h", with = "string_to_float")]
+    pub low_price_24h: Amount,
+    #[serde(rename = "prevPrice24h", with = "string_to_float")]
+    pub prev_price_24h: Amount,
+    #[serde(rename = "volume24h", with = "string_to_float")]
+    pub volume_24h: Amount,
+    #[serde(rename = "turnover24h", with = "string_to_float")]
+    pub turnover_24h: Amount,
+    #[serde(rename = "price24hPcnt", with = "string_to_float")]
+    pub price_24h_pcnt: Amount,
+    #[serde(rename = "usdIndexPrice", default, with = "empty_string_as_none")]
+    pub usd_index_price: Option<Amount>,
 }
 
 unsafe impl Send for SpotTickerData {}
 unsafe impl Sync for SpotTickerData {}
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OptionTickerUpdate {
+    pub id: String,
+    pub topic: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub ts: u64,
+    pub data: OptionTickerData,
+}
+
+unsafe impl Send for OptionTickerUpdate {}
+unsafe impl Sync for OptionTickerUpdate {}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionTickerData {
+    pub symbol: String,
+    #[serde(with = "string_to_float")]
+    pub bid_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub bid_size: Amount,
+    #[serde(with = "string_to_float")]
+    pub bid_iv: Amount,
+    #[serde(with = "string_to_float")]
+    pub ask_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub ask_size: Amount,
+    #[serde(with = "string_to_float")]
+    pub ask_iv: Amount,
+    #[serde(with = "string_to_float")]
+    pub last_price: Amount,
+    #[serde(rename = "highPrice24h", with = "string_to_float")]
+    pub high_price_24h: Amount,
+    #[serde(rename = "lowPrice24h", with = "string_to_float")]
+    pub low_price_24h: Amount,
+    #[serde(with = "string_to_float")]
+    pub mark_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub index_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub mark_price_iv: Amount,
+    #[serde(with = "string_to_float")]
+    pub underlying_price: Amount,
+    #[serde(with = "string_to_float")]
+    pub open_interest: Amount,
+    #[serde(rename = "turnover24h", with = "string_to_float")]
+    pub turnover_24h: Amount,
+    #[serde(rename = "volume24h", with = "string_to_float")]
+    pub volume_24h: Amount,
+    #[serde(with = "string_to_float")]
+    pub total_volume: Amount,
+    #[serde(with = "string_to_float")]
+    pub total_turnover: Amount,
+    #[serde(with = "string_to_float")]
+    pub delta: Amount,
+    #[serde(with = "string_to_float")]
+    pub gamma: Amount,
+    #[serde(with = "string_to_float")]
+    pub vega: Amount,
+    #[serde(with = "string_to_float")]
+    pub theta: Amount,
+    #[serde(with = "string_to_float")]
+    pub predicted_delivery_price: Amount,
+    #[serde(rename = "change24h", with = "string_to_float")]
+    pub change_24h: Amount,
+}
+
+unsafe impl Send for OptionTickerData {}
+unsafe impl Sync for OptionTickerData {}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Liquidation {
     #[serde(rename = "topic")]
@@ -3565,9 +4170,9 @@ pub struct LiquidationData {
     #[serde(rename = "side")]
     pub side: String,
     #[serde(with = "string_to_float")]
-    pub size: f64,
+    pub size: Amount,
     #[serde(with = "string_to_float")]
-    pub price: f64,
+    pub price: Amount,
 }
 
 unsafe impl Send for LiquidationData {}
@@ -3591,12 +4196,18 @@ pub struct KlineData {
     pub start: u64,
     pub end: u64,
     pub interval: String,
-    pub open: String,
-    pub close: String,
-    pub high: String,
-    pub low: String,
-    pub volume: String,
-    pub turnover: String,
+    #[serde(with = "string_to_float")]
+    pub open: Amount,
+    #[serde(with = "string_to_float")]
+    pub close: Amount,
+    #[serde(with = "string_to_float")]
+    pub high: Amount,
+    #[serde(with = "string_to_float")]
+    pub low: Amount,
+    #[serde(with = "string_to_float")]
+    pub volume: Amount,
+    #[serde(with = "string_to_float")]
+    pub turnover: Amount,
     pub confirm: bool,
     pub timestamp: u64,
 }
@@ -3619,49 +4230,51 @@ unsafe impl Sync for PositionEvent {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PositionData {
     #[serde(rename = "positionIdx")]
-    pub position_idx: u8,
+    pub position_idx: PositionIdx,
     #[serde(rename = "tradeMode")]
     pub trade_mode: u8,
     #[serde(rename = "riskId")]
     pub risk_id: u8,
-    #[serde(rename = "riskLimitValue")]
-    pub risk_limit_value: String,
+    #[serde(rename = "riskLimitValue", with = "string_to_float")]
+    pub risk_limit_value: Amount,
     pub symbol: String,
     pub side: String,
-    pub size: String,
-    #[serde(rename = "entryPrice")]
-    pub entry_price: String,
-    pub leverage: String,
-    #[serde(rename = "positionValue")]
-    pub position_value: String,
-    #[serde(rename = "positionBalance")]
-    pub position_balance: String,
-    #[serde(rename = "markPrice")]
-    pub mark_price: String,
-    #[serde(rename = "positionIM")]
-    pub position_im: String,
-    #[serde(rename = "positionMM")]
-    pub position_mm: String,
-    #[serde(rename = "takeProfit")]
-    pub take_profit: String,
-    #[serde(rename = "stopLoss")]
-    pub stop_loss: String,
-    #[serde(rename = "trailingStop")]
-    pub trailing_stop: String,
-    #[serde(rename = "unrealisedPnl")]
-    pub unrealised_pnl: String,
-    #[serde(rename = "cumRealisedPnl")]
-    pub cum_realised_pnl: String,
-    #[serde(rename = "createdTime")]
-    pub created_time: String,
-    #[serde(rename = "updatedTime")]
-    pub updated_time: String,
-    #[serde(rename = "tpslMode")]
-    pub tpsl_mode: String,
-    #[serde(rename = "liqPrice")]
-    pub liq_price: String,
-    #[serde(rename = "bustPrice")]
-    pub bust_price: String,
+    #[serde(with = "string_to_float")]
+    pub size: Amount,
+    #[serde(rename = "entryPrice", with = "string_to_float")]
+    pub entry_price: Amount,
+    #[serde(default, with = "empty_string_as_none")]
+    pub leverage: Option<Amount>,
+    #[serde(rename = "positionValue", with = "string_to_float")]
+    pub position_value: Amount,
+    #[serde(rename = "positionBalance", with = "string_to_float")]
+    pub position_balance: Amount,
+    #[serde(rename = "markPrice", with = "string_to_float")]
+    pub mark_price: Amount,
+    #[serde(rename = "positionIM", default, with = "empty_string_as_none")]
+    pub position_im: Option<Amount>,
+    #[serde(rename = "positionMM", default, with = "empty_string_as_none")]
+    pub position_mm: Option<Amount>,
+    #[serde(rename = "takeProfit", with = "string_to_float")]
+    pub take_profit: Amount,
+    #[serde(rename = "stopLoss", with = "string_to_float")]
+    pub stop_loss: Amount,
+    #[serde(rename = "trailingStop", with = "string_to_float")]
+    pub trailing_stop: Amount,
+    #[serde(rename = "unrealisedPnl", with = "string_to_float")]
+    pub unrealised_pnl: Amount,
+    #[serde(rename = "cumRealisedPnl", with = "string_to_float")]
+    pub cum_realised_pnl: Amount,
+    #[serde(rename = "createdTime", with = "string_to_u64")]
+    pub created_time: u64,
+    #[serde(rename = "updatedTime", with = "string_to_u64")]
+    pub updated_time: u64,
+    #[serde(rename = "tpslMode", default, with = "empty_string_as_none")]
+    pub tpsl_mode: Option<TpSlMode>,
+    #[serde(rename = "liqPrice", default, with = "empty_string_as_none")]
+    pub liq_price: Option<Amount>,
+    #[serde(rename = "bustPrice", default, with = "empty_string_as_none")]
+    pub bust_price: Option<Amount>,
     pub category: String,
     #[serde(rename = "positionStatus")]
     pub position_status: String,
@@ -3669,10 +4282,14 @@ pub struct PositionData {
     pub adl_rank_indicator: u8,
     #[serde(rename = "autoAddMargin")]
     pub auto_add_margin: u8,
-    #[serde(rename = "leverageSysUpdatedTime")]
-    pub leverage_sys_updated_time: String,
-    #[serde(rename = "mmrSysUpdatedTime")]
-    pub mmr_sys_updated_time: String,
+    #[serde(
+        rename = "leverageSysUpdatedTime",
+        default,
+        with = "empty_string_as_none"
+    )]
+    pub leverage_sys_updated_time: Option<u64>,
+    #[serde(rename = "mmrSysUpdatedTime", default, with = "empty_string_as_none")]
+    pub mmr_sys_updated_time: Option<u64>,
     pub seq: u64,
     #[serde(rename = "isReduceOnly")]
     pub is_reduce_only: bool,
@@ -3702,56 +4319,56 @@ pub struct ExecutionData {
     pub category: String,
     #[serde(rename = "symbol")]
     pub symbol: String,
-    #[serde(rename = "execFee")]
-    pub exec_fee: String,
+    #[serde(rename = "execFee", with = "string_to_float")]
+    pub exec_fee: Amount,
     #[serde(rename = "execId")]
     pub exec_id: String,
-    #[serde(rename = "execPrice")]
-    pub exec_price: String,
-    #[serde(rename = "execQty")]
-    pub exec_qty: String,
+    #[serde(rename = "execPrice", with = "string_to_float")]
+    pub exec_price: Amount,
+    #[serde(rename = "execQty", with = "string_to_float")]
+    pub exec_qty: Amount,
     #[serde(rename = "execType")]
     pub exec_type: String,
-    #[serde(rename = "execValue")]
-    pub exec_value: String,
+    #[serde(rename = "execValue", with = "string_to_float")]
+    pub exec_value: Amount,
     #[serde(rename = "isMaker")]
     pub is_maker: bool,
-    #[serde(rename = "feeRate")]
-    pub fee_rate: String,
-    #[serde(rename = "tradeIv")]
-    pub trade_iv: String,
-    #[serde(rename = "markIv")]
-    pub mark_iv: String,
+    #[serde(rename = "feeRate", with = "string_to_float")]
+    pub fee_rate: Amount,
+    #[serde(rename = "tradeIv", default, with = "empty_string_as_none")]
+    pub trade_iv: Option<Amount>,
+    #[serde(rename = "markIv", default, with = "empty_string_as_none")]
+    pub mark_iv: Option<Amount>,
     #[serde(rename = "blockTradeId")]
     pub block_trade_id: String,
-    #[serde(rename = "markPrice")]
-    pub mark_price: String,
-    #[serde(rename = "indexPrice")]
-    pub index_price: String,
-    #[serde(rename = "underlyingPrice")]
-    pub underlying_price: String,
-    #[serde(rename = "leavesQty")]
-    pub leaves_qty: String,
+    #[serde(rename = "markPrice", with = "string_to_float")]
+    pub mark_price: Amount,
+    #[serde(rename = "indexPrice", default, with = "empty_string_as_none")]
+    pub index_price: Option<Amount>,
+    #[serde(rename = "underlyingPrice", default, with = "empty_string_as_none")]
+    pub underlying_price: Option<Amount>,
+    #[serde(rename = "leavesQty", with = "string_to_float")]
+    pub leaves_qty: Amount,
     #[serde(rename = "orderId")]
     pub order_id: String,
     #[serde(rename = "orderLinkId")]
     pub order_link_id: String,
-    #[serde(rename = "orderPrice")]
-    pub order_price: String,
-    #[serde(rename = "orderQty")]
-    pub order_qty: String,
+    #[serde(rename = "orderPrice", with = "string_to_float")]
+    pub order_price: Amount,
+    #[serde(rename = "orderQty", with = "string_to_float")]
+    pub order_qty: Amount,
     #[serde(rename = "orderType")]
-    pub order_type: String,
-    #[serde(rename = "stopOrderType")]
-    pub stop_order_type: String,
+    pub order_type: OrderType,
+    #[serde(rename = "stopOrderType", default, with = "empty_string_as_none")]
+    pub stop_order_type: Option<StopOrderType>,
     #[serde(rename = "side")]
-    pub side: String,
-    #[serde(rename = "execTime")]
-    pub exec_time: String,
+    pub side: Side,
+    #[serde(rename = "execTime", with = "string_to_u64")]
+    pub exec_time: u64,
     #[serde(rename = "isLeverage")]
     pub is_leverage: String,
-    #[serde(rename = "closedSize")]
-    pub closed_size: String,
+    #[serde(rename = "closedSize", default, with = "empty_string_as_none")]
+    pub closed_size: Option<Amount>,
     #[serde(rename = "seq")]
     pub seq: u64,
 }
@@ -3776,17 +4393,17 @@ pub struct FastExecData {
     pub symbol: String,
     #[serde(rename = "execId")]
     pub exec_id: String,
-    #[serde(rename = "execPrice")]
-    pub exec_price: String,
-    #[serde(rename = "execQty")]
-    pub exec_qty: String,
+    #[serde(rename = "execPrice", with = "string_to_float")]
+    pub exec_price: Amount,
+    #[serde(rename = "execQty", with = "string_to_float")]
+    pub exec_qty: Amount,
     #[serde(rename = "orderId")]
     pub order_id: String,
     #[serde(rename = "orderLinkId")]
     pub order_link_id: String,
-    pub side: String,
-    #[serde(rename = "execTime")]
-    pub exec_time: String,
+    pub side: Side,
+    #[serde(rename = "execTime", with = "string_to_u64")]
+    pub exec_time: u64,
     pub seq: u64,
 }
 
@@ -3798,76 +4415,78 @@ pub struct OrderData {
     pub symbol: String,
     #[serde(rename = "orderId")]
     pub order_id: String,
-    pub side: String,
+    pub side: Side,
     #[serde(rename = "orderType")]
-    pub order_type: String,
+    pub order_type: OrderType,
     #[serde(rename = "cancelType")]
     pub cancel_type: String,
-    pub price: String,
-    pub qty: String,
+    #[serde(with = "string_to_float")]
+    pub price: Amount,
+    #[serde(with = "string_to_float")]
+    pub qty: Amount,
     #[serde(rename = "orderIv")]
     pub order_iv: String,
     #[serde(rename = "timeInForce")]
-    pub time_in_force: String,
+    pub time_in_force: TimeInForce,
     #[serde(rename = "orderStatus")]
-    pub order_status: String,
+    pub order_status: OrderState,
     #[serde(rename = "orderLinkId")]
     pub order_link_id: String,
-    #[serde(rename = "lastPriceOnCreated")]
-    pub last_price_on_created: String,
+    #[serde(rename = "lastPriceOnCreated", with = "string_to_float")]
+    pub last_price_on_created: Amount,
     #[serde(rename = "reduceOnly")]
     pub reduce_only: bool,
-    #[serde(rename = "leavesQty")]
-    pub leaves_qty: String,
-    #[serde(rename = "leavesValue")]
-    pub leaves_value: String,
-    #[serde(rename = "cumExecQty")]
-    pub cum_exec_qty: String,
-    #[serde(rename = "cumExecValue")]
-    pub cum_exec_value: String,
-    #[serde(rename = "avgPrice")]
-    pub avg_price: String,
+    #[serde(rename = "leavesQty", with = "string_to_float")]
+    pub leaves_qty: Amount,
+    #[serde(rename = "leavesValue", with = "string_to_float")]
+    pub leaves_value: Amount,
+    #[serde(rename = "cumExecQty", with = "string_to_float")]
+    pub cum_exec_qty: Amount,
+    #[serde(rename = "cumExecValue", with = "string_to_float")]
+    pub cum_exec_value: Amount,
+    #[serde(rename = "avgPrice", default, with = "empty_string_as_none")]
+    pub avg_price: Option<Amount>,
     #[serde(rename = "blockTradeId")]
     pub block_trade_id: String,
     #[serde(rename = "positionIdx")]
-    pub position_idx: u8,
-    #[serde(rename = "cumExecFee")]
-    pub cum_exec_fee: String,
-    #[serde(rename = "createdTime")]
-    pub created_time: String,
-    #[serde(rename = "updatedTime")]
-    pub updated_time: String,
+    pub position_idx: PositionIdx,
+    #[serde(rename = "cumExecFee", with = "string_to_float")]
+    pub cum_exec_fee: Amount,
+    #[serde(rename = "createdTime", with = "string_to_u64")]
+    pub created_time: u64,
+    #[serde(rename = "updatedTime", with = "string_to_u64")]
+    pub updated_time: u64,
     #[serde(rename = "rejectReason")]
     pub reject_reason: String,
-    #[serde(rename = "stopOrderType")]
-    pub stop_order_type: String,
-    #[serde(rename = "tpslMode")]
-    pub tpsl_mode: String,
-    #[serde(rename = "triggerPrice")]
-    pub trigger_price: String,
-    #[serde(rename = "takeProfit")]
-    pub take_profit: String,
-    #[serde(rename = "stopLoss")]
-    pub stop_loss: String,
-    #[serde(rename = "tpTriggerBy")]
-    pub tp_trigger_by: String,
-    #[serde(rename = "slTriggerBy")]
-    pub sl_trigger_by: String,
-    #[serde(rename = "tpLimitPrice")]
-    pub tp_limit_price: String,
-    #[serde(rename = "slLimitPrice")]
-    pub sl_limit_price: String,
+    #[serde(rename = "stopOrderType", default, with = "empty_string_as_none")]
+    pub stop_order_type: Option<StopOrderType>,
+    #[serde(rename = "tpslMode", default, with = "empty_string_as_none")]
+    pub tpsl_mode: Option<TpSlMode>,
+    #[serde(rename = "triggerPrice", default, with = "empty_string_as_none")]
+    pub trigger_price: Option<Amount>,
+    #[serde(rename = "takeProfit", default, with = "empty_string_as_none")]
+    pub take_profit: Option<Amount>,
+    #[serde(rename = "stopLoss", default, with = "empty_string_as_none")]
+    pub stop_loss: Option<Amount>,
+    #[serde(rename = "tpTriggerBy", default, with = "empty_string_as_none")]
+    pub tp_trigger_by: Option<TriggerBy>,
+    #[serde(rename = "slTriggerBy", default, with = "empty_string_as_none")]
+    pub sl_trigger_by: Option<TriggerBy>,
+    #[serde(rename = "tpLimitPrice", default, with = "empty_string_as_none")]
+    pub tp_limit_price: Option<Amount>,
+    #[serde(rename = "slLimitPrice", default, with = "empty_string_as_none")]
+    pub sl_limit_price: Option<Amount>,
     #[serde(rename = "triggerDirection")]
     pub trigger_direction: u8,
-    #[serde(rename = "triggerBy")]
-    pub trigger_by: String,
+    #[serde(rename = "triggerBy", default, with = "empty_string_as_none")]
+    pub trigger_by: Option<TriggerBy>,
     #[serde(rename = "closeOnTrigger")]
     pub close_on_trigger: bool,
     pub category: String,
     #[serde(rename = "placeType")]
     pub place_type: String,
     #[serde(rename = "smpType")]
-    pub smp_type: String,
+    pub smp_type: SmpType,
     #[serde(rename = "smpGroup")]
     pub smp_group: u8,
     #[serde(rename = "smpOrderId")]
@@ -3904,28 +4523,28 @@ unsafe impl Sync for WalletEvent {}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct WalletData {
-    #[serde(rename = "accountIMRate")]
-    pub account_im_rate: String,
-    #[serde(rename = "accountMMRate")]
-    pub account_mm_rate: String,
-    #[serde(rename = "totalEquity")]
-    pub total_equity: String,
-    #[serde(rename = "totalWalletBalance")]
-    pub total_wallet_balance: String,
-    #[serde(rename = "totalMarginBalance")]
-    pub total_margin_balance: String,
-    #[serde(rename = "totalAvailableBalance")]
-    pub total_available_balance: String,
-    #[serde(rename = "totalPerpUPL")]
-    pub total_perp_upl: String,
-    #[serde(rename = "totalInitialMargin")]
-    pub total_initial_margin: String,
-    #[serde(rename = "totalMaintenanceMargin")]
-    pub total_maintenance_margin: String,
+    #[serde(rename = "accountIMRate", default, with = "empty_string_as_none")]
+    pub account_im_rate: Option<Amount>,
+    #[serde(rename = "accountMMRate", default, with = "empty_string_as_none")]
+    pub account_mm_rate: Option<Amount>,
+    #[serde(rename = "totalEquity", with = "string_to_float")]
+    pub total_equity: Amount,
+    #[serde(rename = "totalWalletBalance", with = "string_to_float")]
+    pub total_wallet_balance: Amount,
+    #[serde(rename = "totalMarginBalance", with = "string_to_float")]
+    pub total_margin_balance: Amount,
+    #[serde(rename = "totalAvailableBalance", with = "string_to_float")]
+    pub total_available_balance: Amount,
+    #[serde(rename = "totalPerpUPL", with = "string_to_float")]
+    pub total_perp_upl: Amount,
+    #[serde(rename = "totalInitialMargin", with = "string_to_float")]
+    pub total_initial_margin: Amount,
+    #[serde(rename = "totalMaintenanceMargin", with = "string_to_float")]
+    pub total_maintenance_margin: Amount,
     #[serde(rename = "coin")]
     pub coin: Vec<CoinData>,
-    #[serde(rename = "accountLTV")]
-    pub account_ltv: String,
+    #[serde(rename = "accountLTV", default, with = "empty_string_as_none")]
+    pub account_ltv: Option<Amount>,
     #[serde(rename = "accountType", skip_serializing_if = "Option::is_none")]
     pub account_type: Option<String>,
 }
@@ -3935,39 +4554,40 @@ unsafe impl Sync for WalletData {}
 pub struct CoinData {
     #[serde(rename = "coin")]
     pub coin: String,
-    #[serde(rename = "equity")]
-    pub equity: String,
-    #[serde(rename = "usdValue")]
-    pub usd_value: String,
-    #[serde(rename = "walletBalance")]
-    pub wallet_balance: String,
-    #[serde(rename = "availableToWithdraw")]
-    pub available_to_withdraw: String,
-    #[serde(rename = "availableToBorrow")]
-    pub available_to_borrow: String,
-    #[serde(rename = "borrowAmount")]
-    pub borrow_amount: String,
-    #[serde(rename = "accruedInterest")]
-    pub accrued_interest: String,
-    #[serde(rename = "totalOrderIM")]
-    pub total_order_im: String,
-    #[serde(rename = "totalPositionIM")]
-    pub total_position_im: String,
-    #[serde(rename = "totalPositionMM")]
-    pub total_position_mm: String,
-    #[serde(rename = "unrealisedPnl")]
-    pub unrealised_pnl: String,
-    #[serde(rename = "cumRealisedPnl")]
-    pub cum_realised_pnl: String,
-    pub bonus: String,
+    #[serde(rename = "equity", with = "string_to_float")]
+    pub equity: Amount,
+    #[serde(rename = "usdValue", with = "string_to_float")]
+    pub usd_value: Amount,
+    #[serde(rename = "walletBalance", with = "string_to_float")]
+    pub wallet_balance: Amount,
+    #[serde(rename = "availableToWithdraw", default, with = "empty_string_as_none")]
+    pub available_to_withdraw: Option<Amount>,
+    #[serde(rename = "availableToBorrow", default, with = "empty_string_as_none")]
+    pub available_to_borrow: Option<Amount>,
+    #[serde(rename = "borrowAmount", with = "string_to_float")]
+    pub borrow_amount: Amount,
+    #[serde(rename = "accruedInterest", with = "string_to_float")]
+    pub accrued_interest: Amount,
+    #[serde(rename = "totalOrderIM", default, with = "empty_string_as_none")]
+    pub total_order_im: Option<Amount>,
+    #[serde(rename = "totalPositionIM", default, with = "empty_string_as_none")]
+    pub total_position_im: Option<Amount>,
+    #[serde(rename = "totalPositionMM", default, with = "empty_string_as_none")]
+    pub total_position_mm: Option<Amount>,
+    #[serde(rename = "unrealisedPnl", with = "string_to_float")]
+    pub unrealised_pnl: Amount,
+    #[serde(rename = "cumRealisedPnl", with = "string_to_float")]
+    pub cum_realised_pnl: Amount,
+    #[serde(default, with = "empty_string_as_none")]
+    pub bonus: Option<Amount>,
     #[serde(rename = "collateralSwitch")]
     pub collateral_switch: bool,
     #[serde(rename = "marginCollateral")]
     pub margin_collateral: bool,
-    #[serde(rename = "locked")]
-    pub locked: String,
-    #[serde(rename = "spotHedgingQty")]
-    pub spot_hedging_qty: String,
+    #[serde(rename = "locked", with = "string_to_float")]
+    pub locked: Amount,
+    #[serde(rename = "spotHedgingQty", default, with = "empty_string_as_none")]
+    pub spot_hedging_qty: Option<Amount>,
 }
 
 unsafe impl Send for CoinData {}
@@ -4002,6 +4622,47 @@ mod string_to_u64 {
     }
 }
 
+/// Reads an empty string, `null` or a missing field as `None` and parses any other
+/// string with `FromStr`, into a number or a string enum. Bybit sends `""` for values
+/// that do not apply, such as the liquidation price of an empty position.
+mod empty_string_as_none {
+    use serde::{
+        self,
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+    use std::{
+        fmt::Display,
+        str::FromStr,
+    };
+
+    // Serialize `None` as an empty string.
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => serializer.collect_str(value),
+            None => serializer.serialize_str(""),
+        }
+    }
+
+    // Deserialize an empty string as `None`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)?.as_deref() {
+            None | Some("") => Ok(None),
+            Some(s) => s.parse::<T>().map(Some).map_err(serde::de::Error::custom),
+        }
+    }
+}
+
 mod string_to_float {
     use serde::{
         self,
@@ -4009,23 +4670,29 @@ mod string_to_float {
         Deserializer,
         Serializer,
     };
+    use std::{
+        fmt::Display,
+        str::FromStr,
+    };
 
-    // Serialize a u64 as a string.
-    pub fn serialize<S>(value: &f64, serializer: S) -> Result<S::Ok, S::Error>
+    // Serialize a number as a string.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
     where
+        T: Display,
         S: Serializer,
     {
-        let s = value.to_string();
-        serializer.serialize_str(&s)
+        serializer.collect_str(value)
     }
 
-    // Deserialize a string as an f64.
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    // Deserialize a string as a number.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
     where
+        T: FromStr,
+        T::Err: Display,
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        s.parse::<f64>().map_err(serde::de::Error::custom)
+        s.parse::<T>().map_err(serde::de::Error::custom)
     }
 }
 
@@ -4041,8 +4708,8 @@ pub struct QuoteApply<'a> {
     pub to_coin_type: Option<Cow<'a, str>>,
     #[serde(rename = "requestCoin")]
     pub request_coin: Cow<'a, str>,
-    #[serde(rename = "requestAmount")]
-    pub request_amount: String,
+    #[serde(rename = "requestAmount", with = "string_to_float")]
+    pub request_amount: Amount,
     #[serde(rename = "accountType")]
     pub account_type: Cow<'a, str>,
     // #[serde(rename = "quoteType")]
@@ -4054,7 +4721,7 @@ impl<'a> QuoteApply<'a> {
         from_coin: &'a str,
         to_coin: &'a str,
         request_coin: &'a str,
-        request_amount: String,
+        request_amount: Amount,
         account_type: &'a str,
     ) -> Self {
         const COIN_TYPE: &str = "crypto";
@@ -4081,20 +4748,15 @@ pub struct QuoteApplyResponse {
     pub from_coin_type: String,
     pub to_coin: String,
     pub to_coin_type: String,
-    pub from_amount: String,
-    pub to_amount: String,
+    #[serde(with = "string_to_float")]
+    pub from_amount: Amount,
+    #[serde(with = "string_to_float")]
+    pub to_amount: Amount,
     pub expired_time: String,
     pub request_id: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct ConvertCommonResponse<R> {
-    pub ret_code: u64,
-    pub ret_msg: String,
-    pub result: R,
-    pub time: u64,
-}
+pub type ConvertCommonResponse<R> = ApiResponse<R>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -4107,7 +4769,10 @@ pub struct AllCoinsResponse {
 #[serde(rename_all = "camelCase")]
 pub struct CoinResponse {
     pub coin: String,
-    pub wallet_balance: String,
-    pub transfer_balance: String,
-    pub bonus: String,
+    #[serde(with = "string_to_float")]
+    pub wallet_balance: Amount,
+    #[serde(with = "string_to_float")]
+    pub transfer_balance: Amount,
+    #[serde(default, with = "empty_string_as_none")]
+    pub bonus: Option<Amount>,
 }
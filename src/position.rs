@@ -217,10 +217,10 @@ impl PositionManager {
         parameters.insert("category".into(), req.category.as_str().into());
         parameters.insert("symbol".into(), req.symbol.into());
         if let Some(v) = req.take_profit {
-            parameters.insert("takeProfit".into(), v.into());
+            parameters.insert("takeProfit".into(), v.to_string().into());
         }
         if let Some(v) = req.stop_loss {
-            parameters.insert("stopLoss".into(), v.into());
+            parameters.insert("stopLoss".into(), v.to_string().into());
         }
         if let Some(v) = req.tp_trigger_by {
             parameters.insert("tpTriggerBy".into(), v.into());
@@ -238,16 +238,16 @@ impl PositionManager {
             parameters.insert("slOrderType".into(), v.as_str().into());
         }
         if let Some(v) = req.tp_size {
-            parameters.insert("tpSize".into(), v.into());
+            parameters.insert("tpSize".into(), v.to_string().into());
         }
         if let Some(v) = req.sl_size {
-            parameters.insert("slSize".into(), v.into());
+            parameters.insert("slSize".into(), v.to_string().into());
         }
         if let Some(v) = req.tp_limit_price {
-            parameters.insert("tpLimitPrice".into(), v.into());
+            parameters.insert("tpLimitPrice".into(), v.to_string().into());
         }
         if let Some(v) = req.sl_limit_price {
-            parameters.insert("slLimitPrice".into(), v.into());
+            parameters.insert("slLimitPrice".into(), v.to_string().into());
         }
         parameters.insert("positionIdx".into(), req.position_idx.into());
         let request = build_json_request(&parameters);
@@ -332,7 +332,7 @@ impl PositionManager {
         parameters.insert("symbol".into(), req.symbol.into());
 
         // Add the margin parameter
-        parameters.insert("margin".into(), req.margin.into());
+        parameters.insert("margin".into(), req.margin.to_string().into());
 
         // Add the positionIdx parameter if it is not None
         if let Some(v) = req.position_idx {
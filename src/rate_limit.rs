@@ -0,0 +1,278 @@
+use crate::{
+    api::API,
+    clock::ServerClock,
+    model::{
+        Category,
+        Header,
+    },
+};
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+use tokio::{
+    sync::Notify,
+    time::{
+        sleep,
+        Duration,
+    },
+};
+
+/// Length in milliseconds assumed for a rate limit window whose reset time has
+/// passed before the server reported the next one. Bybit limits are per second.
+const DEFAULT_WINDOW_MS: u64 = 1000;
+
+/// Endpoints whose quota Bybit counts per category, with the name of their group.
+///
+/// Every other endpoint has a quota of its own, shared by all categories. The
+/// trade stream draws from the same groups as the REST order endpoints.
+const CATEGORY_GROUPS: [(&str, &str); 12] = [
+    ("/v5/order/create", "order.create"),
+    ("/v5/order/amend", "order.amend"),
+    ("/v5/order/cancel", "order.cancel"),
+    ("/v5/order/cancel-all", "order.cancel-all"),
+    ("/v5/order/create-batch", "order.create-batch"),
+    ("/v5/order/amend-batch", "order.amend-batch"),
+    ("/v5/order/cancel-batch", "order.cancel-batch"),
+    ("/v5/order/realtime", "order.realtime"),
+    ("/v5/order/history", "order.history"),
+    ("/v5/execution/list", "execution.list"),
+    ("/v5/position/list", "position.list"),
+    ("/v5/position/closed-pnl", "position.closed-pnl"),
+];
+
+/// The quota a request draws from: an endpoint group and, for the groups in
+/// `CATEGORY_GROUPS`, the category of the request.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LimitKey {
+    /// Name of the group in `CATEGORY_GROUPS`, or the path of the endpoint.
+    pub group: Cow<'static, str>,
+    /// `None` for groups limited across categories.
+    pub category: Option<Category>,
+}
+
+impl LimitKey {
+    /// The quota of `endpoint` for requests in `category`.
+    pub fn new(endpoint: API, category: Option<Category>) -> Self {
+        Self::of(endpoint.as_ref(), category)
+    }
+
+    pub(crate) fn of(path: &str, category: Option<Category>) -> Self {
+        let path = format!("/{}", path.trim_start_matches('/'));
+        match CATEGORY_GROUPS.iter().find(|(route, _)| *route == path) {
+            Some((_, group)) => Self {
+                group: Cow::Borrowed(group),
+                category,
+            },
+            None => Self {
+                group: Cow::Owned(path),
+                category: None,
+            },
+        }
+    }
+
+    /// The quota of a request to `path` with the query string `query`.
+    pub(crate) fn from_query(path: &str, query: &str) -> Self {
+        let category = query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find_map(|(key, value)| (key == "category").then(|| parse_category(value)))
+            .flatten();
+        Self::of(path, category)
+    }
+
+    /// The quota of a request to `path` with the JSON body `body`.
+    pub(crate) fn from_body(path: &str, body: &str) -> Self {
+        let category = serde_json::from_str::<Value>(body)
+            .ok()
+            .and_then(|body| body["category"].as_str().and_then(parse_category));
+        Self::of(path, category)
+    }
+}
+
+fn parse_category(value: &str) -> Option<Category> {
+    match value.to_ascii_lowercase().as_str() {
+        "spot" => Some(Category::Spot),
+        "linear" => Some(Category::Linear),
+        "inverse" => Some(Category::Inverse),
+        "option" => Some(Category::Option),
+        _ => None,
+    }
+}
+
+/// The quota of one `LimitKey`, as last reported by Bybit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests allowed per window, from `X-Bapi-Limit`.
+    pub limit: u32,
+    /// Requests left in the current window, from `X-Bapi-Limit-Status`, minus the
+    /// requests sent since.
+    pub remaining: u32,
+    /// Timestamp in milliseconds at which the window resets, from
+    /// `X-Bapi-Limit-Reset-Timestamp`.
+    pub reset_at: u64,
+}
+
+/// Token buckets keyed by `LimitKey`, fed by the `X-Bapi-Limit-*` headers of REST
+/// responses and trade stream replies.
+///
+/// A request whose budget is used up waits until the window resets instead of
+/// being rejected with retCode `10006`. A quota is only enforced once Bybit has
+/// reported it. Clones share the same buckets, so every module built from one
+/// `Client` draws from the same budget.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<LimitKey, Bucket>>>,
+    /// Wakes waiting requests when Bybit reports a new window.
+    updated: Arc<Notify>,
+    /// Compared against the reset timestamps, which come from the server clock.
+    clock: ServerClock,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Bucket {
+    status: RateLimit,
+    /// Whether `reset_at` was guessed locally rather than reported by Bybit.
+    estimated: bool,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A limiter reading the current time from `clock`.
+    pub fn with_clock(clock: ServerClock) -> Self {
+        Self {
+            clock,
+            ..Self::default()
+        }
+    }
+
+    /// The current quota of `endpoint` for requests in `category`, if Bybit has
+    /// reported one. `category` is ignored for endpoints limited across categories.
+    pub fn status(&self, endpoint: API, category: Option<Category>) -> Option<RateLimit> {
+        let buckets = self.buckets.lock().unwrap();
+        buckets
+            .get(&LimitKey::new(endpoint, category))
+            .map(|bucket| bucket.status)
+    }
+
+    /// Requests left in the current window of `endpoint` for `category`, if known.
+    pub fn remaining(&self, endpoint: API, category: Option<Category>) -> Option<u32> {
+        self.status(endpoint, category)
+            .map(|status| status.remaining)
+    }
+
+    /// Every quota seen so far.
+    pub fn statuses(&self) -> HashMap<LimitKey, RateLimit> {
+        let buckets = self.buckets.lock().unwrap();
+        buckets
+            .iter()
+            .map(|(key, bucket)| (key.clone(), bucket.status))
+            .collect()
+    }
+
+    /// Takes a token from the bucket of `key`, waiting for the window to reset if
+    /// none is left.
+    pub(crate) async fn acquire(&self, key: &LimitKey) {
+        loop {
+            let updated = self.updated.notified();
+            let wait = {
+                let mut buckets = self.buckets.lock().unwrap();
+                let Some(bucket) = buckets.get_mut(key) else {
+                    return;
+                };
+                let status = &mut bucket.status;
+                let now = self.clock.now();
+                if status.remaining > 0 {
+                    status.remaining -= 1;
+                    return;
+                }
+                if now >= status.reset_at {
+                    status.remaining = status.limit.saturating_sub(1);
+                    status.reset_at = now + DEFAULT_WINDOW_MS;
+                    bucket.estimated = true;
+                    return;
+                }
+                status.reset_at - now
+            };
+            tracing::debug!("Rate limit of {:?} reached, waiting {}ms", key, wait);
+            tokio::select! {
+                _ = sleep(Duration::from_millis(wait)) => {}
+                _ = updated => {}
+            }
+        }
+    }
+
+    /// Updates the bucket of `key` from the headers of a REST response.
+    pub(crate) fn update(&self, key: &LimitKey, headers: &HeaderMap) {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        self.update_with(
+            key,
+            header("x-bapi-limit"),
+            header("x-bapi-limit-status"),
+            header("x-bapi-limit-reset-timestamp"),
+        );
+    }
+
+    /// Updates the bucket of `key` from the header of a trade stream reply.
+    pub(crate) fn update_from_header(&self, key: &LimitKey, header: &Header) {
+        self.update_with(
+            key,
+            Some(&header.x_bapi_limit),
+            Some(&header.x_bapi_limit_status),
+            Some(&header.x_bapi_limit_reset_timestamp),
+        );
+    }
+
+    fn update_with(
+        &self,
+        key: &LimitKey,
+        limit: Option<&str>,
+        remaining: Option<&str>,
+        reset_at: Option<&str>,
+    ) {
+        let (Some(limit), Some(remaining), Some(reset_at)) = (
+            limit.and_then(|v| v.parse().ok()),
+            remaining.and_then(|v| v.parse().ok()),
+            reset_at.and_then(|v| v.parse().ok()),
+        ) else {
+            return;
+        };
+        let reported = RateLimit {
+            limit,
+            remaining,
+            reset_at,
+        };
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(key.clone()).or_insert(Bucket {
+            status: reported,
+            estimated: false,
+        });
+        // Replies of the same window can arrive out of order, and requests sent
+        // after this one already took their tokens, so only a new window reported
+        // by Bybit may raise the budget.
+        let status = &mut bucket.status;
+        if bucket.estimated && reset_at > self.clock.now() {
+            // First reply of the window the bucket was refilled for locally.
+            status.limit = limit;
+            status.remaining = status.remaining.min(remaining);
+            status.reset_at = reset_at;
+            bucket.estimated = false;
+            self.updated.notify_waiters();
+        } else if reset_at > status.reset_at {
+            *status = reported;
+            self.updated.notify_waiters();
+        } else if reset_at == status.reset_at {
+            status.limit = limit;
+            status.remaining = status.remaining.min(remaining);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{
+    errors::BybitError,
+    model::{
+        Frame,
+        ReconnectEvent,
+        WebsocketEvents,
+    },
+    util::get_timestamp,
+    ws::{
+        Stream,
+        WebSocketHandler,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    fs::File,
+    io::{
+        BufRead,
+        BufReader,
+        BufWriter,
+        Write,
+    },
+    path::Path,
+    task::{
+        Context,
+        Poll,
+    },
+};
+use tokio::{
+    sync::mpsc,
+    task::JoinHandle,
+    time::{
+        sleep_until,
+        Duration,
+        Instant,
+    },
+};
+
+/// One inbound text frame, as written by `FrameRecorder`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecordedFrame {
+    /// Local receive time in milliseconds since the Unix epoch.
+    pub ts: u64,
+    /// The raw frame text.
+    pub frame: String,
+}
+
+/// Handler wrapper that writes every inbound text frame to a newline-delimited file
+/// before passing it on to the wrapped handler.
+///
+/// Pass it to `Stream::event_loop` in place of the handler it wraps; the file can
+/// later be fed back through a handler with `FrameReplay`.
+///
+/// Frames are handed to a blocking writer task over a channel, so the event loop
+/// never waits on the disk. The task buffers its writes and flushes whenever it
+/// catches up with the connection. `finish` waits for every queued frame to be
+/// written.
+///
+/// # Example
+///
+/// ```no_run
+/// use bybit::{errors::BybitError, model::WebsocketEvents, replay::FrameRecorder};
+///
+/// # async fn run() -> Result<(), BybitError> {
+/// let handler = |event: WebsocketEvents| -> Result<(), BybitError> {
+///     println!("{:?}", event);
+///     Ok(())
+/// };
+/// let recorder = FrameRecorder::create("frames.jsonl", handler)?;
+/// // ... run the event loop with `recorder`, then:
+/// let (handler, file) = recorder.finish().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct FrameRecorder<H, W = File> {
+    handler: H,
+    frames: mpsc::UnboundedSender<RecordedFrame>,
+    writer: JoinHandle<Result<W, BybitError>>,
+}
+
+impl<H> FrameRecorder<H> {
+    /// Creates or truncates the file at `path` and records into it, one line per frame.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn create<P: AsRef<Path>>(path: P, handler: H) -> Result<Self, BybitError> {
+        Ok(Self::new(File::create(path)?, handler))
+    }
+}
+
+impl<H, W> FrameRecorder<H, W>
+where
+    W: Write + Send + 'static,
+{
+    /// Records into `writer` from a blocking task.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn new(writer: W, handler: H) -> Self {
+        let (frames, receiver) = mpsc::unbounded_channel();
+        let writer = tokio::task::spawn_blocking(move || Self::write(writer, receiver));
+        Self {
+            handler,
+            frames,
+            writer,
+        }
+    }
+
+    /// Waits for every recorded frame to be written, then returns the wrapped
+    /// handler and the writer, or the first error the writer ran into.
+    pub async fn finish(self) -> Result<(H, W), BybitError> {
+        drop(self.frames);
+        let writer = self
+            .writer
+            .await
+            .map_err(|e| BybitError::Base(format!("Frame writer panicked: {}", e)))??;
+        Ok((self.handler, writer))
+    }
+
+    /// Body of the writer task: writes frames until every sender is dropped.
+    fn write(
+        writer: W,
+        mut receiver: mpsc::UnboundedReceiver<RecordedFrame>,
+    ) -> Result<W, BybitError> {
+        let mut writer = BufWriter::new(writer);
+        while let Some(frame) = receiver.blocking_recv() {
+            serde_json::to_writer(&mut writer, &frame)?;
+            writer.write_all(b"\n")?;
+            if receiver.is_empty() {
+                writer.flush()?;
+            }
+        }
+        writer.into_inner().map_err(|e| e.into_error().into())
+    }
+}
+
+impl<H, W> FrameRecorder<H, W> {
+    fn record(&mut self, frame: &str) -> Result<(), BybitError> {
+        let frame = RecordedFrame {
+            ts: get_timestamp(),
+            frame: frame.to_string(),
+        };
+        // The writer task only stops early on an error, which `finish` returns.
+        self.frames
+            .send(frame)
+            .map_err(|_| BybitError::Base("Frame writer has stopped".to_string()))
+    }
+}
+
+impl<H, W> WebSocketHandler for FrameRecorder<H, W>
+where
+    H: WebSocketHandler,
+{
+    type Event = H::Event;
+    fn handle_msg(&mut self, msg: &str) -> Result<(), BybitError> {
+        self.record(msg)?;
+        self.handler.handle_msg(msg)
+    }
+
+    fn handle_frame(&mut self, frame: &Frame<'_>) -> Result<(), BybitError> {
+        self.record(frame.as_str())?;
+        self.handler.handle_frame(frame)
+    }
+
+    fn handle_reconnect(&mut self, event: ReconnectEvent) -> Result<(), BybitError> {
+        self.handler.handle_reconnect(event)
+    }
+
+    fn handle_control(
+        &mut self,
+        event: WebsocketEvents,
+        msg: &str,
+    ) -> Result<(), BybitError> {
+        self.record(msg)?;
+        self.handler.handle_control(event, msg)
+    }
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), BybitError>> {
+        self.handler.poll_ready(cx)
+    }
+}
+
+/// Feeds frames captured by `FrameRecorder` back through a `WebSocketHandler`,
+/// dispatching them exactly as `Stream::event_loop` does.
+#[derive(Debug, Clone)]
+pub struct FrameReplay {
+    pub frames: Vec<RecordedFrame>,
+    /// Playback speed relative to the recording. `1.0` keeps the original gaps
+    /// between frames, `2.0` halves them, and `0.0` disables the delays.
+    pub speed: f64,
+}
+
+impl FrameReplay {
+    pub fn new(frames: Vec<RecordedFrame>) -> Self {
+        Self { frames, speed: 1.0 }
+    }
+
+    /// Reads a recording from the file at `path`.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, BybitError> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a recording, skipping blank lines.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, BybitError> {
+        let mut frames = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                frames.push(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(Self::new(frames))
+    }
+
+    pub const fn set_speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Dispatches every frame to `handler`, waiting between frames according to
+    /// their recorded timestamps and `speed`.
+    ///
+    /// Stops at the first error returned by the handler, or with `AuthFailed` on a
+    /// failed auth reply, as the live event loop would.
+    pub async fn run<H>(&self, mut handler: H) -> Result<(), BybitError>
+    where
+        H: WebSocketHandler,
+    {
+        let start = Instant::now();
+        let first = self.frames.first().map_or(0, |frame| frame.ts);
+        for frame in &self.frames {
+            if self.speed > 0.0 {
+                let offset = frame.ts.saturating_sub(first) as f64 / self.speed;
+                sleep_until(start + Duration::from_secs_f64(offset / 1000.0)).await;
+            }
+            std::future::poll_fn(|cx| handler.poll_ready(cx)).await?;
+            Stream::replay_frame(&mut handler, &frame.frame)?;
+        }
+        Ok(())
+    }
+}
@@ -2,13 +2,14 @@ use serde_json::{json, Value};
 
 use crate::api::{Trade, API};
 use crate::client::Client;
-use crate::errors::BybitError;
+use crate::errors::{BybitError, BybitErrorCode};
 use crate::model::{
-    AmendOrderRequest, AmendOrderResponse, BatchAmendRequest, BatchAmendResponse,
+    Amount, AmendOrderRequest, AmendOrderResponse, BatchAmendRequest, BatchAmendResponse,
     BatchCancelRequest, BatchCancelResponse, BatchPlaceRequest, BatchPlaceResponse,
-    CancelOrderRequest, CancelOrderResponse, CancelallRequest, CancelallResponse, Category,
+    CancelOrderRequest, CancelOrderResponse, CancelallRequest, CancelallResponse, Category, Empty,
     OpenOrdersRequest, OpenOrdersResponse, OrderHistoryRequest, OrderHistoryResponse, OrderRequest,
-    OrderResponse, OrderType, RequestType, Side, TradeHistoryRequest, TradeHistoryResponse,
+    OrderIds, OrderResponse, OrderType, PositionIdx, RequestType, Side, TradeHistoryRequest,
+    TradeHistoryResponse,
 };
 use crate::util::{build_json_request, build_request, date_to_milliseconds, generate_random_uid};
 
@@ -71,23 +72,124 @@ pub enum Action<'a> {
 }
 
 impl Trader {
+    /// Places an order.
+    ///
+    /// If the client has a retry policy and the order carries an `order_link_id`,
+    /// a request that failed after possibly reaching Bybit is not blindly resent:
+    /// the order is first looked up by its `order_link_id` and returned if it was
+    /// placed, and only resent otherwise. A resend rejected because the
+    /// `order_link_id` is already used means an earlier attempt went through, so the
+    /// order is looked up again and returned.
+    ///
+    /// The lookup cannot prove that an order was never placed: the order history
+    /// lags behind placement, so an order that went through just before a timeout
+    /// may not be found yet. Resending is then only safe because Bybit rejects a
+    /// reused `order_link_id`, which is why the order must carry one. If the order
+    /// is still missing after that rejection, the rejection is returned.
     pub async fn place_custom_order<'b>(
         &self,
         req: OrderRequest<'_>,
     ) -> Result<OrderResponse, BybitError> {
+        let category = req.category;
+        let symbol = req.symbol.clone().into_owned();
+        let order_link_id = req.order_link_id.clone().map(Cow::into_owned);
         let action = Action::Order(req, false);
         let parameters = Self::build_orders(action);
 
         let request = build_json_request(&parameters);
-        let response: OrderResponse = self
-            .client
-            .post_signed(
-                API::Trade(Trade::Place),
-                self.recv_window.into(),
-                Some(request),
-            )
-            .await?;
-        Ok(response)
+        let mut attempt = 0;
+        loop {
+            let result: Result<OrderResponse, BybitError> = self
+                .client
+                .post_signed(
+                    API::Trade(Trade::Place),
+                    self.recv_window.into(),
+                    Some(request.clone()),
+                )
+                .await;
+            // Errors that are safe to resend were already retried by the client
+            let err = match result {
+                Err(err) if err.is_retryable() && !err.is_safe_to_resend() => err,
+                Err(err) if attempt > 0 && is_duplicate_order_link_id(&err) => {
+                    let order_link_id = order_link_id.as_deref().unwrap_or_default();
+                    return match self.find_order(category, &symbol, order_link_id).await {
+                        Ok(Some(response)) => Ok(response),
+                        _ => Err(err),
+                    };
+                }
+                result => return result,
+            };
+            let (Some(policy), Some(order_link_id)) =
+                (&self.client.retry, &order_link_id)
+            else {
+                return Err(err);
+            };
+            if attempt >= policy.max_retries {
+                return Err(err);
+            }
+            attempt += 1;
+            tokio::time::sleep(policy.delay(attempt)).await;
+            match self.find_order(category, &symbol, order_link_id).await {
+                Ok(Some(response)) => return Ok(response),
+                // Not found yet; a resend of a placed order is rejected as a duplicate
+                Ok(None) => tracing::warn!(
+                    "Order {} was not found: {}, resending ({}/{})",
+                    order_link_id,
+                    err,
+                    attempt,
+                    policy.max_retries
+                ),
+                // The order may or may not exist, resending could duplicate it
+                Err(_) => return Err(err),
+            }
+        }
+    }
+
+    /// Looks up an order by its `order_link_id` among the open orders, then in the
+    /// order history in case it was already filled or cancelled, returning it in the
+    /// shape of a successful placement.
+    async fn find_order(
+        &self,
+        category: Category,
+        symbol: &str,
+        order_link_id: &str,
+    ) -> Result<Option<OrderResponse>, BybitError> {
+        let req = OpenOrdersRequest {
+            category,
+            symbol: Cow::Borrowed(symbol),
+            order_link_id: Some(Cow::Borrowed(order_link_id)),
+            ..OpenOrdersRequest::default()
+        };
+        let mut response = self.get_open_orders(req).await?;
+        if !response
+            .result
+            .list
+            .iter()
+            .any(|order| order.order_link_id == order_link_id)
+        {
+            let req = OrderHistoryRequest {
+                category,
+                symbol: Some(Cow::Borrowed(symbol)),
+                order_link_id: Some(Cow::Borrowed(order_link_id)),
+                ..OrderHistoryRequest::default()
+            };
+            response = self.get_order_history(req).await?;
+        }
+        let order = response
+            .result
+            .list
+            .into_iter()
+            .find(|order| order.order_link_id == order_link_id);
+        Ok(order.map(|order| OrderResponse {
+            ret_code: 0,
+            ret_msg: "OK".into(),
+            result: OrderIds {
+                order_id: order.order_id,
+                order_link_id: order.order_link_id,
+            },
+            ret_ext_info: Empty {},
+            time: response.time,
+        }))
     }
 
     pub async fn place_futures_limit_order(
@@ -95,8 +197,8 @@ impl Trader {
         category: Category,
         symbol: &str,
         side: Side,
-        qty: f64,
-        price: f64,
+        qty: Amount,
+        price: Amount,
         mode: u8,
     ) -> Result<OrderResponse, BybitError> {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
@@ -106,7 +208,7 @@ impl Trader {
             side,
             qty,
             order_type: OrderType::Limit,
-            position_idx: Some(mode),
+            position_idx: Some(PositionIdx::from(mode)),
             order_link_id: Some(generate_random_uid(36).into()),
             price: Some(price),
             ..Default::default()
@@ -121,10 +223,12 @@ impl Trader {
         parameters.insert("qty".into(), req.qty.to_string());
         if let Some(v) = req.position_idx {
             match v {
-                0 | 1 | 2 => {
+                PositionIdx::Unknown(_) => {
+                    return Err(BybitError::from("Invalid position index".to_string()))
+                }
+                _ => {
                     parameters.insert("positionIdx".into(), v.to_string());
                 }
-                _ => return Err(BybitError::from("Invalid position index".to_string())),
             }
         }
         if let Some(v) = req.price {
@@ -204,7 +308,7 @@ impl Trader {
             }
         }
         if let Some(order_filter) = req.order_filter {
-            parameters.insert("orderFilter".into(), order_filter.into());
+            parameters.insert("orderFilter".into(), order_filter.as_str().into());
         }
         if let Some(limit) = req.limit {
             parameters.insert("limit".into(), limit.to_string().into());
@@ -232,10 +336,10 @@ impl Trader {
             parameters.insert("settleCoin".into(), settle_coin.into());
         }
         if let Some(order_filter) = req.order_filter {
-            parameters.insert("orderFilter".into(), order_filter.into());
+            parameters.insert("orderFilter".into(), order_filter.as_str().into());
         }
         if let Some(stop_order_type) = req.stop_order_type {
-            parameters.insert("stopOrderType".into(), stop_order_type.into());
+            parameters.insert("stopOrderType".into(), stop_order_type.as_str().into());
         }
         let request = build_json_request(&parameters);
         let response: CancelallResponse = self
@@ -275,9 +379,9 @@ impl Trader {
         req.order_link_id
             .map(|order_link_id| parameters.insert("orderLinkId".into(), order_link_id.into()));
         req.order_filter
-            .map(|order_filter| parameters.insert("orderFilter".into(), order_filter.into()));
+            .map(|order_filter| parameters.insert("orderFilter".into(), order_filter.as_str().into()));
         req.order_status
-            .map(|order_status| parameters.insert("orderStatus".into(), order_status.into()));
+            .map(|order_status| parameters.insert("orderStatus".into(), order_status.as_str().into()));
         req.start_time
             .and_then(|start_time| Some(date_to_milliseconds(start_time.as_ref())))
             .map(|start_millis| parameters.insert("startTime".into(), start_millis.to_string()));
@@ -593,26 +697,29 @@ impl Trader {
                     }
                 }
                 if let Some(order_filter) = req.order_filter {
-                    parameters.insert("orderFilter".into(), order_filter.into());
+                    parameters.insert("orderFilter".into(), order_filter.as_str().into());
                 }
                 if let Some(trigger_price) = req.trigger_price {
                     parameters.insert("triggerPrice".into(), trigger_price.to_string().into());
                 }
                 if let Some(trigger) = req.trigger_by {
-                    parameters.insert("triggerBy".into(), trigger.into());
+                    parameters.insert("triggerBy".into(), trigger.as_str().into());
                 }
                 if let Some(iv) = req.order_iv {
                     parameters.insert("orderIv".into(), iv.to_string().into());
                 }
                 if let Some(time_in_force) = req.time_in_force {
-                    parameters.insert("timeInForce".into(), time_in_force.into());
+                    parameters
+                        .insert("timeInForce".into(), time_in_force.as_str().into());
                 }
                 if let Some(v) = req.position_idx {
                     match v {
-                        0 | 1 | 2 => {
+                        PositionIdx::Unknown(ref idx) => {
+                            tracing::warn!("Invalid position idx {}, leaving it out", idx)
+                        }
+                        _ => {
                             parameters.insert("positionIdx".into(), v.to_string().into());
                         }
-                        _ => println!("Invalid position idx"),
                     }
                 }
                 if let Some(order_link_id) = req.order_link_id {
@@ -628,10 +735,10 @@ impl Trader {
                     parameters.insert("stopLoss".into(), price.to_string().into());
                 }
                 if let Some(kind) = req.tp_trigger_by {
-                    parameters.insert("tpTriggerBy".into(), kind.into());
+                    parameters.insert("tpTriggerBy".into(), kind.as_str().into());
                 }
                 if let Some(kind) = req.sl_trigger_by {
-                    parameters.insert("slTriggerBy".into(), kind.into());
+                    parameters.insert("slTriggerBy".into(), kind.as_str().into());
                 }
                 if let Some(reduce) = req.reduce_only {
                     parameters.insert("reduceOnly".into(), reduce.into());
@@ -639,11 +746,14 @@ impl Trader {
                 if let Some(close) = req.close_on_trigger {
                     parameters.insert("closeOnTrigger".into(), close.into());
                 }
+                if let Some(v) = req.smp_type {
+                    parameters.insert("smpType".into(), v.as_str().into());
+                }
                 if let Some(v) = req.mmp {
                     parameters.insert("mmp".into(), v.into());
                 }
                 if let Some(v) = req.tpsl_mode {
-                    parameters.insert("tpslMode".into(), v.into());
+                    parameters.insert("tpslMode".into(), v.as_str().into());
                 }
                 if let Some(v) = req.tp_limit_price {
                     parameters.insert("tpTriggerPrice".into(), v.to_string().into());
@@ -675,12 +785,12 @@ impl Trader {
                 if let Some(v) = req.trigger_price {
                     parameters.insert("triggerPrice".into(), v.to_string().into());
                 }
-                parameters.insert("qty".into(), req.qty.into());
+                parameters.insert("qty".into(), req.qty.to_string().into());
                 if let Some(v) = req.price {
                     parameters.insert("price".into(), v.to_string().into());
                 }
                 if let Some(v) = req.tpsl_mode {
-                    parameters.insert("tpslMode".into(), v.into());
+                    parameters.insert("tpslMode".into(), v.as_str().into());
                 }
                 if let Some(v) = req.take_profit {
                     parameters.insert("takeProfit".into(), v.to_string().into());
@@ -689,13 +799,13 @@ impl Trader {
                     parameters.insert("stopLoss".into(), v.to_string().into());
                 }
                 if let Some(v) = req.tp_trigger_by {
-                    parameters.insert("tpTriggerBy".into(), v.into());
+                    parameters.insert("tpTriggerBy".into(), v.as_str().into());
                 }
                 if let Some(v) = req.sl_trigger_by {
-                    parameters.insert("slTriggerBy".into(), v.into());
+                    parameters.insert("slTriggerBy".into(), v.as_str().into());
                 }
                 if let Some(v) = req.trigger_by {
-                    parameters.insert("triggerBy".into(), v.into());
+                    parameters.insert("triggerBy".into(), v.as_str().into());
                 }
                 if let Some(v) = req.tp_limit_price {
                     parameters.insert("tpLimitPrice".into(), v.to_string().into());
@@ -716,7 +826,7 @@ impl Trader {
                     parameters.insert("orderLinkId".into(), v.into());
                 }
                 if let Some(v) = req.order_filter {
-                    parameters.insert("orderFilter".into(), v.into());
+                    parameters.insert("orderFilter".into(), v.as_str().into());
                 }
             }
         }
@@ -756,3 +866,14 @@ pub fn build_ws_orders<'a>(orders: RequestType) -> Value {
         }
     }
 }
+
+/// Whether Bybit rejected an order because its `orderLinkId` is already used.
+fn is_duplicate_order_link_id(err: &BybitError) -> bool {
+    matches!(
+        err.error_code(),
+        Some(
+            BybitErrorCode::DuplicateOrderLinkId
+                | BybitErrorCode::SpotDuplicateOrderLinkId
+        )
+    )
+}
@@ -0,0 +1,109 @@
+use crate::errors::{
+    BybitError,
+    TransportErrorKind,
+};
+use futures::future::BoxFuture;
+use reqwest::{
+    header::HeaderMap,
+    Client as ReqwestClient,
+    Method,
+    StatusCode,
+};
+use std::error::Error;
+
+/// A REST request built and signed by `Client`, ready to be sent.
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+    pub method: Method,
+    /// The full URL, including the query string.
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<String>,
+}
+
+/// The reply to an `HttpRequest`, with the body read to the end.
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// Sends the REST requests of a `Client`.
+///
+/// `Client` signs every request, and handles the responses, rate limits and retries
+/// itself, so a transport only moves bytes. Any `reqwest::Client` is a transport,
+/// which allows plugging in one built with timeouts, a proxy or a `local_address`.
+/// Other implementations can record traffic or answer from memory in tests.
+///
+/// # Example
+///
+/// ```no_run
+/// use bybit::{api::Bybit, general::General};
+/// use std::time::Duration;
+///
+/// let transport = reqwest::Client::builder()
+///     .timeout(Duration::from_secs(5))
+///     .tcp_keepalive(Duration::from_secs(30))
+///     .build()
+///     .unwrap();
+/// let general: General = Bybit::new(None, None);
+/// let general = General {
+///     client: general.client.with_transport(transport),
+/// };
+/// ```
+pub trait Transport: Send + Sync {
+    /// Sends `request` and reads its response.
+    ///
+    /// Errors are passed on to the caller of the `Client` method. Report a request
+    /// that got no response as a `BybitError::Transport` whose `kind` says whether it
+    /// was sent: only `Connect` failures are resent for requests that are not
+    /// idempotent, and `Connect` and `Timeout` failures for the others.
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, BybitError>>;
+}
+
+impl Transport for ReqwestClient {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, BybitError>> {
+        Box::pin(async move {
+            let mut builder = self
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+            let response = builder.send().await.map_err(transport_error)?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.text().await.map_err(transport_error)?;
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+fn transport_error(err: reqwest::Error) -> BybitError {
+    let kind = if err.is_connect() {
+        TransportErrorKind::Connect
+    } else if err.is_timeout() {
+        TransportErrorKind::Timeout
+    } else {
+        TransportErrorKind::Other
+    };
+    // reqwest keeps the cause, e.g. a DNS failure, out of its own message.
+    let mut message = err.to_string();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        message = format!("{}: {}", message, cause);
+        source = cause.source();
+    }
+    BybitError::Transport { kind, message }
+}
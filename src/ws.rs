@@ -1,24 +1,47 @@
 use crate::{
-    api::WebsocketAPI,
+    api::{
+        Trade,
+        WebsocketAPI,
+        API,
+    },
+    channel::EventSender,
     client::Client,
+    clock::ServerClock,
     errors::BybitError,
     model::{
+        AmendOrderRequest,
+        BatchAmendRequest,
+        BatchCancelRequest,
+        BatchPlaceRequest,
+        CancelOrderRequest,
         Category,
         ExecutionData,
         FastExecData,
+        Frame,
+        Header,
         LiquidationData,
+        OptionTickerData,
+        OptionTrade,
         OrderBookUpdate,
         OrderData,
+        OrderIds,
+        OrderRequest,
         PongResponse,
         PositionData,
+        ReconnectEvent,
         RequestType,
         Subscription,
+        TickerCache,
         Tickers,
         WalletData,
         WebsocketEvents,
         WsKline,
         WsTrade,
     },
+    rate_limit::{
+        LimitKey,
+        RateLimiter,
+    },
     trade::build_ws_orders,
     util::{
         build_json_request,
@@ -35,13 +58,36 @@ use serde_json::{
     Value,
 };
 use std::{
-    collections::BTreeMap,
-    time::Instant,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        HashMap,
+    },
+    marker::PhantomData,
+    pin::Pin,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    task::{
+        Context,
+        Poll,
+    },
 };
 use tokio::{
     net::TcpStream,
-    sync::mpsc,
-    time::Duration,
+    sync::{
+        mpsc,
+        oneshot,
+    },
+    task::{
+        JoinHandle,
+        JoinSet,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tokio_tungstenite::{
     tungstenite::Message as WsMessage,
@@ -52,9 +98,640 @@ use tokio_tungstenite::{
 #[derive(Clone)]
 pub struct Stream {
     pub client: Client,
+    /// When set, `ws_subscribe` and `ws_priv_subscribe` reconnect and replay their
+    /// subscriptions instead of returning once the socket drops.
+    pub reconnect: Option<ReconnectPolicy>,
+    /// Ping interval and dead-connection threshold used by every connection.
+    pub heartbeat: HeartbeatPolicy,
+}
+
+/// Backoff settings used by a `Stream` to re-establish dropped connections.
+///
+/// The delay before attempt `n` is `initial_delay * multiplier^(n - 1)`, capped at
+/// `max_delay`. With `max_attempts` set to `None` the stream retries forever.
+#[derive(Clone, Debug)]
+pub struct ReconnectPolicy {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+    pub max_attempts: Option<u32>,
+}
+
+impl ReconnectPolicy {
+    pub const fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            max_attempts: None,
+        }
+    }
+
+    pub const fn set_initial_delay(self, initial_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            ..self
+        }
+    }
+
+    pub const fn set_max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay, ..self }
+    }
+
+    pub const fn set_multiplier(self, multiplier: f64) -> Self {
+        Self { multiplier, ..self }
+    }
+
+    pub const fn set_max_attempts(self, max_attempts: Option<u32>) -> Self {
+        Self {
+            max_attempts,
+            ..self
+        }
+    }
+
+    /// Returns how long to wait before the given (1-based) reconnect attempt.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        if delay.is_finite() && delay < self.max_delay.as_secs_f64() {
+            Duration::from_secs_f64(delay)
+        } else {
+            self.max_delay
+        }
+    }
+
+    /// Returns `true` once `attempt` is past the allowed number of attempts.
+    pub fn exhausted(&self, attempt: u32) -> bool {
+        self.max_attempts.is_some_and(|max| attempt > max)
+    }
+}
+
+/// Keep-alive settings used by every connection opened through a `Stream`.
+///
+/// A ping is sent every `interval` whether or not traffic arrives. A ping still
+/// unanswered when the next one is due counts as a missed pong, and the connection
+/// is declared dead once `max_missed` pongs in a row are missed.
+#[derive(Clone, Debug)]
+pub struct HeartbeatPolicy {
+    pub interval: Duration,
+    pub max_missed: u32,
+}
+
+impl HeartbeatPolicy {
+    pub const fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(20),
+            max_missed: 3,
+        }
+    }
+
+    pub const fn set_interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+
+    pub const fn set_max_missed(self, max_missed: u32) -> Self {
+        Self { max_missed, ..self }
+    }
+}
+
+/// Limits used by `Stream::subscribe_sharded` to split topics across connections.
+///
+/// Each connection takes at most `max_topics_per_connection` topics, and never more
+/// than Bybit's 21,000 character limit on the combined length of its args. Its
+/// topics are sent in subscribe requests of at most `max_args_per_request` args.
+#[derive(Clone, Debug)]
+pub struct ShardPolicy {
+    pub max_args_per_request: usize,
+    pub max_topics_per_connection: usize,
+}
+
+impl ShardPolicy {
+    /// 10 args per request, which Bybit enforces on spot, and 200 topics per
+    /// connection.
+    pub const fn default() -> Self {
+        Self {
+            max_args_per_request: SPOT_MAX_ARGS_PER_REQUEST,
+            max_topics_per_connection: 200,
+        }
+    }
+
+    pub const fn set_max_args_per_request(self, max_args_per_request: usize) -> Self {
+        Self {
+            max_args_per_request,
+            ..self
+        }
+    }
+
+    pub const fn set_max_topics_per_connection(
+        self,
+        max_topics_per_connection: usize,
+    ) -> Self {
+        Self {
+            max_topics_per_connection,
+            ..self
+        }
+    }
+
+    /// Splits `topics` into the topic lists of each connection, keeping their order
+    /// and dropping duplicates.
+    pub fn shard(&self, topics: &[&str]) -> Vec<Vec<String>> {
+        let max_topics = self.max_topics_per_connection.max(1);
+        let mut seen = BTreeSet::new();
+        let mut shards: Vec<Vec<String>> = Vec::new();
+        let mut length = 0;
+        for topic in topics.iter().filter(|topic| seen.insert(**topic)) {
+            match shards.last_mut() {
+                Some(shard)
+                    if shard.len() < max_topics
+                        && length + topic.len() <= MAX_ARGS_LENGTH =>
+                {
+                    length += topic.len();
+                    shard.push(topic.to_string());
+                }
+                _ => {
+                    length = topic.len();
+                    shards.push(vec![topic.to_string()]);
+                }
+            }
+        }
+        shards
+    }
+
+    /// Builds the subscribe requests for one connection's topics.
+    fn requests(&self, shard: &[String]) -> Vec<String> {
+        shard
+            .chunks(self.max_args_per_request.max(1))
+            .map(|chunk| {
+                Stream::build_subscription(Subscription::new(
+                    "subscribe",
+                    chunk.iter().map(String::as_str).collect(),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Ping round-trip times measured by the heartbeat of one connection, read from
+/// the `EventStream`, `SubscriptionHandle` or `WsTrader` that owns it.
+#[derive(Clone, Debug, Default)]
+pub struct LatencyStats {
+    /// Round-trip time of the latest pong.
+    pub last: Option<Duration>,
+    pub min: Option<Duration>,
+    pub max: Option<Duration>,
+    pub average: Option<Duration>,
+    /// Number of pongs received.
+    pub samples: u64,
+    /// Pongs missed in a row.
+    pub missed_pongs: u32,
+}
+
+/// Latency of a connection, shared between its task and its handles. A reconnected
+/// connection keeps recording into the same stats.
+type SharedLatency = Arc<Mutex<LatencyStats>>;
+
+impl LatencyStats {
+    fn record(&mut self, rtt: Duration) {
+        self.samples += 1;
+        self.last = Some(rtt);
+        self.min = Some(self.min.map_or(rtt, |min| min.min(rtt)));
+        self.max = Some(self.max.map_or(rtt, |max| max.max(rtt)));
+        let average = self.average.unwrap_or(rtt).as_secs_f64();
+        let average = average + (rtt.as_secs_f64() - average) / self.samples as f64;
+        self.average = Some(Duration::from_secs_f64(average));
+        self.missed_pongs = 0;
+    }
+}
+
+/// Per-connection heartbeat state driven by the event loop.
+struct Heartbeat {
+    policy: HeartbeatPolicy,
+    ping_sent: Option<Instant>,
+    missed: u32,
+    latency: SharedLatency,
+}
+
+impl Heartbeat {
+    fn new(policy: HeartbeatPolicy, latency: SharedLatency) -> Self {
+        Self {
+            policy,
+            ping_sent: None,
+            missed: 0,
+            latency,
+        }
+    }
+
+    fn interval(&self) -> tokio::time::Interval {
+        let mut interval = tokio::time::interval(self.policy.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        interval.reset();
+        interval
+    }
+
+    /// Called when a ping is due. Fails once too many pongs have been missed.
+    fn ping_due(&mut self) -> Result<(), BybitError> {
+        if self.ping_sent.is_some() {
+            self.missed += 1;
+            self.latency.lock().unwrap().missed_pongs = self.missed;
+            if self.missed >= self.policy.max_missed {
+                return Err(BybitError::HeartbeatTimeout {
+                    missed: self.missed,
+                });
+            }
+        }
+        self.ping_sent = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Called when a ping is due while backpressure pauses the reader. Its pong
+    /// cannot be read until the reader resumes, so it is neither timed nor missed.
+    fn ping_paused(&mut self) {
+        self.ping_sent = None;
+    }
+
+    /// Records the round trip if `msg` answers the outstanding ping.
+    fn on_message(&mut self, msg: &str) {
+        if !is_pong(msg) {
+            return;
+        }
+        if let Some(sent) = self.ping_sent.take() {
+            self.missed = 0;
+            self.latency.lock().unwrap().record(sent.elapsed());
+        }
+    }
+}
+
+/// Returns `true` for the pong frames of the public, private and trade streams.
+fn is_pong(msg: &str) -> bool {
+    if !msg.contains("pong") {
+        return false;
+    }
+    match serde_json::from_str::<PongResponse>(msg) {
+        Ok(PongResponse::PublicPong(pong)) | Ok(PongResponse::PrivatePong(pong)) => {
+            pong.op == "pong" || pong.ret_msg == "pong"
+        }
+        // The trade stream answers with camelCase fields.
+        Err(_) => serde_json::from_str::<Value>(msg).is_ok_and(|v| v["op"] == "pong"),
+    }
+}
+
+/// A websocket subscription consumed as a `futures::Stream`.
+///
+/// Events are produced by a background task running the regular event loop. A
+/// connection error is yielded as a final `Err` item before the stream ends, and
+/// dropping the stream closes the underlying connection.
+pub struct EventStream {
+    receiver: mpsc::UnboundedReceiver<Result<WebsocketEvents, BybitError>>,
+    task: JoinHandle<()>,
+    latency: Vec<SharedLatency>,
+}
+
+impl EventStream {
+    /// Ping statistics of every connection behind the stream, one per shard for
+    /// `Stream::subscribe_sharded`.
+    pub fn latency(&self) -> Vec<LatencyStats> {
+        self.latency
+            .iter()
+            .map(|latency| latency.lock().unwrap().clone())
+            .collect()
+    }
+}
+
+impl futures::Stream for EventStream {
+    type Item = Result<WebsocketEvents, BybitError>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for EventStream {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Handle to an authenticated trade stream connection, created by `Stream::ws_trader`.
+///
+/// Requests are correlated with their responses by `reqId`; a request that gets no
+/// response within `timeout` fails with `BybitError::RequestTimeout`.
+#[derive(Clone)]
+pub struct WsTrader {
+    requests: mpsc::UnboundedSender<TradeCall>,
+    timeout: Duration,
+    recv_window: u64,
+    rate_limiter: RateLimiter,
+    clock: ServerClock,
+    latency: SharedLatency,
+}
+
+/// A request queued on the trade stream together with the caller waiting for it.
+struct TradeCall {
+    req_id: String,
+    frame: String,
+    limit: LimitKey,
+    response: oneshot::Sender<Result<OrderIds, BybitError>>,
+}
+
+/// A trade stream request waiting for its response.
+type PendingCall = (LimitKey, oneshot::Sender<Result<OrderIds, BybitError>>);
+
+impl WsTrader {
+    /// Sets how long a request waits for its response.
+    pub fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the `X-BAPI-RECV-WINDOW` header sent with every request.
+    pub fn set_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+
+    /// Ping statistics of the trade stream connection.
+    pub fn latency(&self) -> LatencyStats {
+        self.latency.lock().unwrap().clone()
+    }
+
+    pub async fn place_order(
+        &self,
+        order: OrderRequest<'_>,
+    ) -> Result<OrderIds, BybitError> {
+        let category = order.category;
+        let request = RequestType::Create(BatchPlaceRequest::new(category, vec![order]));
+        self.request(request, self.timeout).await
+    }
+
+    pub async fn amend_order(
+        &self,
+        order: AmendOrderRequest<'_>,
+    ) -> Result<OrderIds, BybitError> {
+        let category = order.category;
+        let request = RequestType::Amend(BatchAmendRequest::new(category, vec![order]));
+        self.request(request, self.timeout).await
+    }
+
+    pub async fn cancel_order(
+        &self,
+        order: CancelOrderRequest<'_>,
+    ) -> Result<OrderIds, BybitError> {
+        let category = order.category;
+        let request = RequestType::Cancel(BatchCancelRequest::new(category, vec![order]));
+        self.request(request, self.timeout).await
+    }
+
+    /// Sends `request` and waits up to `timeout` for the matching response.
+    ///
+    /// The trade stream accepts a single order per request, so `request` should hold
+    /// exactly one order.
+    pub async fn request(
+        &self,
+        request: RequestType<'_>,
+        timeout: Duration,
+    ) -> Result<OrderIds, BybitError> {
+        let (route, category) = match &request {
+            RequestType::Create(request) => (Trade::Place, request.category),
+            RequestType::Amend(request) => (Trade::Amend, request.category),
+            RequestType::Cancel(request) => (Trade::Cancel, request.category),
+        };
+        // The trade stream shares its quota with the REST order endpoints.
+        let limit = LimitKey::new(API::Trade(route), Some(category));
+        self.rate_limiter.acquire(&limit).await;
+        let req_id = generate_random_uid(16);
+        let frame = Stream::build_trade_request(
+            request,
+            &req_id,
+            self.clock.now(),
+            Some(self.recv_window),
+        );
+        let (response, receiver) = oneshot::channel();
+        let call = TradeCall {
+            req_id: req_id.clone(),
+            frame,
+            limit,
+            response,
+        };
+        if self.requests.send(call).is_err() {
+            return Err(BybitError::Base("Trade stream is closed".to_string()));
+        }
+        match tokio::time::timeout(timeout, receiver).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(BybitError::Base("Trade stream is closed".to_string())),
+            Err(_) => Err(BybitError::RequestTimeout { req_id }),
+        }
+    }
+}
+
+/// Handle to a live connection opened with `Stream::connect` or
+/// `Stream::connect_private`, used to add and remove topics at runtime.
+///
+/// Every change is validated against the active topics before it is sent and only
+/// takes effect once the server acknowledges it.
+#[derive(Clone)]
+pub struct SubscriptionHandle {
+    commands: mpsc::UnboundedSender<SubscriptionCommand>,
+    topics: Arc<Mutex<BTreeSet<String>>>,
+    timeout: Duration,
+    latency: SharedLatency,
+}
+
+/// A subscribe or unsubscribe request waiting to be sent on a live connection.
+struct SubscriptionCommand {
+    req_id: String,
+    op: &'static str,
+    topics: Vec<String>,
+    response: oneshot::Sender<Result<(), BybitError>>,
+}
+
+/// Bybit rejects spot subscription requests with more than 10 args.
+const SPOT_MAX_ARGS_PER_REQUEST: usize = 10;
+/// Bybit limits the combined length of the args subscribed on one connection.
+const MAX_ARGS_LENGTH: usize = 21_000;
+
+impl SubscriptionHandle {
+    /// Sets how long `subscribe` and `unsubscribe` wait for the server ack.
+    pub fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Topics acknowledged by the server and not unsubscribed since.
+    pub fn topics(&self) -> Vec<String> {
+        self.topics.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Ping statistics of the connection, kept across reconnects.
+    pub fn latency(&self) -> LatencyStats {
+        self.latency.lock().unwrap().clone()
+    }
+
+    /// Subscribes to `topics`, failing without sending anything if one of them is
+    /// already subscribed or the request exceeds Bybit's limits.
+    pub async fn subscribe(&self, topics: &[&str]) -> Result<(), BybitError> {
+        self.send("subscribe", topics).await
+    }
+
+    /// Unsubscribes from `topics`, failing without sending anything if one of them
+    /// is not subscribed.
+    pub async fn unsubscribe(&self, topics: &[&str]) -> Result<(), BybitError> {
+        self.send("unsubscribe", topics).await
+    }
+
+    async fn send(&self, op: &'static str, topics: &[&str]) -> Result<(), BybitError> {
+        let req_id = generate_random_uid(8);
+        let (response, receiver) = oneshot::channel();
+        let command = SubscriptionCommand {
+            req_id: req_id.clone(),
+            op,
+            topics: topics.iter().map(|topic| topic.to_string()).collect(),
+            response,
+        };
+        if self.commands.send(command).is_err() {
+            return Err(BybitError::Base("Connection is closed".to_string()));
+        }
+        match tokio::time::timeout(self.timeout, receiver).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(BybitError::Base("Connection is closed".to_string())),
+            Err(_) => Err(BybitError::RequestTimeout { req_id }),
+        }
+    }
+}
+
+/// Topic bookkeeping of a live connection, owned by its background task.
+struct LiveTopics {
+    active: Arc<Mutex<BTreeSet<String>>>,
+    pending: HashMap<String, SubscriptionCommand>,
+    max_args_per_request: Option<usize>,
+}
+
+impl LiveTopics {
+    /// Checks `command` against the active and in-flight topics.
+    fn validate(&self, command: &SubscriptionCommand) -> Result<(), BybitError> {
+        if let Some(limit) = self.max_args_per_request {
+            if command.topics.len() > limit {
+                return Err(BybitError::SubscriptionLimitExceeded(format!(
+                    "{} topics requested, at most {} per request",
+                    command.topics.len(),
+                    limit
+                )));
+            }
+        }
+        let active = self.active.lock().unwrap();
+        let mut seen = BTreeSet::new();
+        for topic in &command.topics {
+            if !seen.insert(topic) {
+                return Err(BybitError::DuplicateSubscription(topic.clone()));
+            }
+            let in_flight = self.pending.values().any(|pending| {
+                pending.op == command.op && pending.topics.contains(topic)
+            });
+            match command.op {
+                "subscribe" if active.contains(topic) || in_flight => {
+                    return Err(BybitError::DuplicateSubscription(topic.clone()));
+                }
+                "unsubscribe" if !active.contains(topic) || in_flight => {
+                    return Err(BybitError::NotSubscribed(topic.clone()));
+                }
+                _ => {}
+            }
+        }
+        if command.op == "subscribe" {
+            let length: usize = active
+                .iter()
+                .chain(
+                    self.pending
+                        .values()
+                        .filter(|pending| pending.op == "subscribe")
+                        .flat_map(|pending| &pending.topics),
+                )
+                .chain(&command.topics)
+                .map(String::len)
+                .sum();
+            if length > MAX_ARGS_LENGTH {
+                return Err(BybitError::SubscriptionLimitExceeded(format!(
+                    "args would total {} characters, at most {} per connection",
+                    length, MAX_ARGS_LENGTH
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies a subscribe or unsubscribe ack, returning `false` for other frames.
+    fn acknowledge(&mut self, msg: &str) -> bool {
+        if !msg.contains("subscribe") {
+            return false;
+        }
+        let Ok(ack) = serde_json::from_str::<Value>(msg) else {
+            return false;
+        };
+        let Some(command) = ack["req_id"]
+            .as_str()
+            .and_then(|req_id| self.pending.remove(req_id))
+        else {
+            return false;
+        };
+        let result = if ack["success"].as_bool().unwrap_or(false) {
+            let mut active = self.active.lock().unwrap();
+            for topic in command.topics {
+                if command.op == "subscribe" {
+                    active.insert(topic);
+                } else {
+                    active.remove(&topic);
+                }
+            }
+            Ok(())
+        } else {
+            Err(BybitError::SubscriptionRejected(
+                ack["ret_msg"].as_str().unwrap_or_default().to_string(),
+            ))
+        };
+        let _ = command.response.send(result);
+        true
+    }
+
+    /// Fails every change still waiting for an ack.
+    fn fail_pending(&mut self, error: &BybitError) {
+        for (_, command) in self.pending.drain() {
+            let _ = command
+                .response
+                .send(Err(BybitError::Base(error.to_string())));
+        }
+    }
+}
+
+/// Why an event loop stopped running.
+enum LoopExit {
+    /// The handler returned an error; the caller asked to stop.
+    Handler(BybitError),
+    /// The socket failed or was closed by the server.
+    Socket(BybitError),
+    /// The server refused the connection in a way reconnecting cannot fix.
+    Fatal(BybitError),
 }
 
 impl Stream {
+    /// Enables automatic reconnection for `ws_subscribe`, `ws_priv_subscribe` and
+    /// every helper built on top of them.
+    pub fn with_reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
+
+    /// Replaces the default heartbeat of 20 second pings and 3 missed pongs.
+    pub fn with_heartbeat(mut self, policy: HeartbeatPolicy) -> Self {
+        self.heartbeat = policy;
+        self
+    }
+
+    fn heartbeat(&self, latency: &SharedLatency) -> Heartbeat {
+        Heartbeat::new(self.heartbeat.clone(), latency.clone())
+    }
+
     /// Tests for connectivity by sending a ping request to the Bybit server.
     ///
     /// # Returns
@@ -101,17 +778,27 @@ impl Stream {
     ) -> Result<(), BybitError>
     where
         F: FnMut(WebsocketEvents) -> Result<(), BybitError> + 'static + Send,
+    {
+        self.priv_subscribe_with(req, handler).await
+    }
+
+    async fn priv_subscribe_with<H>(
+        &self,
+        req: Subscription<'_>,
+        handler: H,
+    ) -> Result<(), BybitError>
+    where
+        H: WebSocketHandler,
     {
         let request = Self::build_subscription(req);
-        let response = self
-            .client
-            .wss_connect(WebsocketAPI::Private, Some(request), true, Some(10))
-            .await?;
-        match Self::event_loop(response, handler, None).await {
-            Ok(_) => {}
-            Err(_) => {}
-        }
-        Ok(())
+        self.subscribe_with(
+            WebsocketAPI::Private,
+            vec![request],
+            true,
+            &Arc::default(),
+            handler,
+        )
+        .await
     }
 
     pub async fn ws_subscribe<'b, F>(
@@ -123,26 +810,526 @@ impl Stream {
     where
         F: FnMut(WebsocketEvents) -> Result<(), BybitError> + 'static + Send,
     {
-        let endpoint = {
-            match category {
-                Category::Linear => WebsocketAPI::PublicLinear,
-                Category::Inverse => WebsocketAPI::PublicInverse,
-                Category::Spot => WebsocketAPI::PublicSpot,
-                _ => unimplemented!("Option has not been implemented"),
+        self.public_subscribe_with(req, category, handler).await
+    }
+
+    async fn public_subscribe_with<H>(
+        &self,
+        req: Subscription<'_>,
+        category: Category,
+        handler: H,
+    ) -> Result<(), BybitError>
+    where
+        H: WebSocketHandler,
+    {
+        let endpoint = Self::public_endpoint(category);
+        let request = Self::build_subscription(req);
+        self.subscribe_with(endpoint, vec![request], false, &Arc::default(), handler)
+            .await
+    }
+
+    /// Subscribes to public topics and returns the events as a `futures::Stream`.
+    ///
+    /// The connection is driven by a spawned task, so this must be called from within
+    /// a Tokio runtime. Dropping the returned stream closes the connection.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn run(ws: bybit::ws::Stream) {
+    /// use bybit::model::{Category, Subscription};
+    /// use futures::StreamExt;
+    ///
+    /// let request = Subscription::new("subscribe", vec!["publicTrade.BTCUSDT"]);
+    /// let mut events = ws.subscribe(request, Category::Linear);
+    /// while let Some(event) = events.next().await {
+    ///     println!("{:?}", event);
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe(&self, req: Subscription<'_>, category: Category) -> EventStream {
+        let endpoint = Self::public_endpoint(category);
+        self.spawn_event_stream(endpoint, Self::build_subscription(req), false)
+    }
+
+    /// Subscribes to any number of public topics by spreading them over as many
+    /// connections as `policy` requires, and merges their events into one stream.
+    ///
+    /// Every connection reconnects on its own when the stream has a reconnect policy.
+    /// A connection that gives up yields its error while the others keep running;
+    /// the stream ends once every connection has stopped. Dropping the stream closes
+    /// all of them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn run(ws: bybit::ws::Stream, symbols: Vec<String>) {
+    /// use bybit::{model::Category, ws::ShardPolicy};
+    /// use futures::StreamExt;
+    ///
+    /// let topics: Vec<String> = symbols.iter().map(|s| format!("tickers.{}", s)).collect();
+    /// let topics: Vec<&str> = topics.iter().map(String::as_str).collect();
+    /// let mut events = ws.subscribe_sharded(&topics, Category::Linear, ShardPolicy::default());
+    /// while let Some(event) = events.next().await {
+    ///     println!("{:?}", event);
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe_sharded(
+        &self,
+        topics: &[&str],
+        category: Category,
+        policy: ShardPolicy,
+    ) -> EventStream {
+        let endpoint = Self::public_endpoint(category);
+        let shards: Vec<Vec<String>> = policy
+            .shard(topics)
+            .iter()
+            .map(|shard| policy.requests(shard))
+            .collect();
+        let latency: Vec<SharedLatency> = shards.iter().map(|_| Arc::default()).collect();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let stream = self.clone();
+        let shard_latency = latency.clone();
+        let task = tokio::spawn(async move {
+            // Dropping the set aborts every shard along with this task.
+            let mut connections = JoinSet::new();
+            for (requests, latency) in shards.into_iter().zip(shard_latency) {
+                let events = stream.clone().subscribe_events(
+                    endpoint,
+                    requests,
+                    false,
+                    latency,
+                    sender.clone(),
+                );
+                connections.spawn(events);
+            }
+            drop(sender);
+            while connections.join_next().await.is_some() {}
+        });
+        EventStream {
+            receiver,
+            task,
+            latency,
+        }
+    }
+
+    /// Subscribes to private topics and returns the events as a `futures::Stream`.
+    ///
+    /// See `subscribe` for how the stream behaves.
+    pub fn subscribe_private(&self, req: Subscription<'_>) -> EventStream {
+        self.spawn_event_stream(
+            WebsocketAPI::Private,
+            Self::build_subscription(req),
+            true,
+        )
+    }
+
+    /// Opens a public connection without any topics and returns a handle to
+    /// subscribe and unsubscribe at runtime, along with the stream of its events.
+    ///
+    /// With a reconnect policy the active topics are replayed after a reconnect.
+    /// Dropping the `EventStream` closes the connection.
+    pub async fn connect(
+        &self,
+        category: Category,
+    ) -> Result<(SubscriptionHandle, EventStream), BybitError> {
+        let max_args =
+            matches!(category, Category::Spot).then_some(SPOT_MAX_ARGS_PER_REQUEST);
+        self.connect_live(Self::public_endpoint(category), false, max_args)
+            .await
+    }
+
+    /// Private counterpart of `connect`; the connection is authenticated first.
+    pub async fn connect_private(
+        &self,
+    ) -> Result<(SubscriptionHandle, EventStream), BybitError> {
+        self.connect_live(WebsocketAPI::Private, true, None).await
+    }
+
+    async fn connect_live(
+        &self,
+        endpoint: WebsocketAPI,
+        private: bool,
+        max_args_per_request: Option<usize>,
+    ) -> Result<(SubscriptionHandle, EventStream), BybitError> {
+        let alive_dur = if private { Some(10) } else { None };
+        let socket = self
+            .client
+            .wss_connect(endpoint, None, private, alive_dur)
+            .await?;
+        let (commands, command_receiver) = mpsc::unbounded_channel();
+        let (events, receiver) = mpsc::unbounded_channel();
+        let active = Arc::new(Mutex::new(BTreeSet::new()));
+        let latency = SharedLatency::default();
+        let topics = LiveTopics {
+            active: active.clone(),
+            pending: HashMap::new(),
+            max_args_per_request,
+        };
+        let stream = self.clone();
+        let heartbeat = latency.clone();
+        let task = tokio::spawn(async move {
+            stream
+                .live_loop(
+                    socket,
+                    endpoint,
+                    topics,
+                    command_receiver,
+                    events,
+                    heartbeat,
+                )
+                .await
+        });
+        let handle = SubscriptionHandle {
+            commands,
+            topics: active,
+            timeout: Duration::from_secs(10),
+            latency: latency.clone(),
+        };
+        let events = EventStream {
+            receiver,
+            task,
+            latency: vec![latency],
+        };
+        Ok((handle, events))
+    }
+
+    /// Drives a live connection, reconnecting and replaying the active topics if the
+    /// stream has a reconnect policy.
+    async fn live_loop(
+        self,
+        socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+        endpoint: WebsocketAPI,
+        mut topics: LiveTopics,
+        mut command_receiver: mpsc::UnboundedReceiver<SubscriptionCommand>,
+        events: mpsc::UnboundedSender<Result<WebsocketEvents, BybitError>>,
+        latency: SharedLatency,
+    ) {
+        let private = matches!(endpoint, WebsocketAPI::Private);
+        let alive_dur = if private { Some(10) } else { None };
+        let mut socket = Some(socket);
+        let mut commands = Some(&mut command_receiver);
+        let mut attempt = 0;
+        let mut reason: Option<String> = None;
+        loop {
+            let connected = match socket.take() {
+                Some(socket) => Ok(socket),
+                None => {
+                    let active: Vec<String> =
+                        topics.active.lock().unwrap().iter().cloned().collect();
+                    let request = (!active.is_empty()).then(|| {
+                        Self::build_subscription(Subscription::new(
+                            "subscribe",
+                            active.iter().map(String::as_str).collect(),
+                        ))
+                    });
+                    self.client
+                        .wss_connect(endpoint, request, private, alive_dur)
+                        .await
+                }
+            };
+            let error = match connected {
+                Ok(socket) => {
+                    if let Some(reason) = reason.take() {
+                        let event = WebsocketEvents::Reconnected(ReconnectEvent {
+                            attempt,
+                            reason,
+                            timestamp: get_timestamp(),
+                        });
+                        if events.send(Ok(event)).is_err() {
+                            return;
+                        }
+                    }
+                    attempt = 0;
+                    let mut heartbeat = self.heartbeat(&latency);
+                    match Self::run_live(
+                        socket,
+                        &mut topics,
+                        &mut commands,
+                        &events,
+                        &mut heartbeat,
+                    )
+                    .await
+                    {
+                        Ok(()) => return,
+                        Err(e @ BybitError::AuthFailed(_)) => {
+                            topics.fail_pending(&e);
+                            let _ = events.send(Err(e));
+                            return;
+                        }
+                        Err(e) => {
+                            topics.fail_pending(&e);
+                            tracing::warn!(
+                                "Websocket {:?} disconnected: {}",
+                                endpoint,
+                                e
+                            );
+                            reason = Some(e.to_string());
+                            e
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to reconnect to {:?}: {}", endpoint, e);
+                    e
+                }
+            };
+            let Some(policy) = &self.reconnect else {
+                let _ = events.send(Err(error));
+                return;
+            };
+            attempt += 1;
+            if policy.exhausted(attempt) {
+                let _ = events.send(Err(error));
+                return;
+            }
+            tokio::time::sleep(policy.delay(attempt)).await;
+        }
+    }
+
+    /// Runs one connection of a live subscription until the socket fails. Returns
+    /// `Ok` once the event receiver has been dropped.
+    async fn run_live(
+        mut socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+        topics: &mut LiveTopics,
+        commands: &mut Option<&mut mpsc::UnboundedReceiver<SubscriptionCommand>>,
+        events: &mpsc::UnboundedSender<Result<WebsocketEvents, BybitError>>,
+        heartbeat: &mut Heartbeat,
+    ) -> Result<(), BybitError> {
+        let mut ping = heartbeat.interval();
+        loop {
+            tokio::select! {
+                msg = socket.next() => match msg {
+                    Some(Ok(WsMessage::Text(msg))) => {
+                        heartbeat.on_message(&msg);
+                        if topics.acknowledge(&msg) {
+                            continue;
+                        }
+                        let event = WebsocketEvents::from_frame(&msg);
+                        let auth_failed = match &event {
+                            WebsocketEvents::AuthFailed(reason) => Some(reason.clone()),
+                            _ => None,
+                        };
+                        if events.send(Ok(event)).is_err() {
+                            return Ok(());
+                        }
+                        if let Some(reason) = auth_failed {
+                            return Err(BybitError::AuthFailed(reason));
+                        }
+                    }
+                    Some(Err(e)) => return Err(BybitError::from(e)),
+                    None => return Err(BybitError::Base("Stream was closed".to_string())),
+                    _ => {}
+                },
+                command = async {
+                    match commands {
+                        Some(commands) => commands.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => match command {
+                    Some(command) => {
+                        if let Err(e) = topics.validate(&command) {
+                            let _ = command.response.send(Err(e));
+                            continue;
+                        }
+                        let request = Self::build_subscription_with_id(
+                            Subscription::new(
+                                command.op,
+                                command.topics.iter().map(String::as_str).collect(),
+                            ),
+                            &command.req_id,
+                        );
+                        socket.send(WsMessage::Text(request)).await?;
+                        topics.pending.insert(command.req_id.clone(), command);
+                    }
+                    // Every handle was dropped; keep delivering events.
+                    None => *commands = None,
+                },
+                _ = ping.tick() => {
+                    heartbeat.ping_due()?;
+                    let request = json!({ "req_id": generate_random_uid(8), "op": "ping" });
+                    socket.send(WsMessage::Text(request.to_string())).await?;
+                }
+            }
+        }
+    }
+
+    fn spawn_event_stream(
+        &self,
+        endpoint: WebsocketAPI,
+        request: String,
+        private: bool,
+    ) -> EventStream {
+        let latency = SharedLatency::default();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let stream = self.clone();
+        let task = tokio::spawn(stream.subscribe_events(
+            endpoint,
+            vec![request],
+            private,
+            latency.clone(),
+            sender,
+        ));
+        EventStream {
+            receiver,
+            task,
+            latency: vec![latency],
+        }
+    }
+
+    /// Runs a subscription that forwards every event to `sender`, followed by the
+    /// error that ended it, if any.
+    async fn subscribe_events(
+        self,
+        endpoint: WebsocketAPI,
+        requests: Vec<String>,
+        private: bool,
+        latency: SharedLatency,
+        sender: mpsc::UnboundedSender<Result<WebsocketEvents, BybitError>>,
+    ) {
+        let events = sender.clone();
+        let handler = move |event: WebsocketEvents| -> Result<(), BybitError> {
+            events.send(Ok(event))?;
+            Ok(())
+        };
+        if let Err(e) = self
+            .subscribe_with(endpoint, requests, private, &latency, handler)
+            .await
+        {
+            let _ = sender.send(Err(e));
+        }
+    }
+
+    fn public_endpoint(category: Category) -> WebsocketAPI {
+        match category {
+            Category::Linear => WebsocketAPI::PublicLinear,
+            Category::Inverse => WebsocketAPI::PublicInverse,
+            Category::Spot => WebsocketAPI::PublicSpot,
+            Category::Option => WebsocketAPI::PublicOption,
+        }
+    }
+
+    /// Connects to `endpoint`, sends `requests` and feeds every message to
+    /// `handler`, reconnecting if the stream has a reconnect policy.
+    async fn subscribe_with<H>(
+        &self,
+        endpoint: WebsocketAPI,
+        requests: Vec<String>,
+        private: bool,
+        latency: &SharedLatency,
+        handler: H,
+    ) -> Result<(), BybitError>
+    where
+        H: WebSocketHandler,
+    {
+        if let Some(policy) = &self.reconnect {
+            return self
+                .reconnect_loop(endpoint, requests, private, policy, latency, handler)
+                .await;
+        }
+        let response = self.connect_with(endpoint, &requests, private).await?;
+        self.run(response, handler, None, &requests, latency).await
+    }
+
+    /// Opens a connection and sends every request in `requests` on it.
+    async fn connect_with(
+        &self,
+        endpoint: WebsocketAPI,
+        requests: &[String],
+        private: bool,
+    ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, BybitError> {
+        let alive_dur = if private { Some(10) } else { None };
+        let mut stream = self
+            .client
+            .wss_connect(endpoint, requests.first().cloned(), private, alive_dur)
+            .await?;
+        for request in requests.iter().skip(1) {
+            stream.send(WsMessage::Text(request.clone())).await?;
+        }
+        Ok(stream)
+    }
+
+    /// Keeps a subscription alive across dropped connections.
+    ///
+    /// Every (re)connect goes through `Client::wss_connect`, so private endpoints are
+    /// re-authenticated with a fresh signature before `requests` are replayed. Once a
+    /// connection is restored the handler receives a `ReconnectEvent`.
+    ///
+    /// Returns when the handler fails or when the policy runs out of attempts.
+    async fn reconnect_loop<H>(
+        &self,
+        endpoint: WebsocketAPI,
+        requests: Vec<String>,
+        private: bool,
+        policy: &ReconnectPolicy,
+        latency: &SharedLatency,
+        mut handler: H,
+    ) -> Result<(), BybitError>
+    where
+        H: WebSocketHandler,
+    {
+        let mut attempt = 0;
+        let mut reason: Option<String> = None;
+        loop {
+            let error = match self.connect_with(endpoint, &requests, private).await {
+                Ok(stream) => {
+                    if let Some(reason) = reason.take() {
+                        handler.handle_reconnect(ReconnectEvent {
+                            attempt,
+                            reason,
+                            timestamp: get_timestamp(),
+                        })?;
+                    }
+                    attempt = 0;
+                    let mut heartbeat = self.heartbeat(latency);
+                    match Self::run_event_loop(
+                        stream,
+                        &mut handler,
+                        None,
+                        &requests,
+                        &mut heartbeat,
+                        &self.client.clock,
+                    )
+                    .await
+                    {
+                        Ok(()) => return Ok(()),
+                        Err(LoopExit::Handler(e)) | Err(LoopExit::Fatal(e)) => {
+                            return Err(e)
+                        }
+                        Err(LoopExit::Socket(e)) => {
+                            tracing::warn!(
+                                "Websocket {:?} disconnected: {}",
+                                endpoint,
+                                e
+                            );
+                            reason = Some(e.to_string());
+                            e
+                        }
+                    }
+                }
+                // The very first connection failed, there is nothing to resume.
+                Err(e) if reason.is_none() => return Err(e),
+                Err(e) => {
+                    tracing::warn!("Failed to reconnect to {:?}: {}", endpoint, e);
+                    e
+                }
+            };
+            attempt += 1;
+            if policy.exhausted(attempt) {
+                return Err(error);
             }
-        };
-        let request = Self::build_subscription(req);
-        let response = self
-            .client
-            .wss_connect(endpoint, Some(request), false, None)
-            .await?;
-        Self::event_loop(response, handler, None).await?;
-        Ok(())
+            tokio::time::sleep(policy.delay(attempt)).await;
+        }
     }
 
     pub fn build_subscription(action: Subscription) -> String {
+        Self::build_subscription_with_id(action, &generate_random_uid(8))
+    }
+
+    fn build_subscription_with_id(action: Subscription, req_id: &str) -> String {
         let mut parameters: BTreeMap<String, Value> = BTreeMap::new();
-        parameters.insert("req_id".into(), generate_random_uid(8).into());
+        parameters.insert("req_id".into(), req_id.into());
         parameters.insert("op".into(), action.op.into());
         let args_value: Value = action
             .args
@@ -155,14 +1342,30 @@ impl Stream {
         build_json_request(&parameters)
     }
 
+    /// Builds a trade stream request stamped with the local time. Requests sent by
+    /// `ws_trade_stream` are stamped with the client's `ServerClock` instead.
     pub fn build_trade_subscription(
         orders: RequestType,
         recv_window: Option<u64>,
+    ) -> String {
+        Self::build_trade_request(
+            orders,
+            &generate_random_uid(16),
+            get_timestamp(),
+            recv_window,
+        )
+    }
+
+    fn build_trade_request(
+        orders: RequestType,
+        req_id: &str,
+        timestamp: u64,
+        recv_window: Option<u64>,
     ) -> String {
         let mut parameters: BTreeMap<String, Value> = BTreeMap::new();
-        parameters.insert("reqId".into(), generate_random_uid(16).into());
+        parameters.insert("reqId".into(), req_id.into());
         let mut header_map: BTreeMap<String, String> = BTreeMap::new();
-        header_map.insert("X-BAPI-TIMESTAMP".into(), get_timestamp().to_string());
+        header_map.insert("X-BAPI-TIMESTAMP".into(), timestamp.to_string());
         header_map.insert(
             "X-BAPI-RECV-WINDOW".into(),
             recv_window.unwrap_or(5000).to_string(),
@@ -201,6 +1404,7 @@ impl Stream {
     ///
     /// * `subs` - A vector of tuples containing the order book ID and symbol
     /// * `category` - The category of the order book
+    /// * `sender` - An `UnboundedSender`, or a `BoundedSender` to apply an overflow policy
     ///
     /// # Example
     ///
@@ -208,25 +1412,29 @@ impl Stream {
     /// use your_crate_name::Category;
     /// let subs = vec![(1, "BTC"), (2, "ETH")];
     /// ```
-    pub async fn ws_orderbook(
+    pub async fn ws_orderbook<S>(
         &self,
         subs: Vec<(i32, &str)>,
         category: Category,
-        sender: mpsc::UnboundedSender<OrderBookUpdate>,
-    ) -> Result<(), BybitError> {
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<OrderBookUpdate>,
+    {
         let arr: Vec<String> = subs
             .into_iter()
             .map(|(num, sym)| format!("orderbook.{}.{}", num, sym.to_uppercase()))
             .collect();
         let request =
             Subscription::new("subscribe", arr.iter().map(AsRef::as_ref).collect());
-        self.ws_subscribe(request, category, move |event| {
+        let handler = SenderHandler::new(sender, |event, sender| {
             if let WebsocketEvents::OrderBookEvent(order_book) = event {
-                sender.send(order_book).unwrap();
+                let topic = order_book.topic.clone();
+                sender.send_event(&topic, order_book)?;
             }
             Ok(())
-        })
-        .await
+        });
+        self.public_subscribe_with(request, category, handler).await
     }
 
     /// This function subscribes to the specified trades and handles the trade events.
@@ -234,6 +1442,7 @@ impl Stream {
     ///
     /// * `subs` - A vector of trade subscriptions
     /// * `category` - The category of the trades
+    /// * `sender` - An `UnboundedSender`, or a `BoundedSender` to apply an overflow policy
     ///
     /// # Example
     ///
@@ -243,28 +1452,31 @@ impl Stream {
     /// let category = Category::Linear;
     /// ws_trades(subs, category);
     /// ```
-    pub async fn ws_trades(
+    pub async fn ws_trades<S>(
         &self,
         subs: Vec<&str>,
         category: Category,
-        sender: mpsc::UnboundedSender<WsTrade>,
-    ) -> Result<(), BybitError> {
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<WsTrade>,
+    {
         let arr: Vec<String> = subs
             .iter()
             .map(|&sub| format!("publicTrade.{}", sub.to_uppercase()))
             .collect();
         let request =
             Subscription::new("subscribe", arr.iter().map(AsRef::as_ref).collect());
-        let handler = move |event| {
+        let handler = SenderHandler::new(sender, |event, sender| {
             if let WebsocketEvents::TradeEvent(trades) = event {
                 for trade in trades.data {
-                    sender.send(trade).unwrap();
+                    sender.send_event(&trades.topic, trade)?;
                 }
             }
             Ok(())
-        };
+        });
 
-        self.ws_subscribe(request, category, handler).await
+        self.public_subscribe_with(request, category, handler).await
     }
 
     /// Subscribes to ticker events for the specified symbols and category.
@@ -273,6 +1485,7 @@ impl Stream {
     ///
     /// * `subs` - A vector of symbols for which ticker events are subscribed.
     /// * `category` - The category for which ticker events are subscribed.
+    /// * `sender` - An `UnboundedSender`, or a `BoundedSender` to apply an overflow policy.
     ///
     /// # Examples
     ///
@@ -283,12 +1496,31 @@ impl Stream {
     /// let sender = UnboundedSender<Tickers>;
     /// ws_tickers(subs, category, sender);
     /// ```
-    pub async fn ws_tickers(
+    pub async fn ws_tickers<S>(
         &self,
         subs: Vec<&str>,
         category: Category,
-        sender: mpsc::UnboundedSender<Tickers>,
-    ) -> Result<(), BybitError> {
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<Tickers>,
+    {
+        self.ws_tickers_with_cache(subs, category, TickerCache::new(), sender)
+            .await
+    }
+
+    /// Same as `ws_tickers`, keeping the merged linear and inverse tickers in
+    /// `cache` so their current state can be looked up with `TickerCache::get`.
+    pub async fn ws_tickers_with_cache<S>(
+        &self,
+        subs: Vec<&str>,
+        category: Category,
+        cache: TickerCache,
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<Tickers>,
+    {
         let arr: Vec<String> = subs
             .into_iter()
             .map(|sub| format!("tickers.{}", sub.to_uppercase()))
@@ -296,28 +1528,98 @@ impl Stream {
         let request =
             Subscription::new("subscribe", arr.iter().map(String::as_str).collect());
 
-        let handler = move |event| {
+        let handler = SenderHandler::new(sender, move |event, sender| {
+            if let WebsocketEvents::TickerDeltaEvent(delta) = &event {
+                match cache.apply_delta(delta) {
+                    Ok(ticker) => {
+                        sender.send_event(&delta.topic, Tickers::Linear(ticker))?
+                    }
+                    Err(e) => tracing::warn!("Dropping ticker delta: {}", e),
+                }
+            }
             if let WebsocketEvents::TickerEvent(tickers) = event {
+                if let Tickers::Linear(ticker) = &tickers.data {
+                    cache.apply_snapshot(ticker);
+                }
                 match tickers.data {
-                    Tickers::Linear(linear_ticker) => {
-                        sender.send(Tickers::Linear(linear_ticker)).unwrap()
-                    }
+                    Tickers::Linear(linear_ticker) => sender
+                        .send_event(&tickers.topic, Tickers::Linear(linear_ticker))?,
                     Tickers::Spot(spot_ticker) => {
-                        sender.send(Tickers::Spot(spot_ticker)).unwrap()
+                        sender.send_event(&tickers.topic, Tickers::Spot(spot_ticker))?
                     }
                 }
             }
             Ok(())
-        };
+        });
+
+        self.public_subscribe_with(request, category, handler).await
+    }
+    /// Subscribes to option tickers (greeks, IV and underlying price) on the public
+    /// option stream.
+    pub async fn ws_option_tickers<S>(
+        &self,
+        subs: Vec<&str>,
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<OptionTickerData>,
+    {
+        let arr: Vec<String> = subs
+            .into_iter()
+            .map(|sub| format!("tickers.{}", sub.to_uppercase()))
+            .collect();
+        let request =
+            Subscription::new("subscribe", arr.iter().map(String::as_str).collect());
+
+        let handler = SenderHandler::new(sender, |event, sender| {
+            if let WebsocketEvents::OptionTickerEvent(ticker) = event {
+                sender.send_event(&ticker.topic, ticker.data)?;
+            }
+            Ok(())
+        });
+
+        self.public_subscribe_with(request, Category::Option, handler)
+            .await
+    }
+
+    /// Subscribes to public trades on the option stream. `subs` takes base coins such
+    /// as `BTC`.
+    pub async fn ws_option_trades<S>(
+        &self,
+        subs: Vec<&str>,
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<OptionTrade>,
+    {
+        let arr: Vec<String> = subs
+            .iter()
+            .map(|&sub| format!("publicTrade.{}", sub.to_uppercase()))
+            .collect();
+        let request =
+            Subscription::new("subscribe", arr.iter().map(AsRef::as_ref).collect());
+        let handler = SenderHandler::new(sender, |event, sender| {
+            if let WebsocketEvents::OptionTradeEvent(trades) = event {
+                for trade in trades.data {
+                    sender.send_event(&trades.topic, trade)?;
+                }
+            }
+            Ok(())
+        });
 
-        self.ws_subscribe(request, category, handler).await
+        self.public_subscribe_with(request, Category::Option, handler)
+            .await
     }
-    pub async fn ws_liquidations(
+
+    pub async fn ws_liquidations<S>(
         &self,
         subs: Vec<&str>,
         category: Category,
-        sender: mpsc::UnboundedSender<LiquidationData>,
-    ) -> Result<(), BybitError> {
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<LiquidationData>,
+    {
         let arr: Vec<String> = subs
             .into_iter()
             .map(|sub| format!("liquidation.{}", sub.to_uppercase()))
@@ -325,41 +1627,48 @@ impl Stream {
         let request =
             Subscription::new("subscribe", arr.iter().map(String::as_str).collect());
 
-        let handler = move |event| {
+        let handler = SenderHandler::new(sender, |event, sender| {
             if let WebsocketEvents::LiquidationEvent(liquidation) = event {
-                sender.send(liquidation.data).unwrap();
+                sender.send_event(&liquidation.topic, liquidation.data)?;
             }
             Ok(())
-        };
+        });
 
-        self.ws_subscribe(request, category, handler).await
+        self.public_subscribe_with(request, category, handler).await
     }
-    pub async fn ws_klines(
+    pub async fn ws_klines<S>(
         &self,
         subs: Vec<(&str, &str)>,
         category: Category,
-        sender: mpsc::UnboundedSender<WsKline>,
-    ) -> Result<(), BybitError> {
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<WsKline>,
+    {
         let arr: Vec<String> = subs
             .into_iter()
             .map(|(interval, sym)| format!("kline.{}.{}", interval, sym.to_uppercase()))
             .collect();
         let request =
             Subscription::new("subscribe", arr.iter().map(AsRef::as_ref).collect());
-        self.ws_subscribe(request, category, move |event| {
+        let handler = SenderHandler::new(sender, |event, sender| {
             if let WebsocketEvents::KlineEvent(kline) = event {
-                sender.send(kline).unwrap();
+                let topic = kline.topic.clone();
+                sender.send_event(&topic, kline)?;
             }
             Ok(())
-        })
-        .await
+        });
+        self.public_subscribe_with(request, category, handler).await
     }
 
-    pub async fn ws_position(
+    pub async fn ws_position<S>(
         &self,
         cat: Option<Category>,
-        sender: mpsc::UnboundedSender<PositionData>,
-    ) -> Result<(), BybitError> {
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<PositionData>,
+    {
         let sub_str = if let Some(v) = cat {
             match v {
                 Category::Linear => "position.linear",
@@ -371,22 +1680,26 @@ impl Stream {
         };
 
         let request = Subscription::new("subscribe", vec![sub_str]);
-        self.ws_priv_subscribe(request, move |event| {
+        let handler = SenderHandler::new(sender, |event, sender| {
             if let WebsocketEvents::PositionEvent(position) = event {
                 for v in position.data {
-                    sender.send(v).unwrap();
+                    let topic = format!("{}.{}", position.topic, v.symbol);
+                    sender.send_event(&topic, v)?;
                 }
             }
             Ok(())
-        })
-        .await
+        });
+        self.priv_subscribe_with(request, handler).await
     }
 
-    pub async fn ws_executions(
+    pub async fn ws_executions<S>(
         &self,
         cat: Option<Category>,
-        sender: mpsc::UnboundedSender<ExecutionData>,
-    ) -> Result<(), BybitError> {
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<ExecutionData>,
+    {
         let sub_str = if let Some(v) = cat {
             match v {
                 Category::Linear => "execution.linear",
@@ -399,40 +1712,45 @@ impl Stream {
         };
 
         let request = Subscription::new("subscribe", vec![sub_str]);
-        self.ws_priv_subscribe(request, move |event| {
+        let handler = SenderHandler::new(sender, |event, sender| {
             if let WebsocketEvents::ExecutionEvent(execute) = event {
                 for v in execute.data {
-                    sender.send(v).unwrap();
+                    let topic = format!("{}.{}", execute.topic, v.symbol);
+                    sender.send_event(&topic, v)?;
                 }
             }
             Ok(())
-        })
-        .await
+        });
+        self.priv_subscribe_with(request, handler).await
     }
 
-    pub async fn ws_fast_exec(
-        &self,
-        sender: mpsc::UnboundedSender<FastExecData>,
-    ) -> Result<(), BybitError> {
+    pub async fn ws_fast_exec<S>(&self, sender: S) -> Result<(), BybitError>
+    where
+        S: EventSender<FastExecData>,
+    {
         let sub_str = "execution.fast";
         let request = Subscription::new("subscribe", vec![sub_str]);
 
-        self.ws_priv_subscribe(request, move |event| {
+        let handler = SenderHandler::new(sender, |event, sender| {
             if let WebsocketEvents::FastExecEvent(execution) = event {
                 for v in execution.data {
-                    sender.send(v).unwrap();
+                    let topic = format!("{}.{}", execution.topic, v.symbol);
+                    sender.send_event(&topic, v)?;
                 }
             }
             Ok(())
-        })
-        .await
+        });
+        self.priv_subscribe_with(request, handler).await
     }
 
-    pub async fn ws_orders(
+    pub async fn ws_orders<S>(
         &self,
         cat: Option<Category>,
-        sender: mpsc::UnboundedSender<OrderData>,
-    ) -> Result<(), BybitError> {
+        sender: S,
+    ) -> Result<(), BybitError>
+    where
+        S: EventSender<OrderData>,
+    {
         let sub_str = if let Some(v) = cat {
             match v {
                 Category::Linear => "order.linear",
@@ -445,32 +1763,33 @@ impl Stream {
         };
 
         let request = Subscription::new("subscribe", vec![sub_str]);
-        self.ws_priv_subscribe(request, move |event| {
+        let handler = SenderHandler::new(sender, |event, sender| {
             if let WebsocketEvents::OrderEvent(order) = event {
                 for v in order.data {
-                    sender.send(v).unwrap();
+                    let topic = format!("{}.{}", order.topic, v.symbol);
+                    sender.send_event(&topic, v)?;
                 }
             }
             Ok(())
-        })
-        .await
+        });
+        self.priv_subscribe_with(request, handler).await
     }
 
-    pub async fn ws_wallet(
-        &self,
-        sender: mpsc::UnboundedSender<WalletData>,
-    ) -> Result<(), BybitError> {
+    pub async fn ws_wallet<S>(&self, sender: S) -> Result<(), BybitError>
+    where
+        S: EventSender<WalletData>,
+    {
         let sub_str = "wallet";
         let request = Subscription::new("subscribe", vec![sub_str]);
-        self.ws_priv_subscribe(request, move |event| {
+        let handler = SenderHandler::new(sender, |event, sender| {
             if let WebsocketEvents::Wallet(wallet) = event {
                 for v in wallet.data {
-                    sender.send(v).unwrap();
+                    sender.send_event(&wallet.topic, v)?;
                 }
             }
             Ok(())
-        })
-        .await
+        });
+        self.priv_subscribe_with(request, handler).await
     }
 
     pub async fn ws_trade_stream<'a, F>(
@@ -486,58 +1805,330 @@ impl Stream {
             .client
             .wss_connect(WebsocketAPI::TradeStream, None, true, Some(10))
             .await?;
-        Self::event_loop(response, handler, Some(req)).await?;
+        self.run(response, handler, Some(req), &[], &Arc::default())
+            .await?;
 
         Ok(())
     }
 
-    pub async fn event_loop<'a, H>(
+    /// Connects to the trade stream and returns a `WsTrader` handle.
+    ///
+    /// Each request made through the handle is tagged with its own `reqId` and
+    /// resolves with the matching response, so several requests can be in flight
+    /// at once. The connection is closed once every clone of the handle is dropped.
+    pub async fn ws_trader(&self) -> Result<WsTrader, BybitError> {
+        let response = self
+            .client
+            .wss_connect(WebsocketAPI::TradeStream, None, true, Some(10))
+            .await?;
+        let (requests, calls) = mpsc::unbounded_channel();
+        let rate_limiter = self.client.rate_limiter.clone();
+        let latency = SharedLatency::default();
+        tokio::spawn(Self::trader_loop(
+            response,
+            calls,
+            self.heartbeat(&latency),
+            rate_limiter.clone(),
+        ));
+        Ok(WsTrader {
+            requests,
+            timeout: Duration::from_secs(10),
+            recv_window: 3000,
+            rate_limiter,
+            clock: self.client.clock.clone(),
+            latency,
+        })
+    }
+
+    async fn trader_loop(
         mut stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+        mut calls: mpsc::UnboundedReceiver<TradeCall>,
+        mut heartbeat: Heartbeat,
+        rate_limiter: RateLimiter,
+    ) {
+        let mut pending: HashMap<String, PendingCall> = HashMap::new();
+        let mut ping = heartbeat.interval();
+        let error = loop {
+            tokio::select! {
+                msg = stream.next() => match msg {
+                    Some(Ok(WsMessage::Text(msg))) => {
+                        heartbeat.on_message(&msg);
+                        if let Some(WebsocketEvents::AuthFailed(reason)) =
+                            Frame::parse(&msg).ok().and_then(|frame| {
+                                WebsocketEvents::control_frame(&frame, |_| None)
+                            })
+                        {
+                            break BybitError::AuthFailed(reason);
+                        }
+                        Self::resolve_trade_response(&msg, &mut pending, &rate_limiter);
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => break BybitError::from(e),
+                    None => break BybitError::Base("Stream was closed".to_string()),
+                },
+                call = calls.recv() => match call {
+                    Some(call) => {
+                        if let Err(e) = stream.send(WsMessage::Text(call.frame)).await {
+                            let _ = call.response.send(Err(BybitError::Base(e.to_string())));
+                            break BybitError::from(e);
+                        }
+                        pending.insert(call.req_id, (call.limit, call.response));
+                    }
+                    // Every `WsTrader` handle was dropped.
+                    None => return,
+                },
+                _ = ping.tick() => {
+                    // Callers that timed out dropped their receivers.
+                    pending.retain(|_, (_, response)| !response.is_closed());
+                    if let Err(e) = heartbeat.ping_due() {
+                        break e;
+                    }
+                    let ping = json!({ "op": "ping" }).to_string();
+                    if let Err(e) = stream.send(WsMessage::Text(ping)).await {
+                        break BybitError::from(e);
+                    }
+                }
+            }
+        };
+        for (_, (_, response)) in pending.drain() {
+            let _ = response.send(Err(BybitError::Base(error.to_string())));
+        }
+    }
+
+    /// Hands a trade stream response to the request waiting on its `reqId`.
+    fn resolve_trade_response(
+        msg: &str,
+        pending: &mut HashMap<String, PendingCall>,
+        rate_limiter: &RateLimiter,
+    ) {
+        let Ok(response) = serde_json::from_str::<Value>(msg) else {
+            return;
+        };
+        let Some((limit, response_tx)) = response["reqId"]
+            .as_str()
+            .and_then(|req_id| pending.remove(req_id))
+        else {
+            return;
+        };
+        if let Ok(header) = serde_json::from_value::<Header>(response["header"].clone()) {
+            rate_limiter.update_from_header(&limit, &header);
+        }
+        let code = response["retCode"].as_i64().unwrap_or(-1);
+        let result = if code == 0 {
+            serde_json::from_value::<OrderIds>(response["data"].clone())
+                .map_err(BybitError::from)
+        } else {
+            Err(BybitError::TradeRequestRejected {
+                code,
+                msg: response["retMsg"].as_str().unwrap_or_default().to_string(),
+            })
+        };
+        let _ = response_tx.send(result);
+    }
+
+    pub async fn event_loop<'a, H>(
+        stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
         mut handler: H,
-        mut order_sender: Option<mpsc::UnboundedReceiver<RequestType<'_>>>,
+        order_sender: Option<mpsc::UnboundedReceiver<RequestType<'_>>>,
+    ) -> Result<(), BybitError>
+    where
+        H: WebSocketHandler,
+    {
+        let mut heartbeat = Heartbeat::new(HeartbeatPolicy::default(), Arc::default());
+        Self::loop_result(
+            Self::run_event_loop(
+                stream,
+                &mut handler,
+                order_sender,
+                &[],
+                &mut heartbeat,
+                &ServerClock::default(),
+            )
+            .await,
+        )
+    }
+
+    /// Runs the event loop with this stream's heartbeat settings.
+    async fn run<H>(
+        &self,
+        stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+        mut handler: H,
+        order_sender: Option<mpsc::UnboundedReceiver<RequestType<'_>>>,
+        requests: &[String],
+        latency: &SharedLatency,
     ) -> Result<(), BybitError>
     where
         H: WebSocketHandler,
     {
-        let mut interval = Instant::now();
+        let mut heartbeat = self.heartbeat(latency);
+        Self::loop_result(
+            Self::run_event_loop(
+                stream,
+                &mut handler,
+                order_sender,
+                requests,
+                &mut heartbeat,
+                &self.client.clock,
+            )
+            .await,
+        )
+    }
+
+    fn loop_result(result: Result<(), LoopExit>) -> Result<(), BybitError> {
+        match result {
+            Ok(()) => Ok(()),
+            Err(LoopExit::Handler(_)) => Err(BybitError::Base(
+                "Error handling stream message".to_string(),
+            )),
+            Err(LoopExit::Socket(e)) | Err(LoopExit::Fatal(e)) => Err(e),
+        }
+    }
+
+    /// Returns the `req_id` and topics of a request built by `build_subscription`.
+    fn subscription_topics(request: &str) -> Option<(String, Vec<String>)> {
+        let request: Value = serde_json::from_str(request).ok()?;
+        let topics = request["args"]
+            .as_array()?
+            .iter()
+            .filter_map(|arg| arg.as_str().map(String::from))
+            .collect();
+        Some((request["req_id"].as_str()?.to_string(), topics))
+    }
+
+    async fn run_event_loop<H>(
+        mut stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+        handler: &mut H,
+        mut order_sender: Option<mpsc::UnboundedReceiver<RequestType<'_>>>,
+        requests: &[String],
+        heartbeat: &mut Heartbeat,
+        clock: &ServerClock,
+    ) -> Result<(), LoopExit>
+    where
+        H: WebSocketHandler,
+    {
+        let subscriptions: Vec<(String, Vec<String>)> = requests
+            .iter()
+            .filter_map(|request| Self::subscription_topics(request))
+            .collect();
+        let mut ping = heartbeat.interval();
+        // Backpressure only pauses the reader: pings and orders keep flowing while a
+        // blocked consumer catches up, so the server does not drop the connection.
+        let mut ready = false;
         loop {
-            let msg = stream.next().await;
-            match msg {
-                Some(Ok(WsMessage::Text(msg))) => {
-                    if let Err(_) = handler.handle_msg(&msg) {
-                        return Err(BybitError::Base(
-                            "Error handling stream message".to_string(),
-                        ));
-                    }
+            tokio::select! {
+                result = std::future::poll_fn(|cx| handler.poll_ready(cx)), if !ready => {
+                    result.map_err(LoopExit::Handler)?;
+                    ready = true;
                 }
-                Some(Err(e)) => {
-                    return Err(BybitError::from(e.to_string()));
-                }
-                None => {
-                    return Err(BybitError::Base("Stream was closed".to_string()));
-                }
-                _ => {}
-            }
-            if let Some(sender) = order_sender.as_mut() {
-                if let Some(v) = sender.recv().await {
-                    let order_req = Self::build_trade_subscription(v, Some(3000));
-                    stream.send(WsMessage::Text(order_req)).await?;
+                msg = stream.next(), if ready => match msg {
+                    Some(Ok(WsMessage::Text(msg))) => {
+                        ready = false;
+                        heartbeat.on_message(&msg);
+                        Self::dispatch_frame(handler, &msg, &subscriptions)?;
+                    }
+                    Some(Err(e)) => {
+                        return Err(LoopExit::Socket(BybitError::from(e.to_string())));
+                    }
+                    None => {
+                        return Err(LoopExit::Socket(BybitError::Base(
+                            "Stream was closed".to_string(),
+                        )));
+                    }
+                    _ => {}
+                },
+                order = Self::next_order(&mut order_sender) => match order {
+                    Some(v) => {
+                        let order_req = Self::build_trade_request(
+                            v,
+                            &generate_random_uid(16),
+                            clock.now(),
+                            Some(3000),
+                        );
+                        stream
+                            .send(WsMessage::Text(order_req))
+                            .await
+                            .map_err(|e| LoopExit::Socket(e.into()))?;
+                    }
+                    // The sender was dropped; keep reading responses.
+                    None => order_sender = None,
+                },
+                _ = ping.tick() => {
+                    if ready {
+                        heartbeat.ping_due().map_err(LoopExit::Socket)?;
+                    } else {
+                        heartbeat.ping_paused();
+                    }
+                    let mut parameters: BTreeMap<String, Value> = BTreeMap::new();
+                    if order_sender.is_none() {
+                        parameters.insert("req_id".into(), generate_random_uid(8).into());
+                    }
+                    parameters.insert("op".into(), "ping".into());
+                    let request = build_json_request(&parameters);
+                    stream
+                        .send(WsMessage::Text(request))
+                        .await
+                        .map_err(|e| LoopExit::Socket(e.into()))?;
                 }
             }
+        }
+    }
 
-            if interval.elapsed() > Duration::from_secs(300) {
-                let mut parameters: BTreeMap<String, Value> = BTreeMap::new();
-                if order_sender.is_none() {
-                    parameters.insert("req_id".into(), generate_random_uid(8).into());
-                }
-                parameters.insert("op".into(), "ping".into());
-                let request = build_json_request(&parameters);
-                let _ = stream
-                    .send(WsMessage::Text(request))
-                    .await
-                    .map_err(BybitError::from);
-                interval = Instant::now();
+    /// Hands a text frame to the handler, routing auth replies and subscribe acks to
+    /// `handle_control`. `subscriptions` resolves acks that only carry a `req_id`.
+    ///
+    /// The frame's envelope is parsed once here and shared by the control check and
+    /// `handle_frame`.
+    fn dispatch_frame<H>(
+        handler: &mut H,
+        msg: &str,
+        subscriptions: &[(String, Vec<String>)],
+    ) -> Result<(), LoopExit>
+    where
+        H: WebSocketHandler,
+    {
+        let frame = match Frame::parse(msg) {
+            Ok(frame) => frame,
+            // Not JSON: left to the handler, which reports it as a `ParseError`
+            Err(_) => return handler.handle_msg(msg).map_err(LoopExit::Handler),
+        };
+        let control = WebsocketEvents::control_frame(&frame, |req_id| {
+            subscriptions
+                .iter()
+                .find(|(id, _)| id == req_id)
+                .map(|(_, topics)| topics.clone())
+        });
+        match control {
+            Some(WebsocketEvents::AuthFailed(reason)) => {
+                let event = WebsocketEvents::AuthFailed(reason.clone());
+                handler
+                    .handle_control(event, msg)
+                    .map_err(LoopExit::Handler)?;
+                Err(LoopExit::Fatal(BybitError::AuthFailed(reason)))
             }
+            Some(event) => handler
+                .handle_control(event, msg)
+                .map_err(LoopExit::Handler),
+            None => handler.handle_frame(&frame).map_err(LoopExit::Handler),
+        }
+    }
+
+    /// Dispatches a recorded frame the same way the event loop dispatches a live one.
+    pub(crate) fn replay_frame<H>(handler: &mut H, msg: &str) -> Result<(), BybitError>
+    where
+        H: WebSocketHandler,
+    {
+        Self::dispatch_frame(handler, msg, &[]).map_err(|exit| match exit {
+            LoopExit::Handler(e) | LoopExit::Socket(e) | LoopExit::Fatal(e) => e,
+        })
+    }
+
+    /// Waits for the next queued order, or forever if there is no order receiver.
+    async fn next_order<'a>(
+        receiver: &mut Option<mpsc::UnboundedReceiver<RequestType<'a>>>,
+    ) -> Option<RequestType<'a>> {
+        match receiver {
+            Some(receiver) => receiver.recv().await,
+            None => std::future::pending().await,
         }
     }
 }
@@ -545,6 +2136,106 @@ impl Stream {
 pub trait WebSocketHandler {
     type Event;
     fn handle_msg(&mut self, msg: &str) -> Result<(), BybitError>;
+
+    /// Called by a reconnecting stream once the connection has been restored.
+    fn handle_reconnect(&mut self, _event: ReconnectEvent) -> Result<(), BybitError> {
+        Ok(())
+    }
+
+    /// Called for every frame that is not a control frame, with its envelope already
+    /// parsed. Forwards the raw frame to `handle_msg` by default; handlers that parse
+    /// frames into events override it to use `WebsocketEvents::from_parsed_frame`,
+    /// which does not parse the envelope again.
+    fn handle_frame(&mut self, frame: &Frame<'_>) -> Result<(), BybitError> {
+        self.handle_msg(frame.as_str())
+    }
+
+    /// Called instead of `handle_msg` for auth replies and subscribe acks, with the
+    /// parsed control event and the raw frame. Forwards the frame by default.
+    fn handle_control(
+        &mut self,
+        _event: WebsocketEvents,
+        msg: &str,
+    ) -> Result<(), BybitError> {
+        self.handle_msg(msg)
+    }
+
+    /// Polled before every frame is read. Returning `Poll::Pending` applies
+    /// backpressure by pausing the reader; an error stops the event loop.
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), BybitError>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Handler used by the `ws_*` helpers: maps every event into an `EventSender` and
+/// forwards the sender's readiness to the event loop. Frames that fail to parse or
+/// that no event type handles are logged at `warn` level.
+struct SenderHandler<S, F, T> {
+    sender: S,
+    map: F,
+    _value: PhantomData<fn(T)>,
+}
+
+impl<S, F, T> SenderHandler<S, F, T>
+where
+    S: EventSender<T>,
+    F: FnMut(WebsocketEvents, &S) -> Result<(), BybitError>,
+{
+    fn new(sender: S, map: F) -> Self {
+        Self {
+            sender,
+            map,
+            _value: PhantomData,
+        }
+    }
+
+    fn handle_event(&mut self, event: WebsocketEvents) -> Result<(), BybitError> {
+        // The sender only takes the helper's own event type, so frames that do not
+        // map to it are logged rather than lost silently.
+        match event {
+            WebsocketEvents::ParseError(e) => {
+                tracing::warn!("Dropping frame that failed to parse: {}", e.error);
+                tracing::debug!("Unparsed frame: {}", e.frame);
+                Ok(())
+            }
+            WebsocketEvents::Unknown(frame) if !is_pong(&frame) => {
+                tracing::warn!("Dropping unhandled frame: {}", frame);
+                Ok(())
+            }
+            event => (self.map)(event, &self.sender),
+        }
+    }
+}
+
+impl<S, F, T> WebSocketHandler for SenderHandler<S, F, T>
+where
+    S: EventSender<T>,
+    F: FnMut(WebsocketEvents, &S) -> Result<(), BybitError>,
+{
+    type Event = WebsocketEvents;
+    fn handle_msg(&mut self, msg: &str) -> Result<(), BybitError> {
+        self.handle_event(WebsocketEvents::from_frame(msg))
+    }
+
+    fn handle_frame(&mut self, frame: &Frame<'_>) -> Result<(), BybitError> {
+        self.handle_event(WebsocketEvents::from_parsed_frame(frame))
+    }
+
+    fn handle_reconnect(&mut self, event: ReconnectEvent) -> Result<(), BybitError> {
+        (self.map)(WebsocketEvents::Reconnected(event), &self.sender)
+    }
+
+    fn handle_control(
+        &mut self,
+        event: WebsocketEvents,
+        _msg: &str,
+    ) -> Result<(), BybitError> {
+        (self.map)(event, &self.sender)
+    }
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), BybitError>> {
+        self.sender.poll_ready(cx)
+    }
 }
 
 impl<F> WebSocketHandler for F
@@ -553,11 +2244,22 @@ where
 {
     type Event = WebsocketEvents;
     fn handle_msg(&mut self, msg: &str) -> Result<(), BybitError> {
-        let update: Value = serde_json::from_str(msg)?;
-        if let Ok(event) = serde_json::from_value::<WebsocketEvents>(update.clone()) {
-            self(event)?;
-        }
+        self(WebsocketEvents::from_frame(msg))
+    }
 
-        Ok(())
+    fn handle_frame(&mut self, frame: &Frame<'_>) -> Result<(), BybitError> {
+        self(WebsocketEvents::from_parsed_frame(frame))
+    }
+
+    fn handle_reconnect(&mut self, event: ReconnectEvent) -> Result<(), BybitError> {
+        self(WebsocketEvents::Reconnected(event))
+    }
+
+    fn handle_control(
+        &mut self,
+        event: WebsocketEvents,
+        _msg: &str,
+    ) -> Result<(), BybitError> {
+        self(event)
     }
 }
@@ -2,6 +2,7 @@ use bybit::{
     account::AccountManager,
     api::*,
     enable_tracing,
+    mock::MockRestServer,
     model::*,
     test_utils::{
         api_key,
@@ -37,3 +38,23 @@ async fn test_borrow_history() {
 
     tracing::info!("{:?}", wallet);
 }
+
+#[tokio::test]
+async fn test_mock_signature_check() {
+    let server = MockRestServer::start().await.unwrap();
+    let account: AccountManager = Bybit::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    );
+    let wallet = account.get_wallet_balance("UNIFIED", None).await.unwrap();
+    assert_eq!(wallet.result.list.len(), 1);
+
+    let forged: AccountManager = Bybit::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some("wrong-secret".to_string()),
+    );
+    assert!(forged.get_wallet_balance("UNIFIED", None).await.is_err());
+    assert_eq!(server.requests().len(), 2);
+}
@@ -0,0 +1,48 @@
+use bybit::{
+    api::{
+        Bybit,
+        BybitClient,
+    },
+    mock::{
+        MockRestServer,
+        MockWsServer,
+    },
+    model::{
+        Category,
+        OrderbookRequest,
+        Subscription,
+        WebsocketEvents,
+    },
+};
+use futures::StreamExt;
+
+#[tokio::test]
+async fn test_client_views() {
+    let rest = MockRestServer::start().await.unwrap();
+    let ws = MockWsServer::start().await.unwrap();
+    let config = rest.config().set_ws_endpoint(ws.endpoint().to_string());
+    let bybit = BybitClient::new_with_config(
+        &config,
+        Some(rest.api_key().to_string()),
+        Some(rest.secret().to_string()),
+    );
+
+    let request = OrderbookRequest::new("BTCUSDT", Category::Linear, None);
+    assert!(bybit.market().get_depth(request).await.is_ok());
+    assert!(bybit.general().get_server_time().await.is_ok());
+    assert!(bybit
+        .account()
+        .get_wallet_balance("UNIFIED", None)
+        .await
+        .is_ok());
+    assert_eq!(rest.requests().len(), 3);
+
+    let request = Subscription::new("subscribe", vec!["order"]);
+    let mut events = bybit.ws().subscribe_private(request);
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(WebsocketEvents::AuthOk))
+    ));
+    let auth = &ws.requests()[0];
+    assert_eq!(auth["args"][0], rest.api_key());
+}
@@ -6,7 +6,9 @@ use bybit::{
     api::*,
     asset::AssetManager,
     enable_tracing,
+    mock::MockRestServer,
     test_utils::{
+        amount,
         api_key,
         secret,
     },
@@ -23,7 +25,7 @@ async fn test_quota() {
             "ETH",
             "USDT",
             "ETH",
-            0.95.to_string(),
+            amount("0.95"),
             QuotaAccountType::Funding,
         )
         .await
@@ -42,3 +44,19 @@ async fn test_query_all_coins() {
 
     tracing::info!("{:?}", coins);
 }
+
+#[tokio::test]
+async fn test_mock_query_all_coins() {
+    let server = MockRestServer::start().await.unwrap();
+    let asset: AssetManager = Bybit::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    );
+    let coins = asset
+        .query_all_coins(AccountType::Funding, Some(&["USDT"]))
+        .await
+        .unwrap();
+    assert_eq!(coins.member_id, "1234567");
+    assert_eq!(server.requests()[0].query, "accountType=FUND&coins=USDT");
+}
@@ -0,0 +1,125 @@
+use bybit::{
+    channel::{
+        bounded_channel,
+        EventSender,
+        OverflowPolicy,
+    },
+    errors::BybitError,
+};
+use futures::task::{
+    noop_waker_ref,
+    waker,
+    ArcWake,
+};
+use std::{
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+    task::{
+        Context,
+        Poll,
+    },
+};
+
+#[derive(Default)]
+struct Flag(AtomicBool);
+
+impl ArcWake for Flag {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_drop_oldest() {
+    let (tx, mut rx) = bounded_channel(2, OverflowPolicy::DropOldest);
+    for i in 0..5 {
+        tx.send("tickers.BTCUSDT", i).unwrap();
+    }
+    assert_eq!(tx.dropped(), 3);
+    assert_eq!(rx.try_recv(), Some(3));
+    assert_eq!(rx.try_recv(), Some(4));
+    assert_eq!(rx.try_recv(), None);
+}
+
+#[test]
+fn test_drop_newest() {
+    let (tx, mut rx) = bounded_channel(2, OverflowPolicy::DropNewest);
+    for i in 0..5 {
+        tx.send("tickers.BTCUSDT", i).unwrap();
+    }
+    assert_eq!(rx.dropped(), 3);
+    assert_eq!(rx.try_recv(), Some(0));
+    assert_eq!(rx.try_recv(), Some(1));
+    assert_eq!(rx.try_recv(), None);
+}
+
+#[test]
+fn test_conflate_latest() {
+    let (tx, mut rx) = bounded_channel(2, OverflowPolicy::ConflateLatest);
+    tx.send("tickers.BTCUSDT", 1).unwrap();
+    tx.send("tickers.ETHUSDT", 2).unwrap();
+    tx.send("tickers.BTCUSDT", 3).unwrap();
+    assert_eq!(tx.len(), 2);
+    assert_eq!(rx.try_recv(), Some(3));
+    assert_eq!(rx.try_recv(), Some(2));
+    // A new topic on a full queue evicts the oldest entry.
+    tx.send("tickers.BTCUSDT", 4).unwrap();
+    tx.send("tickers.ETHUSDT", 5).unwrap();
+    tx.send("tickers.SOLUSDT", 6).unwrap();
+    assert_eq!(rx.dropped(), 2);
+    assert_eq!(rx.try_recv(), Some(5));
+    assert_eq!(rx.try_recv(), Some(6));
+}
+
+#[test]
+fn test_block_backpressure() {
+    let (tx, mut rx) = bounded_channel(1, OverflowPolicy::Block);
+    let mut cx = Context::from_waker(noop_waker_ref());
+    assert!(matches!(tx.poll_ready(&mut cx), Poll::Ready(Ok(()))));
+    tx.send_event("orderbook.1.BTCUSDT", 1).unwrap();
+    assert!(tx.poll_ready(&mut cx).is_pending());
+    assert_eq!(rx.try_recv(), Some(1));
+    assert!(matches!(tx.poll_ready(&mut cx), Poll::Ready(Ok(()))));
+    drop(rx);
+    assert!(matches!(
+        tx.send("orderbook.1.BTCUSDT", 2),
+        Err(BybitError::ChannelClosed)
+    ));
+}
+
+#[test]
+fn test_block_wakes_every_sender() {
+    let (tx, mut rx) = bounded_channel(1, OverflowPolicy::Block);
+    let other = tx.clone();
+    tx.send("orderbook.1.BTCUSDT", 1).unwrap();
+    let flags = [Arc::new(Flag::default()), Arc::new(Flag::default())];
+    for (sender, flag) in [&tx, &other].into_iter().zip(&flags) {
+        let waker = waker(flag.clone());
+        let mut cx = Context::from_waker(&waker);
+        assert!(sender.poll_ready(&mut cx).is_pending());
+    }
+    assert_eq!(rx.try_recv(), Some(1));
+    // Both blocked senders are woken, not just the last one to register
+    assert!(flags.iter().all(|flag| flag.0.load(Ordering::SeqCst)));
+}
+
+#[tokio::test]
+async fn test_receiver_closes() {
+    let (tx, mut rx) = bounded_channel(4, OverflowPolicy::Block);
+    let task = tokio::spawn(async move {
+        for i in 0..3 {
+            tx.send("publicTrade.BTCUSDT", i).unwrap();
+        }
+    });
+    task.await.unwrap();
+    let mut values = Vec::new();
+    while let Some(value) = rx.recv().await {
+        values.push(value);
+    }
+    assert_eq!(values, vec![0, 1, 2]);
+}
@@ -0,0 +1,358 @@
+use bybit::{
+    api::{
+        Bybit,
+        BybitClient,
+        Market,
+        Trade,
+        API,
+    },
+    client::RetryPolicy,
+    errors::{
+        BybitError,
+        BybitErrorCode,
+        TransportErrorKind,
+    },
+    general::General,
+    mock::{
+        MockResponse,
+        MockRestServer,
+    },
+    model::{
+        Category,
+        OrderRequest,
+        OrderbookRequest,
+    },
+    test_utils::amount,
+    transport::{
+        HttpRequest,
+        HttpResponse,
+        Transport,
+    },
+};
+use futures::future::BoxFuture;
+use reqwest::{
+    header::HeaderMap,
+    Method,
+    StatusCode,
+};
+use serde_json::json;
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+use tokio::time::Duration;
+
+/// Answers from a queue of bodies and keeps every request it was given.
+#[derive(Clone, Default)]
+struct FakeTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+    responses: Arc<Mutex<VecDeque<(StatusCode, String)>>>,
+}
+
+impl FakeTransport {
+    fn reply(&self, status: StatusCode, body: serde_json::Value) {
+        let mut responses = self.responses.lock().unwrap();
+        responses.push_back((status, body.to_string()));
+    }
+}
+
+impl Transport for FakeTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, BybitError>> {
+        self.requests.lock().unwrap().push(request);
+        let (status, body) = self.responses.lock().unwrap().pop_front().unwrap();
+        Box::pin(async move {
+            Ok(HttpResponse {
+                status,
+                headers: HeaderMap::new(),
+                body,
+            })
+        })
+    }
+}
+
+fn bybit(server: &MockRestServer) -> BybitClient {
+    let policy = RetryPolicy::default().set_initial_delay(Duration::from_millis(10));
+    BybitClient::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    )
+    .with_retry(policy)
+}
+
+fn order(order_link_id: Option<&str>) -> OrderRequest<'_> {
+    OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.01"),
+        order_link_id: order_link_id.map(Cow::Borrowed),
+        ..OrderRequest::default()
+    }
+}
+
+#[test]
+fn test_retry_delay() {
+    let policy = RetryPolicy::default()
+        .set_initial_delay(Duration::from_millis(100))
+        .set_max_delay(Duration::from_millis(350))
+        .set_jitter(0.0);
+    assert_eq!(policy.delay(1), Duration::from_millis(100));
+    assert_eq!(policy.delay(2), Duration::from_millis(200));
+    assert_eq!(policy.delay(3), Duration::from_millis(350));
+    assert_eq!(policy.delay(u32::MAX), Duration::from_millis(350));
+
+    let policy = policy.set_jitter(0.5);
+    for _ in 0..100 {
+        let delay = policy.delay(1);
+        assert!(
+            delay >= Duration::from_millis(50) && delay <= Duration::from_millis(150)
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_retry_get() {
+    let server = MockRestServer::start().await.unwrap();
+    let route = || API::Market(Market::OrderBook);
+    server.respond_once(route(), MockResponse::Status(503));
+    server.respond_once(route(), MockResponse::RetCode(10016, "Server error".into()));
+    server.respond_once(
+        route(),
+        MockResponse::RetCode(10006, "Too many visits!".into()),
+    );
+
+    let market = bybit(&server).market();
+    let depth = market
+        .get_depth(OrderbookRequest::new("BTCUSDT", Category::Linear, None))
+        .await
+        .unwrap();
+    assert_eq!(depth.result.symbol, "BTCUSDT");
+    assert_eq!(server.hits(route()), 4);
+
+    // Errors that cannot be fixed by resending are returned at once
+    server.respond_once(route(), MockResponse::Status(404));
+    let depth = market
+        .get_depth(OrderbookRequest::new("BTCUSDT", Category::Linear, None))
+        .await;
+    assert!(matches!(depth, Err(BybitError::StatusCode(404))));
+    assert_eq!(server.hits(route()), 5);
+
+    // Retries stop after `max_retries`
+    for _ in 0..4 {
+        server.respond_once(route(), MockResponse::Status(503));
+    }
+    let depth = market
+        .get_depth(OrderbookRequest::new("BTCUSDT", Category::Linear, None))
+        .await;
+    assert!(matches!(depth, Err(BybitError::ServiceUnavailable)));
+    assert_eq!(server.hits(route()), 9);
+}
+
+#[tokio::test]
+async fn test_retry_post() {
+    let server = MockRestServer::start().await.unwrap();
+    let route = || API::Trade(Trade::Place);
+    let trade = bybit(&server).trade();
+
+    // A rate limited order never reached the matching engine
+    server.respond_once(
+        route(),
+        MockResponse::RetCode(10006, "Too many visits!".into()),
+    );
+    trade.place_custom_order(order(None)).await.unwrap();
+    assert_eq!(server.hits(route()), 2);
+
+    // Without an order_link_id a failed order cannot be checked
+    server.respond_once(route(), MockResponse::Status(503));
+    let placed = trade.place_custom_order(order(None)).await;
+    assert!(matches!(placed, Err(BybitError::ServiceUnavailable)));
+    assert_eq!(server.hits(route()), 3);
+}
+
+#[tokio::test]
+async fn test_retry_order_link_id() {
+    let server = MockRestServer::start().await.unwrap();
+    let route = || API::Trade(Trade::Place);
+    let lookup = || API::Trade(Trade::OpenOrders);
+    let history = || API::Trade(Trade::History);
+    let no_orders = || {
+        MockResponse::ok(json!({"category": "linear", "list": [], "nextPageCursor": ""}))
+    };
+    let trade = bybit(&server).trade();
+
+    // The order was placed before the failure, it is returned instead of resent
+    server.respond_once(route(), MockResponse::Status(503));
+    let placed = trade
+        .place_custom_order(order(Some("mock-order")))
+        .await
+        .unwrap();
+    assert_eq!(placed.result.order_id, "1321003749386327552");
+    assert_eq!(placed.result.order_link_id, "mock-order");
+    assert_eq!(server.hits(route()), 1);
+    assert_eq!(server.hits(lookup()), 1);
+    let request = server.requests().pop().unwrap();
+    assert!(request.query.contains("orderLinkId=mock-order"));
+
+    // The order is unknown, so it is safe to resend
+    server.respond_once(route(), MockResponse::Status(503));
+    server.respond_once(lookup(), no_orders());
+    server.respond_once(history(), no_orders());
+    trade
+        .place_custom_order(order(Some("mock-order")))
+        .await
+        .unwrap();
+    assert_eq!(server.hits(route()), 3);
+    assert_eq!(server.hits(lookup()), 2);
+    assert_eq!(server.hits(history()), 1);
+
+    // A resend rejected as a duplicate means an earlier attempt went through
+    server.respond_once(route(), MockResponse::Status(503));
+    server.respond_once(lookup(), no_orders());
+    server.respond_once(history(), no_orders());
+    server.respond_once(
+        route(),
+        MockResponse::RetCode(110072, "OrderLinkedID is duplicate".into()),
+    );
+    server.respond_once(lookup(), no_orders());
+    let placed = trade
+        .place_custom_order(order(Some("mock-order")))
+        .await
+        .unwrap();
+    assert_eq!(placed.result.order_link_id, "mock-order");
+    assert_eq!(server.hits(route()), 5);
+    assert_eq!(server.hits(lookup()), 4);
+    assert_eq!(server.hits(history()), 3);
+}
+
+#[tokio::test]
+async fn test_retry_after_timeout_with_lagging_history() {
+    let server = MockRestServer::start().await.unwrap();
+    let route = || API::Trade(Trade::Place);
+    let lookup = || API::Trade(Trade::OpenOrders);
+    let history = || API::Trade(Trade::History);
+    let no_orders = || {
+        MockResponse::ok(json!({"category": "linear", "list": [], "nextPageCursor": ""}))
+    };
+    let duplicate = || MockResponse::RetCode(110072, "OrderLinkedID is duplicate".into());
+    let trade = bybit(&server).trade();
+
+    // The timed out order went through, but the history does not show it yet, so
+    // the lookup misses and the order is resent. Bybit rejects the resend as a
+    // duplicate, and the order is found once the history catches up.
+    server.respond_once(route(), MockResponse::Status(504));
+    server.respond_once(lookup(), no_orders());
+    server.respond_once(history(), no_orders());
+    server.respond_once(route(), duplicate());
+    let placed = trade
+        .place_custom_order(order(Some("mock-order")))
+        .await
+        .unwrap();
+    assert_eq!(placed.result.order_id, "1321003749386327552");
+    assert_eq!(placed.result.order_link_id, "mock-order");
+    assert_eq!(server.hits(route()), 2);
+    assert_eq!(server.hits(lookup()), 2);
+    assert_eq!(server.hits(history()), 1);
+
+    // While the history still lags, the duplicate rejection is returned rather
+    // than a third placement.
+    server.respond_once(route(), MockResponse::Status(504));
+    for _ in 0..2 {
+        server.respond_once(lookup(), no_orders());
+        server.respond_once(history(), no_orders());
+    }
+    server.respond_once(route(), duplicate());
+    let err = trade
+        .place_custom_order(order(Some("mock-order")))
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_code(), Some(BybitErrorCode::DuplicateOrderLinkId));
+    assert_eq!(server.hits(route()), 4);
+}
+
+#[tokio::test]
+async fn test_fake_transport() {
+    let transport = FakeTransport::default();
+    let bybit = BybitClient::new(Some("key".into()), Some("secret".into()))
+        .with_transport(transport.clone());
+
+    transport.reply(
+        StatusCode::OK,
+        json!({
+            "retCode": 0, "retMsg": "OK", "retExtInfo": {}, "time": 1,
+            "result": {"timeSecond": "1", "timeNano": "1000000000"},
+        }),
+    );
+    let time = bybit.general().get_server_time().await.unwrap();
+    assert_eq!(time.result.time_nano, 1_000_000_000);
+
+    transport.reply(
+        StatusCode::OK,
+        json!({
+            "retCode": 0, "retMsg": "OK", "retExtInfo": {}, "time": 1,
+            "result": {"orderId": "1", "orderLinkId": "fake"},
+        }),
+    );
+    let placed = bybit
+        .trade()
+        .place_custom_order(order(Some("fake")))
+        .await
+        .unwrap();
+    assert_eq!(placed.result.order_id, "1");
+
+    transport.reply(
+        StatusCode::BAD_REQUEST,
+        json!({"code": 10001, "msg": "bad"}),
+    );
+    let time = bybit.general().get_server_time().await;
+    assert!(matches!(time, Err(BybitError::BybitError(e)) if e.code == 10001));
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, Method::GET);
+    assert!(requests[0].url.ends_with("/v5/market/time"));
+    assert_eq!(requests[1].method, Method::POST);
+    assert!(requests[1].url.ends_with("/v5/order/create"));
+    assert!(requests[1].headers.contains_key("x-bapi-sign"));
+    assert_eq!(requests[1].headers["x-bapi-api-key"], "key");
+    assert!(requests[1]
+        .body
+        .as_ref()
+        .unwrap()
+        .contains("\"orderLinkId\":\"fake\""));
+}
+
+#[tokio::test]
+async fn test_reqwest_transport() {
+    let server = MockRestServer::start().await.unwrap();
+    let transport = reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+    let general: General = Bybit::new_with_config(&server.config(), None, None);
+    let general = General {
+        client: general.client.with_transport(transport),
+    };
+    general.get_server_time().await.unwrap();
+    assert_eq!(server.hits(API::Market(Market::Time)), 1);
+
+    // Nothing listens on the port of a dropped listener
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+    let config = server.config().set_rest_api_endpoint(endpoint);
+    let general: General = Bybit::new_with_config(&config, None, None);
+    let time = general.get_server_time().await;
+    assert!(matches!(
+        time,
+        Err(BybitError::Transport {
+            kind: TransportErrorKind::Connect,
+            ..
+        })
+    ));
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "decimal")]
+
+use bybit::{
+    api::{
+        Bybit,
+        Trade,
+        API,
+    },
+    mock::MockRestServer,
+    model::{
+        Amount,
+        Ask,
+        Bid,
+        LocalOrderBook,
+        OrderBookUpdate,
+        OrderRequest,
+        Side,
+    },
+    test_utils::amount,
+    trade::Trader,
+};
+use std::borrow::Cow;
+
+#[test]
+fn test_decimal_round_trip() {
+    let ask: Ask = serde_json::from_str(r#"{"price":"60000.10","qty":"0.30"}"#).unwrap();
+    assert_eq!(ask.price, Amount::new(6000010, 2));
+    assert_eq!(ask.qty.to_string(), "0.30");
+    assert_eq!(
+        serde_json::to_string(&ask).unwrap(),
+        r#"{"price":"60000.10","qty":"0.30"}"#
+    );
+
+    let bid = Bid::new(amount("0.1") + amount("0.2"), amount("0.00000001"));
+    assert_eq!(bid.price, amount("0.3"));
+    assert_eq!(
+        serde_json::to_string(&bid).unwrap(),
+        r#"{"price":"0.3","qty":"0.00000001"}"#
+    );
+}
+
+#[test]
+fn test_decimal_order_book() {
+    let msg = r#"{"topic":"orderbook.1.BTCUSDT","type":"snapshot","ts":1,"data":{"s":"BTCUSDT","b":[["0.1","3"]],"a":[["0.2","4"]],"u":1,"seq":10},"cts":1}"#;
+    let update: OrderBookUpdate = serde_json::from_str(msg).unwrap();
+    let mut book = LocalOrderBook::new("BTCUSDT");
+    book.apply_update(&update).unwrap();
+    assert_eq!(book.spread(), Some(amount("0.1")));
+    assert_eq!(book.mid_price(), Some(amount("0.15")));
+    // Levels are found by value, whatever their scale
+    assert_eq!(book.depth_at(Side::Buy, amount("0.10")), amount("3"));
+    assert_eq!(book.depth_at(Side::Sell, amount("0.3")), Amount::default());
+}
+
+#[tokio::test]
+async fn test_decimal_order_request() {
+    let server = MockRestServer::start().await.unwrap();
+    let trade: Trader = Bybit::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    );
+    let order = OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.1") + amount("0.2"),
+        price: Some(amount("60000.10")),
+        ..OrderRequest::default()
+    };
+    trade.place_custom_order(order).await.unwrap();
+    assert_eq!(server.hits(API::Trade(Trade::Place)), 1);
+    let request = server.requests().pop().unwrap();
+    assert!(request.body.contains(r#""qty":"0.3""#));
+    assert!(request.body.contains(r#""price":"60000.10""#));
+}
@@ -0,0 +1,121 @@
+use bybit::{
+    api::{
+        Bybit,
+        Position,
+        Trade,
+        API,
+    },
+    errors::{
+        BybitContentError,
+        BybitError,
+        BybitErrorCategory,
+        BybitErrorCode,
+        TransportErrorKind,
+    },
+    mock::{
+        MockResponse,
+        MockRestServer,
+    },
+    model::{
+        Category,
+        LeverageRequest,
+        OrderRequest,
+    },
+    position::PositionManager,
+    test_utils::amount,
+    trade::Trader,
+};
+use std::borrow::Cow;
+
+#[test]
+fn test_error_codes() {
+    for code in [10003, 10006, 110007, 110025, 170131, 12345] {
+        assert_eq!(BybitErrorCode::from_code(code).code(), code);
+    }
+    let category = |code| BybitErrorCode::from(code).category();
+    assert_eq!(category(10004), BybitErrorCategory::Auth);
+    assert_eq!(category(10018), BybitErrorCategory::RateLimit);
+    assert_eq!(category(110007), BybitErrorCategory::InsufficientBalance);
+    assert_eq!(category(170131), BybitErrorCategory::InsufficientBalance);
+    assert_eq!(category(110072), BybitErrorCategory::OrderRejected);
+    assert_eq!(category(110025), BybitErrorCategory::PositionModeMismatch);
+    assert_eq!(category(12345), BybitErrorCategory::Unknown);
+
+    assert!(BybitErrorCode::TooManyVisits.is_retryable());
+    assert!(BybitErrorCode::ServerError.is_retryable());
+    assert!(!BybitErrorCode::InvalidSignature.is_retryable());
+    assert!(!BybitErrorCode::Other(12345).is_retryable());
+
+    let error: BybitContentError =
+        serde_json::from_str(r#"{"retCode": 110007, "retMsg": "ab not enough"}"#)
+            .unwrap();
+    assert_eq!(
+        error.error_code(),
+        BybitErrorCode::InsufficientAvailableBalance
+    );
+    let error = BybitError::TradeRequestRejected {
+        code: 10016,
+        msg: "Server error".into(),
+    };
+    assert_eq!(error.error_code(), Some(BybitErrorCode::ServerError));
+    assert!(error.is_retryable());
+}
+
+#[test]
+fn test_transport_errors() {
+    let error = |kind| BybitError::Transport {
+        kind,
+        message: "error sending request".into(),
+    };
+    assert!(error(TransportErrorKind::Connect).is_retryable());
+    assert!(error(TransportErrorKind::Connect).is_safe_to_resend());
+    assert!(error(TransportErrorKind::Timeout).is_retryable());
+    assert!(!error(TransportErrorKind::Timeout).is_safe_to_resend());
+    assert!(!error(TransportErrorKind::Other).is_retryable());
+    assert!(!error(TransportErrorKind::Other).is_safe_to_resend());
+}
+
+#[tokio::test]
+async fn test_mock_ret_code_errors() {
+    let server = MockRestServer::start().await.unwrap();
+    let trade: Trader = Bybit::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    );
+    server.respond_once(
+        API::Trade(Trade::Place),
+        MockResponse::RetCode(110007, "ab not enough for new order".into()),
+    );
+    let order = OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.01"),
+        ..OrderRequest::default()
+    };
+    match trade.place_custom_order(order).await {
+        Err(BybitError::BybitError(error)) => {
+            assert_eq!(error.code, 110007);
+            assert_eq!(
+                error.error_code().category(),
+                BybitErrorCategory::InsufficientBalance
+            );
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    let position = PositionManager {
+        client: trade.client.clone(),
+        recv_window: trade.recv_window,
+    };
+    server.respond_once(
+        API::Position(Position::SetLeverage),
+        MockResponse::RetCode(110043, "Set leverage not modified".into()),
+    );
+    let leverage = LeverageRequest::new(Category::Linear, "BTCUSDT", 10);
+    let error = position.set_leverage(leverage).await.unwrap_err();
+    assert_eq!(
+        error.error_code(),
+        Some(BybitErrorCode::LeverageNotModified)
+    );
+    assert!(!error.is_retryable());
+}
@@ -3,14 +3,43 @@
 use bybit::general::General;
 // Import the tokio test module.
 use bybit::{
-    api::Bybit,
+    api::{
+        Bybit,
+        BybitClient,
+        Trade,
+        API,
+    },
     enable_tracing,
+    mock::{
+        MockRestServer,
+        MockWsServer,
+    },
+    model::{
+        BatchPlaceRequest,
+        Category,
+        OrderRequest,
+        RequestType,
+        Subscription,
+        WebsocketEvents,
+    },
     test_utils::{
+        amount,
         api_key,
         secret,
     },
+    util::get_timestamp,
+    ws::Stream,
+};
+use futures::StreamExt;
+use std::borrow::Cow;
+use tokio::{
+    sync::mpsc,
+    test,
+    time::{
+        sleep,
+        Duration,
+    },
 };
-use tokio::test;
 
 enable_tracing!();
 
@@ -45,3 +74,75 @@ async fn test_ping() {
         Err(err) => println!("{:#?}", err),
     }
 }
+
+#[test]
+async fn test_mock_sync_clock() {
+    let server = MockRestServer::start().await.unwrap();
+    server.set_clock_skew(60_000);
+    let bybit = BybitClient::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    );
+    let order = || OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.01"),
+        ..OrderRequest::default()
+    };
+
+    // Stamped with the local clock, the order falls outside its recv_window
+    assert!(bybit.trade().place_custom_order(order()).await.is_err());
+
+    let offset = bybit.general().sync_clock().await.unwrap();
+    assert!((offset - 60_000).abs() < 100, "offset {}", offset);
+    assert_eq!(bybit.client.clock.offset(), offset);
+    let placed = bybit.trade().place_custom_order(order()).await.unwrap();
+    assert_eq!(placed.result.order_link_id, "mock-order");
+    assert_eq!(server.hits(API::Trade(Trade::Place)), 2);
+}
+
+#[test]
+async fn test_mock_ws_auth_clock() {
+    let server = MockWsServer::start().await.unwrap();
+    let ws: Stream = Bybit::new_with_config(&server.config(), None, None);
+    ws.client.clock.set_offset(60_000);
+    let mut events = ws.subscribe_private(Subscription::new("subscribe", vec!["order"]));
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(WebsocketEvents::AuthOk))
+    ));
+
+    let auth = &server.requests()[0];
+    assert_eq!(auth["op"], "auth");
+    let expires = auth["args"][1].as_u64().unwrap();
+    assert!(expires >= get_timestamp() + 60_000);
+}
+
+#[test]
+async fn test_mock_ws_trade_stream_clock() {
+    let server = MockWsServer::start().await.unwrap();
+    let ws: Stream = Bybit::new_with_config(&server.config(), None, None);
+    ws.client.clock.set_offset(60_000);
+    let (orders, requests) = mpsc::unbounded_channel();
+    tokio::spawn(async move { ws.ws_trade_stream(requests, |_| Ok(())).await });
+    let order = OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        ..OrderRequest::default()
+    };
+    let batch = BatchPlaceRequest::new(Category::Linear, vec![order]);
+    orders.send(RequestType::Create(batch)).unwrap();
+
+    let create = loop {
+        let requests = server.requests();
+        if let Some(create) = requests.into_iter().find(|r| r["op"] == "order.create") {
+            break create;
+        }
+        sleep(Duration::from_millis(10)).await;
+    };
+    let timestamp: u64 = create["header"]["X-BAPI-TIMESTAMP"]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(timestamp >= get_timestamp() + 50_000);
+}
@@ -2,8 +2,14 @@ use bybit::{
     api::*,
     config::*,
     enable_tracing,
+    errors::BybitError,
     market::*,
+    mock::{
+        MockResponse,
+        MockRestServer,
+    },
     model::{
+        Amount,
         Category,
         FundingHistoryRequest,
         HistoricalVolatilityRequest,
@@ -15,6 +21,7 @@ use bybit::{
         RiskLimitRequest,
     },
     test_utils::{
+        amount,
         api_key,
         secret,
     },
@@ -70,28 +77,29 @@ async fn test_market() {
     while Instant::now() - start < end_minutes {
         let data = market.get_depth(request.clone()).await.unwrap();
         let order_book = data.result;
-        let mid_price = (order_book.asks[0].price + order_book.bids[0].price) / 2.0;
+        let mid_price =
+            (order_book.asks[0].price + order_book.bids[0].price) / amount("2");
         let imbalance = (order_book.bids[0].qty - order_book.asks[0].qty)
             / (order_book.asks[0].qty + order_book.bids[0].qty);
-        let fees = fee_percent(mid_price, 0.04);
+        let fees = fee_percent(mid_price, amount("0.04"));
         let spread = order_book.asks[0].price - order_book.bids[0].price;
         let arb = spread - fees;
         tracing::info!(
             "{:#?} , Spread: {:.5} Arb: {} Imb: {:.4}",
             order_book,
             spread,
-            if arb > fee_percent(mid_price, 0.02) {
+            if arb > fee_percent(mid_price, amount("0.02")) {
                 arb
             } else {
-                0.0
+                Amount::default()
             },
             imbalance
         );
     }
 }
 
-fn fee_percent(value: f64, percent: f64) -> f64 {
-    (percent / 100.0) * value
+fn fee_percent(value: Amount, percent: Amount) -> Amount {
+    (percent / amount("100")) * value
 }
 
 #[tokio::test]
@@ -198,3 +206,40 @@ async fn test_longshort_ratio() {
         tracing::info!("{:#?}", data.result);
     }
 }
+
+#[tokio::test]
+async fn test_mock_depth() {
+    let server = MockRestServer::start().await.unwrap();
+    let market: MarketData = Bybit::new_with_config(&server.config(), None, None);
+    let request = OrderbookRequest::new("BTCUSDT", Category::Linear, Some(50));
+    let depth = market.get_depth(request).await.unwrap();
+    assert_eq!(depth.result.symbol, "BTCUSDT");
+    assert_eq!(depth.result.bids.len(), 2);
+
+    let tickers = market.get_futures_tickers(Some("BTCUSDT")).await.unwrap();
+    assert_eq!(tickers.result.list[0].symbol, "BTCUSDT");
+    let requests = server.requests();
+    assert_eq!(requests[0].query, "category=linear&limit=50&symbol=BTCUSDT");
+    assert_eq!(server.hits(API::Market(Market::Tickers)), 1);
+}
+
+#[tokio::test]
+async fn test_mock_scripted_errors() {
+    let server = MockRestServer::start().await.unwrap();
+    let market: MarketData = Bybit::new_with_config(&server.config(), None, None);
+    let route = || API::Market(Market::OrderBook);
+    server.respond_once(route(), MockResponse::Status(503));
+    server.respond_once(route(), MockResponse::Raw(200, "{\"retCode\":".into()));
+    server.respond_once(
+        route(),
+        MockResponse::RetCode(10006, "Too many visits!".into()),
+    );
+
+    let depth =
+        || market.get_depth(OrderbookRequest::new("BTCUSDT", Category::Linear, None));
+    assert!(matches!(depth().await, Err(BybitError::ServiceUnavailable)));
+    assert!(depth().await.is_err());
+    assert!(depth().await.is_err());
+    assert!(depth().await.is_ok());
+    assert_eq!(server.hits(route()), 4);
+}
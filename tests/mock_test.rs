@@ -0,0 +1,400 @@
+use bybit::{
+    account::{
+        AccountManager,
+        AccountType,
+        QuotaAccountType,
+    },
+    api::*,
+    asset::AssetManager,
+    enable_tracing,
+    general::General,
+    market::MarketData,
+    mock::MockRestServer,
+    model::*,
+    position::PositionManager,
+    test_utils::amount,
+    trade::Trader,
+};
+use serde_json::{
+    json,
+    Value,
+};
+use std::borrow::Cow;
+
+enable_tracing!();
+
+fn manager<T: Bybit>(server: &MockRestServer) -> T {
+    Bybit::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    )
+}
+
+/// Every routed `API` variant. `Asset::SaveTransferSubmember`, `Asset::QueryAsset`,
+/// `Asset::Deposit` and `Asset::OrderRecord` have no path yet.
+fn routes() -> Vec<API> {
+    vec![
+        API::Market(Market::Time),
+        API::Market(Market::Kline),
+        API::Market(Market::MarkPriceKline),
+        API::Market(Market::IndexPriceKline),
+        API::Market(Market::PremiumIndexPriceKline),
+        API::Market(Market::InstrumentsInfo),
+        API::Market(Market::OrderBook),
+        API::Market(Market::Tickers),
+        API::Market(Market::FundingRate),
+        API::Market(Market::RecentTrades),
+        API::Market(Market::OpenInterest),
+        API::Market(Market::HistoricalVolatility),
+        API::Market(Market::Insurance),
+        API::Market(Market::RiskLimit),
+        API::Market(Market::DeliveryPrice),
+        API::Market(Market::LongShortRatio),
+        API::Trade(Trade::Place),
+        API::Trade(Trade::Amend),
+        API::Trade(Trade::Cancel),
+        API::Trade(Trade::OpenOrders),
+        API::Trade(Trade::CancelAll),
+        API::Trade(Trade::History),
+        API::Trade(Trade::TradeHistory),
+        API::Trade(Trade::BatchPlace),
+        API::Trade(Trade::BatchAmend),
+        API::Trade(Trade::BatchCancel),
+        API::Trade(Trade::SpotBorrowCheck),
+        API::Trade(Trade::SetDisconnectCancelall),
+        API::Position(Position::Information),
+        API::Position(Position::SetLeverage),
+        API::Position(Position::SetRiskLimit),
+        API::Position(Position::SetTradingStop),
+        API::Position(Position::SwitchIsolated),
+        API::Position(Position::SwitchMode),
+        API::Position(Position::SetAutoaddMargin),
+        API::Position(Position::AddorReduceMargin),
+        API::Position(Position::ClosedPnl),
+        API::Position(Position::MovePosition),
+        API::Position(Position::MovePositionHistory),
+        API::Account(Account::Balance),
+        API::Account(Account::UpgradetoUTA),
+        API::Account(Account::BorrowHistory),
+        API::Account(Account::RepayLiability),
+        API::Account(Account::SetCollateral),
+        API::Account(Account::BatchSetCollateral),
+        API::Account(Account::CollateralInfo),
+        API::Account(Account::CoinGreeks),
+        API::Account(Account::FeeRate),
+        API::Account(Account::Information),
+        API::Account(Account::TransactionLog),
+        API::Account(Account::SetMarginMode),
+        API::Account(Account::SMPGroupID),
+        API::Account(Account::SetSpotHedging),
+        API::Asset(Asset::CoinExchangeRecord),
+        API::Asset(Asset::DeliveryRecord),
+        API::Asset(Asset::SettlementRecord),
+        API::Asset(Asset::Intertransfer),
+        API::Asset(Asset::QueryTransferList),
+        API::Asset(Asset::UniversalTransfer),
+        API::Asset(Asset::QueryUniversalTransferList),
+        API::Asset(Asset::QueryTransferCoinList),
+        API::Asset(Asset::QueryTransferSubmemberList),
+        API::Asset(Asset::QueryAccountCoinBalance),
+        API::Asset(Asset::QueryAssetInfo),
+        API::Asset(Asset::QueryAllowedList),
+        API::Asset(Asset::QueryRecord),
+        API::Asset(Asset::QueryInfo),
+        API::Asset(Asset::Withdraw),
+        API::Asset(Asset::CancelWithdraw),
+        API::Asset(Asset::QuerySubmemberAddress),
+        API::Asset(Asset::RequestQuote),
+        API::Asset(Asset::ConfirmQuote),
+        API::Asset(Asset::QueryCoinsList),
+        API::SpotLeverage(SpotLeverage::Info),
+        API::SpotLeverage(SpotLeverage::Marketinfo),
+        API::SpotLeverage(SpotLeverage::Purchase),
+        API::SpotLeverage(SpotLeverage::Redeem),
+        API::SpotLeverage(SpotLeverage::OrderRecord),
+        API::SpotMargin(SpotMargin::SwitchMode),
+        API::SpotMargin(SpotMargin::SetLeverage),
+        API::SpotMargin(SpotMargin::MarginCoinInfo),
+        API::SpotMargin(SpotMargin::State),
+        API::SpotMargin(SpotMargin::BorrowableCoin),
+        API::SpotMargin(SpotMargin::LoanInfo),
+        API::SpotMargin(SpotMargin::LoanAccountInfo),
+        API::SpotMargin(SpotMargin::Borrow),
+        API::SpotMargin(SpotMargin::Repay),
+        API::SpotMargin(SpotMargin::BorrowOrderDetail),
+        API::SpotMargin(SpotMargin::RepayOrderDetail),
+        API::SpotMargin(SpotMargin::ClassicMarginTogggle),
+    ]
+}
+
+/// Routes whose V5 `result` is an empty object.
+const EMPTY_RESULTS: [&str; 6] = [
+    "/v5/position/set-leverage",
+    "/v5/position/switch-isolated",
+    "/v5/position/switch-mode",
+    "/v5/position/trading-stop",
+    "/v5/position/set-auto-add-margin",
+    "/v5/account/set-collateral-switch",
+];
+
+#[tokio::test]
+async fn test_mock_canned_routes() {
+    let server = MockRestServer::start().await.unwrap();
+    let general: General = manager(&server);
+    for route in routes() {
+        let path = format!("/{}", route.as_ref().trim_start_matches('/'));
+        let response: Value = general.client.get_signed(route, 5000, None).await.unwrap();
+        let result = &response["result"];
+        if EMPTY_RESULTS.contains(&path.as_str()) {
+            assert_eq!(result, &json!({}), "{}", path);
+            continue;
+        }
+        assert!(
+            result.as_object().is_some_and(|result| !result.is_empty())
+                || result.as_array().is_some_and(|result| !result.is_empty()),
+            "no canned result for {}",
+            path
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_mock_general() {
+    let server = MockRestServer::start().await.unwrap();
+    let general: General = manager(&server);
+    general.ping().await.unwrap();
+    general.get_server_time().await.unwrap();
+    general.sample_clock().await.unwrap();
+    general.sync_clock().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mock_market() {
+    let server = MockRestServer::start().await.unwrap();
+    let market: MarketData = manager(&server);
+    let klines =
+        || KlineRequest::new(Some(Category::Linear), "BTCUSDT", "60", None, None, None);
+    market.get_klines(klines()).await.unwrap();
+    market.get_mark_price_klines(klines()).await.unwrap();
+    market.get_index_price_klines(klines()).await.unwrap();
+    market
+        .get_premium_index_price_klines(klines())
+        .await
+        .unwrap();
+    let instruments =
+        InstrumentRequest::new(Category::Linear, Some("BTCUSDT"), None, None, None);
+    market
+        .get_futures_instrument_info(instruments.clone())
+        .await
+        .unwrap();
+    let spot = InstrumentRequest::new(Category::Spot, Some("BTCUSDT"), None, None, None);
+    market.get_spot_instrument_info(spot).await.unwrap();
+    // `get_options_instrument_info` is still a `todo!()`.
+    let depth = OrderbookRequest::new("BTCUSDT", Category::Linear, Some(1));
+    market.get_depth(depth).await.unwrap();
+    let mut book = LocalOrderBook::new("BTCUSDT");
+    market
+        .resync_order_book(&mut book, Category::Linear, Some(50))
+        .await
+        .unwrap();
+    market.get_spot_tickers(Some("BTCUSDT")).await.unwrap();
+    market.get_futures_tickers(Some("BTCUSDT")).await.unwrap();
+    let funding =
+        FundingHistoryRequest::new(Category::Linear, "BTCUSDT", None, None, None);
+    market.get_funding_history(funding).await.unwrap();
+    let trades = RecentTradesRequest::new(Category::Linear, Some("BTCUSDT"), None, None);
+    market.get_recent_trades(trades).await.unwrap();
+    let interest =
+        OpenInterestRequest::new(Category::Linear, "BTCUSDT", "1h", None, None, None);
+    market.get_open_interest(interest).await.unwrap();
+    let volatility = HistoricalVolatilityRequest::new(Some("BTC"), None, None, None);
+    market.get_historical_volatility(volatility).await.unwrap();
+    market.get_insurance(None).await.unwrap();
+    let risk = RiskLimitRequest::new(Category::Linear, Some("BTCUSDT"));
+    market.get_risk_limit(risk).await.unwrap();
+    market
+        .get_delivery_price(Category::Option, None, Some("BTC"), None)
+        .await
+        .unwrap();
+    market
+        .get_longshort_ratio(Category::Linear, "BTCUSDT", "1h", None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_mock_trade() {
+    let server = MockRestServer::start().await.unwrap();
+    let trade: Trader = manager(&server);
+    let order = || OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.01"),
+        price: Some(amount("60000")),
+        ..OrderRequest::default()
+    };
+    trade.place_custom_order(order()).await.unwrap();
+    trade
+        .place_futures_limit_order(
+            Category::Linear,
+            "BTCUSDT",
+            Side::Buy,
+            amount("0.01"),
+            amount("60000"),
+            0,
+        )
+        .await
+        .unwrap();
+    let amend = || AmendOrderRequest {
+        order_id: Some(Cow::Borrowed("1321003749386327552")),
+        qty: amount("0.02"),
+        ..AmendOrderRequest::default()
+    };
+    trade.amend_order(amend()).await.unwrap();
+    let cancel = || CancelOrderRequest {
+        category: Category::Linear,
+        symbol: Cow::Borrowed("BTCUSDT"),
+        order_id: Some(Cow::Borrowed("1321003749386327552")),
+        order_link_id: None,
+        order_filter: None,
+    };
+    trade.cancel_order(cancel()).await.unwrap();
+    trade
+        .get_open_orders(OpenOrdersRequest::default())
+        .await
+        .unwrap();
+    let cancel_all =
+        CancelallRequest::new(Category::Linear, "BTCUSDT", None, None, None, None);
+    trade.cancel_all_orders(cancel_all).await.unwrap();
+    trade
+        .get_order_history(OrderHistoryRequest::default())
+        .await
+        .unwrap();
+    let executions = TradeHistoryRequest::new(
+        Category::Linear,
+        Some("BTCUSDT"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    trade.get_trade_history(executions).await.unwrap();
+    let batch = BatchPlaceRequest::new(Category::Linear, vec![order(), order()]);
+    trade.batch_place_order(batch).await.unwrap();
+    let batch = BatchAmendRequest::new(Category::Linear, vec![amend()]);
+    trade.batch_amend_order(batch).await.unwrap();
+    let batch = BatchCancelRequest::new(Category::Linear, vec![cancel()]);
+    trade.batch_cancel_order(batch).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mock_position() {
+    let server = MockRestServer::start().await.unwrap();
+    let position: PositionManager = manager(&server);
+    let info = PositionRequest::new(Category::Linear, Some("BTCUSDT"), None, None, None);
+    position.get_info(info).await.unwrap();
+    let leverage = LeverageRequest::new(Category::Linear, "BTCUSDT", 10);
+    position.set_leverage(leverage).await.unwrap();
+    let margin = ChangeMarginRequest::new(Category::Linear, "BTCUSDT", 1, 10);
+    position.set_margin_mode(margin).await.unwrap();
+    let mode = MarginModeRequest::new(Category::Linear, 3, Some("BTCUSDT"), None);
+    position.set_position_mode(mode).await.unwrap();
+    let risk = SetRiskLimit::new(Category::Linear, "BTCUSDT", 1, None);
+    position.set_risk_limit(risk).await.unwrap();
+    let stop = TradingStopRequest::new(
+        Category::Linear,
+        "BTCUSDT",
+        Some(amount("70000")),
+        Some(amount("50000")),
+        None,
+        None,
+        Some("Full"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        0,
+    );
+    position.set_trading_stop(stop).await.unwrap();
+    let auto_add = AddMarginRequest::new(Category::Linear, "BTCUSDT", true, None);
+    position.set_add_margin(auto_add).await.unwrap();
+    let add =
+        AddReduceMarginRequest::new(Category::Linear, "BTCUSDT", amount("10"), None);
+    position.add_or_reduce_margin(add).await.unwrap();
+    let pnl = ClosedPnlRequest::new(Category::Linear, Some("BTCUSDT"), None, None, None);
+    position.get_closed_pnl(pnl).await.unwrap();
+    let item = PositionItem::new(
+        Category::Linear,
+        "BTCUSDT",
+        amount("60000"),
+        Side::Buy,
+        amount("0.01"),
+    );
+    let moved = MovePositionRequest::new(1, 2, vec![item]);
+    position.move_position(moved).await.unwrap();
+    let history = MoveHistoryRequest::new(
+        Some(Category::Linear),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    position.move_position_history(history).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mock_account() {
+    let server = MockRestServer::start().await.unwrap();
+    let account: AccountManager = manager(&server);
+    account.get_wallet_balance("UNIFIED", None).await.unwrap();
+    account.upgrade_to_uta().await.unwrap();
+    let borrows = BorrowHistoryRequest::new(Some("USDT"), None, None, None);
+    account.get_borrow_history(borrows).await.unwrap();
+    account.repay_liability(Some("USDT")).await.unwrap();
+    account.set_collateral_coin("BTC", true).await.unwrap();
+    account
+        .batch_set_collateral(vec![("BTC", true), ("ETH", false)])
+        .await
+        .unwrap();
+    account.get_collateral_info(Some("BTC")).await.unwrap();
+    account
+        .get_fee_rate(Category::Linear, Some("BTCUSDT".to_string()))
+        .await
+        .unwrap();
+    account.get_account_info().await.unwrap();
+    let log = TransactionLogRequest::new(
+        Some(QuotaAccountType::UTA),
+        Some(Category::Linear),
+        Some("USDT"),
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    account.get_transaction_log(log).await.unwrap();
+    account.get_smp_id().await.unwrap();
+    account.set_margin_mode("REGULAR_MARGIN").await.unwrap();
+    account.set_spot_hedging(true).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mock_asset() {
+    let server = MockRestServer::start().await.unwrap();
+    let asset: AssetManager = manager(&server);
+    asset
+        .query_all_coins(AccountType::Funding, Some(&["USDT", "BTC"]))
+        .await
+        .unwrap();
+    asset
+        .apply_for_quota("USDT", "BTC", "USDT", amount("100"), QuotaAccountType::UTA)
+        .await
+        .unwrap();
+}
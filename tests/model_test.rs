@@ -0,0 +1,329 @@
+use bybit::{
+    api::{
+        Bybit,
+        Market,
+        Trade,
+        API,
+    },
+    errors::BybitError,
+    market::MarketData,
+    mock::{
+        MockResponse,
+        MockRestServer,
+    },
+    model::{
+        ApiResponse,
+        BatchPlaceResponse,
+        ConvertCommonResponse,
+        Empty,
+        KlineResponse,
+        MovePositionResponse,
+        OrderFilter,
+        OrderRequest,
+        OrderState,
+        OrderType,
+        Orders,
+        PositionIdx,
+        PositionInfo,
+        ServerTime,
+        ServerTimeResponse,
+        Side,
+        SmpType,
+        StopOrderType,
+        TimeInForce,
+        TpSlMode,
+        TradeHistory,
+        TriggerBy,
+        WebsocketEvents,
+    },
+    test_utils::amount,
+    trade::Trader,
+};
+use serde_json::json;
+use std::borrow::Cow;
+
+#[test]
+fn test_api_response() {
+    let response: ServerTimeResponse = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {"timeSecond": "1688639403", "timeNano": "1688639403423213947"},
+        "retExtInfo": {},
+        "time": 1688639403423u64,
+    }))
+    .unwrap();
+    assert!(response.is_success());
+    assert_eq!(response.time, 1688639403423);
+    let time: ServerTime = response.into_result().unwrap();
+    assert_eq!(time.time_second, 1688639403);
+
+    let failed = ApiResponse::<Empty> {
+        ret_code: 110007,
+        ret_msg: "ab not enough for new order".into(),
+        result: Empty {},
+        ret_ext_info: Empty {},
+        time: 0,
+    };
+    assert!(!failed.is_success());
+    match failed.into_result() {
+        Err(BybitError::BybitError(error)) => assert_eq!(error.code, 110007),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn test_api_response_ext_info() {
+    let response: BatchPlaceResponse = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {"list": [{
+            "category": "linear", "symbol": "BTCUSDT", "orderId": "1",
+            "orderLinkId": "a", "createAt": "1713434102752"
+        }]},
+        "retExtInfo": {"list": [{"code": 0, "msg": "OK"}, {"code": 110007, "msg": "ab not enough"}]},
+        "time": 1713434102753u64,
+    }))
+    .unwrap();
+    assert_eq!(response.result.list[0].order_id, "1");
+    assert_eq!(response.ret_ext_info.list[1].code, 110007);
+
+    // Endpoints that omit `retExtInfo` and `time`
+    let response: MovePositionResponse = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "Success",
+        "result": {"blockTradeId": "1", "status": "Processing", "rejectParty": ""},
+    }))
+    .unwrap();
+    assert_eq!(response.time, 0);
+    let response: ConvertCommonResponse<Vec<u32>> = serde_json::from_value(json!({
+        "retCode": 0, "retMsg": "ok", "result": [1, 2], "time": 1
+    }))
+    .unwrap();
+    assert_eq!(response.into_result().unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn test_string_numbers() {
+    let response: KlineResponse = serde_json::from_value(json!({
+        "retCode": 0, "retMsg": "OK", "retExtInfo": {}, "time": 1,
+        "result": {"symbol": "BTCUSDT", "category": "linear", "list": [
+            ["1670608800000", "17071", "17073", "17027", "17055.5", "268611", "15.74462667"]
+        ]},
+    }))
+    .unwrap();
+    let kline = &response.result.list[0];
+    assert_eq!(kline.start_time, 1670608800000);
+    assert_eq!(kline.close_price.to_string(), "17055.5");
+    assert_eq!(kline.quote_asset_volume.to_string(), "15.74462667");
+
+    let trade: TradeHistory = serde_json::from_value(json!({
+        "symbol": "BTCUSDT", "orderType": "Limit", "underlyingPrice": "",
+        "orderLinkId": "", "side": "Buy", "indexPrice": "", "orderId": "1",
+        "stopOrderType": "UNKNOWN", "leavesQty": "0", "execTime": "1669196423581",
+        "isMaker": false, "execFee": "0.07", "feeRate": "0.0006", "execId": "e-1",
+        "tradeIv": "", "blockTradeId": "", "markPrice": "1.0062", "execPrice": "1.0062",
+        "markIv": "", "orderQty": "100", "orderPrice": "1.0062", "execValue": "100.62",
+        "execType": "Trade", "execQty": "100", "closedSize": "", "seq": 1,
+    }))
+    .unwrap();
+    assert_eq!(trade.exec_time, 1669196423581);
+    assert_eq!(trade.order_type, OrderType::Limit);
+    assert_eq!(trade.side, Side::Buy);
+    assert_eq!(trade.exec_value.to_string(), "100.62");
+    assert!(trade.index_price.is_none() && trade.closed_size.is_none());
+    // `None` is written back as an empty string, or skipped
+    let value = serde_json::to_value(&trade).unwrap();
+    assert_eq!(value["execFee"], "0.07");
+    assert!(value.get("closedSize").is_none());
+
+    // An empty position has no liquidation or bankruptcy price
+    let position: PositionInfo = serde_json::from_value(json!({
+        "positionIdx": 0, "riskId": 1, "riskLimitValue": "2000000", "symbol": "BTCUSDT",
+        "side": "", "size": "0", "avgPrice": "0", "positionValue": "0", "tradeMode": 0,
+        "positionStatus": "Normal", "autoAddMargin": 0, "adlRankIndicator": 0,
+        "leverage": "10", "positionBalance": "0", "markPrice": "28184.5",
+        "liqPrice": "", "bustPrice": "", "positionMM": "0", "positionIM": "0",
+        "tpslMode": "Full", "takeProfit": "0", "stopLoss": "0", "trailingStop": "0",
+        "unrealisedPnl": "0", "cumRealisedPnl": "-25.06579337", "seq": 1,
+        "isReduceOnly": false, "mmrSysUpdateTime": "", "leverageSysUpdatedTime": "",
+        "createdTime": "1694402496913", "updatedTime": "1697682317038",
+    }))
+    .unwrap();
+    assert_eq!(position.mark_price, amount("28184.5"));
+    assert!(position.liq_price.is_none() && position.bust_price.is_none());
+    assert_eq!(position.take_profit, amount("0"));
+    assert_eq!(position.position_idx, PositionIdx::OneWay);
+    assert_eq!(position.tpsl_mode, Some(TpSlMode::Full));
+    assert_eq!(position.updated_time, 1697682317038);
+}
+
+#[test]
+fn test_string_numbers_ws() {
+    let position = json!({
+        "positionIdx": 0, "tradeMode": 0, "riskId": 1, "riskLimitValue": "2000000",
+        "symbol": "BTCUSDT", "side": "", "size": "0", "entryPrice": "0",
+        "leverage": "10", "positionValue": "0", "positionBalance": "0",
+        "markPrice": "28184.5", "positionIM": "0", "positionMM": "0",
+        "takeProfit": "0", "stopLoss": "0", "trailingStop": "0",
+        "unrealisedPnl": "0", "cumRealisedPnl": "-25.06579337",
+        "createdTime": "1694402496913", "updatedTime": "1697682317038",
+        "tpslMode": "Full", "liqPrice": "", "bustPrice": "", "category": "linear",
+        "positionStatus": "Normal", "adlRankIndicator": 0, "autoAddMargin": 0,
+        "leverageSysUpdatedTime": "", "mmrSysUpdatedTime": "", "seq": 8172241025u64,
+        "isReduceOnly": false,
+    });
+    let frame = json!({
+        "id": "1", "topic": "position", "creationTime": 1697682317044u64,
+        "data": [position],
+    });
+    match WebsocketEvents::from_frame(&frame.to_string()) {
+        WebsocketEvents::PositionEvent(event) => {
+            let position = &event.data[0];
+            assert_eq!(position.mark_price.to_string(), "28184.5");
+            assert_eq!(position.updated_time, 1697682317038);
+            assert_eq!(
+                position.leverage.map(|v| v.to_string()).as_deref(),
+                Some("10")
+            );
+            assert!(position.liq_price.is_none());
+            assert!(position.leverage_sys_updated_time.is_none());
+            assert_eq!(position.tpsl_mode, Some(TpSlMode::Full));
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+
+    // The error names the field that failed to parse
+    let frame = json!({
+        "id": "1", "topic": "wallet", "creationTime": 1, "data": [{
+            "accountIMRate": "", "accountMMRate": "", "totalEquity": "3.31",
+            "totalWalletBalance": "n/a", "totalMarginBalance": "3.00",
+            "totalAvailableBalance": "2.99", "totalPerpUPL": "0",
+            "totalInitialMargin": "0", "totalMaintenanceMargin": "0",
+            "coin": [], "accountLTV": "",
+        }],
+    });
+    match WebsocketEvents::from_frame(&frame.to_string()) {
+        WebsocketEvents::ParseError(e) => {
+            assert!(
+                e.error.starts_with("data[0].totalWalletBalance"),
+                "{}",
+                e.error
+            );
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+}
+
+#[tokio::test]
+async fn test_deserialize_error_path() {
+    let server = MockRestServer::start().await.unwrap();
+    let market: MarketData = Bybit::new_with_config(&server.config(), None, None);
+    let tickers = market.get_futures_tickers(Some("BTCUSDT")).await.unwrap();
+    let ticker = &tickers.result.list[0];
+    assert_eq!(
+        ticker.funding_rate.map(|v| v.to_string()).as_deref(),
+        Some("0.0001")
+    );
+    assert!(ticker.basis.is_none() && ticker.delivery_fee_rate.is_none());
+
+    server.respond_once(
+        API::Market(Market::Tickers),
+        MockResponse::ok(json!({
+            "category": "linear",
+            "list": [{"symbol": "BTCUSDT", "lastPrice": "65485.48", "indexPrice": "-"}],
+        })),
+    );
+    match market.get_futures_tickers(Some("BTCUSDT")).await {
+        Err(BybitError::Deserialize { path, body, .. }) => {
+            assert_eq!(path, "result.list[0].indexPrice");
+            assert!(body.contains("65485.48"));
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn test_enums() {
+    let status: OrderState = serde_json::from_value(json!("PartiallyFilled")).unwrap();
+    assert_eq!(status, OrderState::PartiallyFilled);
+    assert_eq!(
+        serde_json::from_value::<StopOrderType>(json!("tpslOrder")).unwrap(),
+        StopOrderType::TpslOrder
+    );
+    assert_eq!(OrderFilter::TpslOrder.as_str(), "tpslOrder");
+    assert_eq!(TriggerBy::MarkPrice.to_string(), "MarkPrice");
+
+    // Values added by Bybit later are kept and sent back as they came
+    let status: OrderState = serde_json::from_value(json!("Frozen")).unwrap();
+    assert_eq!(status, OrderState::Unknown("Frozen".into()));
+    assert_eq!(serde_json::to_value(&status).unwrap(), json!("Frozen"));
+    let side: Side = serde_json::from_value(json!("None")).unwrap();
+    assert_eq!(side, Side::Unknown("None".into()));
+    assert_eq!(side.as_str(), "None");
+
+    let idx: PositionIdx = serde_json::from_value(json!(2)).unwrap();
+    assert_eq!(idx, PositionIdx::SellHedge);
+    assert_eq!(serde_json::to_value(&idx).unwrap(), json!(2));
+    assert_eq!(PositionIdx::from(1), PositionIdx::BuyHedge);
+    let idx: PositionIdx = serde_json::from_value(json!(7)).unwrap();
+    assert_eq!(idx, PositionIdx::Unknown("7".into()));
+    assert_eq!(serde_json::to_value(&idx).unwrap(), json!(7));
+}
+
+#[test]
+fn test_enums_in_responses() {
+    let order: Orders = serde_json::from_value(json!({
+        "orderId": "1", "orderLinkId": "", "blockTradeId": "", "symbol": "BTCUSDT",
+        "price": "60000", "qty": "0.01", "side": "Buy", "isLeverage": "",
+        "positionIdx": 1, "orderStatus": "Untriggered", "cancelType": "UNKNOWN",
+        "rejectReason": "EC_NoError", "avgPrice": "0", "leavesQty": "0.01",
+        "leavesValue": "600", "cumExecQty": "0", "cumExecValue": "0", "cumExecFee": "0",
+        "timeInForce": "GTC", "orderType": "Limit", "stopOrderType": "",
+        "orderIv": "", "triggerPrice": "59000", "takeProfit": "0", "stopLoss": "0",
+        "tpTriggerBy": "", "slTriggerBy": "", "triggerDirection": 2,
+        "triggerBy": "MarkPrice", "lastPriceOnCreated": "60010", "reduceOnly": false,
+        "closeOnTrigger": false, "smpType": "None", "smpGroup": 0, "smpOrderId": "",
+        "tpslMode": "", "tpLimitPrice": "0", "slLimitPrice": "0", "placeType": "",
+        "createdTime": "1684738540559", "updatedTime": "1684738540561",
+    }))
+    .unwrap();
+    assert_eq!(order.order_status, OrderState::Untriggered);
+    assert_eq!(order.position_idx, PositionIdx::BuyHedge);
+    assert_eq!(order.time_in_force, TimeInForce::GTC);
+    assert_eq!(order.trigger_by, Some(TriggerBy::MarkPrice));
+    assert_eq!(order.smp_type, SmpType::None);
+    assert!(order.stop_order_type.is_none() && order.tp_trigger_by.is_none());
+    assert!(order.tpsl_mode.is_none());
+}
+
+#[tokio::test]
+async fn test_enums_in_requests() {
+    let server = MockRestServer::start().await.unwrap();
+    let trade: Trader = Bybit::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    );
+    let order = OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.01"),
+        time_in_force: Some(TimeInForce::PostOnly),
+        position_idx: Some(PositionIdx::SellHedge),
+        tp_trigger_by: Some(TriggerBy::IndexPrice),
+        tpsl_mode: Some(TpSlMode::Partial),
+        smp_type: Some(SmpType::CancelBoth),
+        ..OrderRequest::default()
+    };
+    trade.place_custom_order(order).await.unwrap();
+    assert_eq!(server.hits(API::Trade(Trade::Place)), 1);
+    let body = server.requests().pop().unwrap().body;
+    for field in [
+        r#""timeInForce":"PostOnly""#,
+        r#""positionIdx":"2""#,
+        r#""tpTriggerBy":"IndexPrice""#,
+        r#""tpslMode":"Partial""#,
+        r#""smpType":"CancelBoth""#,
+    ] {
+        assert!(body.contains(field), "{field} missing from {body}");
+    }
+}
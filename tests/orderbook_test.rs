@@ -0,0 +1,87 @@
+use bybit::{
+    enable_tracing,
+    errors::BybitError,
+    model::{
+        Amount,
+        LocalOrderBook,
+        OrderBookUpdate,
+        Side,
+    },
+    test_utils::amount,
+};
+
+enable_tracing!();
+
+fn update(kind: &str, u: u64, bids: &str, asks: &str) -> OrderBookUpdate {
+    let msg = format!(
+        r#"{{"topic":"orderbook.50.BTCUSDT","type":"{kind}","ts":{u},"data":{{"s":"BTCUSDT","b":{bids},"a":{asks},"u":{u},"seq":{}}},"cts":{u}}}"#,
+        u * 10
+    );
+    serde_json::from_str(&msg).unwrap()
+}
+
+#[test]
+fn test_local_order_book() {
+    let mut book = LocalOrderBook::new("BTCUSDT");
+    book.apply_update(&update(
+        "snapshot",
+        100,
+        r#"[["100.5","1"],["100.0","2"]]"#,
+        r#"[["101.0","3"],["101.5","4"]]"#,
+    ))
+    .unwrap();
+    assert_eq!(book.best_bid().unwrap().price, amount("100.5"));
+    assert_eq!(book.best_ask().unwrap().price, amount("101.0"));
+    assert_eq!(book.spread(), Some(amount("0.5")));
+    assert_eq!(book.mid_price(), Some(amount("100.75")));
+
+    // Remove the best bid and add a new ask level.
+    book.apply_update(&update(
+        "delta",
+        101,
+        r#"[["100.5","0"]]"#,
+        r#"[["100.8","5"]]"#,
+    ))
+    .unwrap();
+    assert_eq!(book.best_bid().unwrap().price, amount("100.0"));
+    assert_eq!(book.depth_at(Side::Sell, amount("100.8")), amount("5"));
+    assert_eq!(book.depth_at(Side::Buy, amount("100.5")), amount("0"));
+    let asks: Vec<Amount> = book.top_asks(2).iter().map(|a| a.price).collect();
+    assert_eq!(asks, vec![amount("100.8"), amount("101.0")]);
+    assert_eq!(book.update_id(), 101);
+}
+
+#[test]
+fn test_local_order_book_gap() {
+    let mut book = LocalOrderBook::new("BTCUSDT");
+    book.apply_update(&update(
+        "snapshot",
+        100,
+        r#"[["100","1"]]"#,
+        r#"[["101","1"]]"#,
+    ))
+    .unwrap();
+    let gap = book.apply_update(&update("delta", 102, "[]", r#"[["101","2"]]"#));
+    assert!(matches!(
+        gap,
+        Err(BybitError::OrderBookSequenceGap {
+            expected: 101,
+            received: 102,
+            ..
+        })
+    ));
+    assert!(!book.is_synced());
+    // Deltas stay rejected until a snapshot arrives.
+    assert!(book
+        .apply_update(&update("delta", 103, "[]", "[]"))
+        .is_err());
+    book.apply_update(&update(
+        "snapshot",
+        104,
+        r#"[["99","1"]]"#,
+        r#"[["102","1"]]"#,
+    ))
+    .unwrap();
+    assert!(book.is_synced());
+    assert_eq!(book.top_bids(5).len(), 1);
+}
@@ -1,6 +1,7 @@
 use bybit::{
     api::*,
     enable_tracing,
+    mock::MockRestServer,
     model::{
         Category,
         LeverageRequest,
@@ -36,3 +37,17 @@ async fn set_leverage() {
         Err(e) => tracing::error!("{:?}", e),
     }
 }
+
+#[test]
+async fn test_mock_set_leverage() {
+    let server = MockRestServer::start().await.unwrap();
+    let position: PositionManager = Bybit::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    );
+    let request = LeverageRequest::new(Category::Linear, "BTCUSDT", 5);
+    let response = position.set_leverage(request).await.unwrap();
+    assert_eq!(response.ret_code, 0);
+    assert!(server.requests()[0].body.contains("\"buyLeverage\":\"5\""));
+}
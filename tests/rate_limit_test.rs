@@ -0,0 +1,92 @@
+use bybit::{
+    api::{
+        Bybit,
+        BybitClient,
+        Market,
+        Trade,
+        API,
+    },
+    general::General,
+    mock::{
+        MockRestServer,
+        MockWsServer,
+    },
+    model::{
+        Category,
+        OrderRequest,
+    },
+    rate_limit::LimitKey,
+    test_utils::amount,
+};
+use futures::future::join_all;
+use std::borrow::Cow;
+use tokio::time::{
+    Duration,
+    Instant,
+};
+
+#[tokio::test]
+async fn test_waits_for_window_reset() {
+    let server = MockRestServer::start().await.unwrap();
+    server.set_rate_limit(API::Market(Market::Time), 2, Duration::from_millis(300));
+    let general: General = Bybit::new_with_config(&server.config(), None, None);
+
+    let start = Instant::now();
+    for _ in 0..7 {
+        general.get_server_time().await.unwrap();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(900));
+    let status = general
+        .client
+        .rate_limiter
+        .status(API::Market(Market::Time), None)
+        .unwrap();
+    assert_eq!(status.limit, 2);
+    assert!(status.remaining <= 1);
+
+    let concurrent = join_all((0..6).map(|_| general.get_server_time())).await;
+    assert!(concurrent.iter().all(Result::is_ok));
+    assert_eq!(server.hits(API::Market(Market::Time)), 13);
+}
+
+#[tokio::test]
+async fn test_shared_budget() {
+    let rest = MockRestServer::start().await.unwrap();
+    let ws = MockWsServer::start().await.unwrap();
+    let config = rest.config().set_ws_endpoint(ws.endpoint().to_string());
+    let bybit = BybitClient::new_with_config(&config, None, None);
+    let limiter = bybit.client.rate_limiter.clone();
+    let place = || API::Trade(Trade::Place);
+    assert!(limiter.status(place(), Some(Category::Linear)).is_none());
+
+    let trader = bybit.ws().ws_trader().await.unwrap();
+    let order = OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.01"),
+        ..OrderRequest::default()
+    };
+    trader.place_order(order).await.unwrap();
+    assert_eq!(limiter.remaining(place(), Some(Category::Linear)), Some(9));
+    // Order quotas are counted per category.
+    assert!(limiter.status(place(), Some(Category::Spot)).is_none());
+    assert!(bybit
+        .trade()
+        .client
+        .rate_limiter
+        .statuses()
+        .contains_key(&LimitKey::new(place(), Some(Category::Linear))));
+}
+
+#[test]
+fn test_limit_groups() {
+    let time = LimitKey::new(API::Market(Market::Time), Some(Category::Spot));
+    assert_eq!(time, LimitKey::new(API::Market(Market::Time), None));
+    assert_eq!(time.group, "/v5/market/time");
+
+    let place = LimitKey::new(API::Trade(Trade::Place), Some(Category::Spot));
+    assert_eq!(place.group, "order.create");
+    assert_ne!(
+        place,
+        LimitKey::new(API::Trade(Trade::Place), Some(Category::Linear))
+    );
+}
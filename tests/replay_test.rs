@@ -0,0 +1,169 @@
+use bybit::{
+    errors::BybitError,
+    model::{
+        Frame,
+        WebsocketEvents,
+    },
+    replay::{
+        FrameRecorder,
+        FrameReplay,
+        RecordedFrame,
+    },
+    test_utils::amount,
+    ws::WebSocketHandler,
+};
+use serde_json::json;
+use std::sync::mpsc;
+use tokio::time::{
+    Duration,
+    Instant,
+};
+
+fn trade_frame(price: &str) -> String {
+    json!({
+        "topic": "publicTrade.BTCUSDT",
+        "type": "snapshot",
+        "ts": 1,
+        "data": [{
+            "T": 1, "s": "BTCUSDT", "S": "Buy", "v": "0.1", "p": price,
+            "L": "PlusTick", "i": "1", "BT": false
+        }]
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_record_frames() {
+    let (tx, rx) = mpsc::channel();
+    let handler = move |event: WebsocketEvents| {
+        tx.send(event).unwrap();
+        Ok::<(), BybitError>(())
+    };
+    let mut recorder = FrameRecorder::new(Vec::new(), handler);
+    let ack = json!({"success": true, "op": "subscribe", "req_id": "1"}).to_string();
+    recorder
+        .handle_control(WebsocketEvents::Subscribed(vec![]), &ack)
+        .unwrap();
+    recorder.handle_msg(&trade_frame("60000")).unwrap();
+
+    let (_, written) = recorder.finish().await.unwrap();
+    let replay = FrameReplay::from_reader(written.as_slice()).unwrap();
+    let frames: Vec<&str> = replay.frames.iter().map(|f| f.frame.as_str()).collect();
+    assert_eq!(frames, vec![ack.as_str(), trade_frame("60000").as_str()]);
+    assert!(matches!(rx.try_recv(), Ok(WebsocketEvents::Subscribed(_))));
+    assert!(matches!(rx.try_recv(), Ok(WebsocketEvents::TradeEvent(_))));
+}
+
+struct FullDisk;
+
+impl std::io::Write for FullDisk {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_record_write_error() {
+    let handler = |_: WebsocketEvents| Ok::<(), BybitError>(());
+    let mut recorder = FrameRecorder::new(FullDisk, handler);
+    recorder.handle_msg(&trade_frame("60000")).unwrap();
+    let error = recorder.finish().await.err().unwrap();
+    assert!(error.to_string().contains("disk full"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_replay_timing() {
+    let frames = vec![
+        RecordedFrame {
+            ts: 1_000,
+            frame: trade_frame("60000"),
+        },
+        RecordedFrame {
+            ts: 3_000,
+            frame: json!({"success": true, "op": "subscribe", "req_id": "1"}).to_string(),
+        },
+        RecordedFrame {
+            ts: 5_000,
+            frame: trade_frame("60100"),
+        },
+    ];
+    let start = Instant::now();
+    let (tx, rx) = mpsc::channel();
+    let handler = move |event: WebsocketEvents| {
+        tx.send((start.elapsed(), event)).unwrap();
+        Ok::<(), BybitError>(())
+    };
+    FrameReplay::new(frames.clone())
+        .set_speed(2.0)
+        .run(handler)
+        .await
+        .unwrap();
+
+    let events: Vec<_> = rx.try_iter().collect();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0].0, Duration::ZERO);
+    assert_eq!(events[1].0, Duration::from_secs(1));
+    assert_eq!(events[2].0, Duration::from_secs(2));
+    match &events[2].1 {
+        WebsocketEvents::TradeEvent(trade) => {
+            assert_eq!(trade.data[0].price, amount("60100"))
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    assert!(matches!(events[1].1, WebsocketEvents::Subscribed(_)));
+
+    let start = Instant::now();
+    FrameReplay::new(frames)
+        .set_speed(0.0)
+        .run(|_: WebsocketEvents| Ok::<(), BybitError>(()))
+        .await
+        .unwrap();
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+/// Reports the raw frames reaching `handle_msg` and the topics of the parsed frames
+/// reaching `handle_frame`.
+struct Routes(mpsc::Sender<Result<String, Option<String>>>);
+
+impl WebSocketHandler for Routes {
+    type Event = ();
+    fn handle_msg(&mut self, msg: &str) -> Result<(), BybitError> {
+        self.0.send(Ok(msg.to_string())).unwrap();
+        Ok(())
+    }
+
+    fn handle_frame(&mut self, frame: &Frame<'_>) -> Result<(), BybitError> {
+        self.0.send(Err(frame.topic().map(String::from))).unwrap();
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_replay_parsed_frames() {
+    let ack = json!({"success": true, "op": "subscribe", "req_id": "1"}).to_string();
+    let frames = [ack.clone(), trade_frame("60000"), "not json".to_string()]
+        .into_iter()
+        .map(|frame| RecordedFrame { ts: 0, frame })
+        .collect();
+    let (tx, rx) = mpsc::channel();
+    FrameReplay::new(frames)
+        .set_speed(0.0)
+        .run(Routes(tx))
+        .await
+        .unwrap();
+    // Acks go through `handle_control`, which forwards the raw frame by default,
+    // and frames that are not JSON go straight to `handle_msg`
+    let routes: Vec<_> = rx.try_iter().collect();
+    assert_eq!(
+        routes,
+        vec![
+            Ok(ack),
+            Err(Some("publicTrade.BTCUSDT".to_string())),
+            Ok("not json".to_string()),
+        ]
+    );
+}
@@ -1,13 +1,17 @@
 use bybit::{
     api::*,
     enable_tracing,
+    mock::MockRestServer,
     model::*,
     test_utils::{
+        amount,
         api_key,
         secret,
     },
     trade::*,
 };
+use serde_json::Value;
+use std::borrow::Cow;
 use tokio;
 
 enable_tracing!();
@@ -20,8 +24,8 @@ async fn test_trade() {
             Category::Linear,
             "MATICUSDT",
             Side::Buy,
-            100.0,
-            0.7500,
+            amount("100"),
+            amount("0.7500"),
             0,
         )
         .await;
@@ -75,14 +79,14 @@ async fn test_batch() {
         OrderRequest {
             symbol: "MATICUSDT".into(),
             side: Side::Buy,
-            qty: 100.0,
+            qty: amount("100"),
             order_type: OrderType::Market,
             ..Default::default()
         },
         OrderRequest {
             symbol: "BTCUSDT".into(),
             side: Side::Buy,
-            qty: 100.0,
+            qty: amount("100"),
             order_type: OrderType::Market,
             ..Default::default()
         },
@@ -109,3 +113,27 @@ async fn test_open_closed_orders() {
     let result = trade.get_open_orders(request).await.unwrap();
     tracing::info!("{:#?}", result);
 }
+
+#[tokio::test]
+async fn test_mock_place_order() {
+    let server = MockRestServer::start().await.unwrap();
+    let trade: Trader = Bybit::new_with_config(
+        &server.config(),
+        Some(server.api_key().to_string()),
+        Some(server.secret().to_string()),
+    );
+    let order = OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.01"),
+        order_link_id: Some(Cow::Borrowed("mock-order")),
+        ..OrderRequest::default()
+    };
+    let placed = trade.place_custom_order(order).await.unwrap();
+    assert_eq!(placed.result.order_link_id, "mock-order");
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    let body: Value = serde_json::from_str(&request.body).unwrap();
+    assert_eq!(body["symbol"], "BTCUSDT");
+    assert!(request.headers.contains_key("x-bapi-sign"));
+}
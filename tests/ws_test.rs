@@ -1,23 +1,47 @@
 use bybit::api::*;
+use futures::StreamExt;
 use tokio;
 
 use bybit::{
+    channel::{
+        bounded_channel,
+        OverflowPolicy,
+    },
     enable_tracing,
+    errors::BybitError,
+    mock::MockWsServer,
     model::{
+        CancelOrderRequest,
         Category,
+        OrderRequest,
         Subscription,
+        TickerCache,
         Tickers,
         WebsocketEvents,
     },
     test_utils::{
+        amount,
         api_key,
         secret,
     },
-    ws::Stream,
+    ws::{
+        HeartbeatPolicy,
+        ReconnectPolicy,
+        ShardPolicy,
+        Stream,
+    },
 };
+use serde_json::{
+    json,
+    Value,
+};
+use std::borrow::Cow;
 use tokio::{
     sync::mpsc,
-    time::Instant,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 enable_tracing!();
@@ -158,3 +182,551 @@ async fn test_default_klines() {
         tracing::info!("{:#?}", data);
     }
 }
+
+#[test]
+fn test_reconnect_backoff() {
+    let policy = ReconnectPolicy::default()
+        .set_initial_delay(Duration::from_millis(100))
+        .set_max_delay(Duration::from_secs(1))
+        .set_max_attempts(Some(3));
+    assert_eq!(policy.delay(1), Duration::from_millis(100));
+    assert_eq!(policy.delay(2), Duration::from_millis(200));
+    assert_eq!(policy.delay(4), Duration::from_millis(800));
+    assert_eq!(policy.delay(5), Duration::from_secs(1));
+    assert_eq!(policy.delay(u32::MAX), Duration::from_secs(1));
+    assert!(!policy.exhausted(3));
+    assert!(policy.exhausted(4));
+}
+
+#[tokio::test]
+async fn test_reconnecting_subscribe() {
+    let ws: Stream = Stream::new(api_key(), secret())
+        .with_reconnect(ReconnectPolicy::default().set_max_attempts(Some(5)));
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let request = Subscription::new("subscribe", vec!["publicTrade.BTCUSDT"]);
+        let _ = ws
+            .ws_subscribe(request, Category::Linear, move |event| {
+                tx.send(event).map_err(|e| e.to_string().into())
+            })
+            .await;
+    });
+    while let Some(event) = rx.recv().await {
+        if let WebsocketEvents::Reconnected(event) = event {
+            tracing::info!("{:#?}", event);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_event_stream() {
+    let ws: Stream = Bybit::new(api_key(), secret());
+    let request = Subscription::new("subscribe", vec!["publicTrade.BTCUSDT"]);
+    let mut events = ws.subscribe(request, Category::Linear).take(5);
+    while let Some(event) = events.next().await {
+        match event {
+            Ok(event) => tracing::info!("{:#?}", event),
+            Err(e) => tracing::error!("{:#?}", e),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_ws_trader_correlation() {
+    let server = MockWsServer::start().await.unwrap();
+    // The first order is answered after the cancel, and the second one never.
+    server.delay("order.create", Duration::from_millis(100));
+    server.mute("order.create", 1);
+    let ws: Stream = Bybit::new_with_config(&server.config(), api_key(), secret());
+    let trader = ws
+        .ws_trader()
+        .await
+        .unwrap()
+        .set_timeout(Duration::from_millis(500));
+    let order = OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.01"),
+        order_link_id: Some(Cow::Borrowed("link-1")),
+        ..OrderRequest::default()
+    };
+    let cancel = CancelOrderRequest {
+        category: Category::Linear,
+        symbol: Cow::Borrowed("BTCUSDT"),
+        order_id: Some(Cow::Borrowed("2")),
+        order_link_id: None,
+        order_filter: None,
+    };
+    let (placed, cancelled, unanswered) = tokio::join!(
+        trader.place_order(order.clone()),
+        async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            server
+                .reject_orders(Some((110001, "order not exists or too late to cancel")));
+            trader.cancel_order(cancel).await
+        },
+        async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            trader.place_order(order).await
+        },
+    );
+    assert_eq!(placed.unwrap().order_link_id, "link-1");
+    assert!(matches!(
+        cancelled,
+        Err(BybitError::TradeRequestRejected { code: 110001, .. })
+    ));
+    assert!(matches!(unanswered, Err(BybitError::RequestTimeout { .. })));
+}
+
+#[tokio::test]
+async fn test_heartbeat_detects_dead_connection() {
+    let server = MockWsServer::start().await.unwrap();
+    // Answer the first ping only, then keep the socket silent.
+    server.mute("ping", 1);
+    let ws: Stream = Stream::new_with_config(&server.config(), api_key(), secret())
+        .with_heartbeat(
+            HeartbeatPolicy::default()
+                .set_interval(Duration::from_millis(50))
+                .set_max_missed(2),
+        );
+    let request = Subscription::new("subscribe", vec!["publicTrade.BTCUSDT"]);
+    let mut events = ws.subscribe(request, Category::Linear);
+    let error = loop {
+        match events.next().await {
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => break e,
+            None => panic!("stream ended without an error"),
+        }
+    };
+    assert!(matches!(error, BybitError::HeartbeatTimeout { missed: 2 }));
+    let latency = events.latency();
+    assert_eq!(latency.len(), 1);
+    assert_eq!(latency[0].samples, 1);
+    assert_eq!(latency[0].missed_pongs, 2);
+    assert!(latency[0].last.is_some());
+
+    // Every connection measures its own round trips.
+    let mut quiet = ws.subscribe(
+        Subscription::new("subscribe", vec!["publicTrade.ETHUSDT"]),
+        Category::Linear,
+    );
+    assert!(matches!(
+        quiet.next().await,
+        Some(Ok(WebsocketEvents::Subscribed(_)))
+    ));
+    assert_eq!(quiet.latency()[0].samples, 0);
+}
+
+#[tokio::test]
+async fn test_blocked_consumer_keeps_pinging() {
+    let server = MockWsServer::start().await.unwrap();
+    let ws: Stream = Stream::new_with_config(&server.config(), api_key(), secret())
+        .with_heartbeat(
+            HeartbeatPolicy::default()
+                .set_interval(Duration::from_millis(50))
+                .set_max_missed(2),
+        );
+    let (tx, mut rx) = bounded_channel(1, OverflowPolicy::Block);
+    tokio::spawn(
+        async move { ws.ws_trades(vec!["BTCUSDT"], Category::Linear, tx).await },
+    );
+    let trades = |id: u32| {
+        json!([{
+            "T": 1, "s": "BTCUSDT", "S": "Buy", "v": "0.5", "p": "60000.5",
+            "L": "PlusTick", "i": id.to_string(), "BT": false
+        }])
+    };
+    while server.push("publicTrade.BTCUSDT", "snapshot", trades(0)) == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    for id in 1..4 {
+        server.push("publicTrade.BTCUSDT", "snapshot", trades(id));
+    }
+    let pings = || {
+        server
+            .requests()
+            .iter()
+            .filter(|request| request["op"] == "ping")
+            .count()
+    };
+
+    // Nothing is consumed for several ping intervals: the reader is paused, but
+    // pings keep the connection alive and their unread pongs are not counted missed.
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    let before = pings();
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    assert!(pings() >= before + 3);
+    assert_eq!(server.connections(), 1);
+    for id in 0..4 {
+        assert_eq!(rx.recv().await.unwrap().id, id.to_string());
+    }
+    assert_eq!(server.connections(), 1);
+}
+
+#[test]
+fn test_option_events() {
+    let ticker = r#"{"id":"tickers.BTC-6JAN23-17500-C-2480334983-1672917511074","topic":"tickers.BTC-6JAN23-17500-C","ts":1672917511074,"data":{"symbol":"BTC-6JAN23-17500-C","bidPrice":"0","bidSize":"0","bidIv":"0","askPrice":"10","askSize":"5.1","askIv":"0.514","lastPrice":"10","highPrice24h":"25","lowPrice24h":"5","markPrice":"7.86976724","indexPrice":"16823.73","markPriceIv":"0.4896","underlyingPrice":"16815.1","openInterest":"49.85","turnover24h":"446802.8473","volume24h":"26.55","totalVolume":"86","totalTurnover":"1437431","delta":"0.047831","gamma":"0.00021453","vega":"0.81351067","theta":"-19.9115368","predictedDeliveryPrice":"0","change24h":"-0.33333334"},"type":"snapshot"}"#;
+    match serde_json::from_str(ticker).unwrap() {
+        WebsocketEvents::OptionTickerEvent(ticker) => {
+            assert_eq!(ticker.data.underlying_price, amount("16815.1"));
+            assert_eq!(ticker.data.mark_price_iv, amount("0.4896"));
+            assert_eq!(ticker.data.theta, amount("-19.9115368"));
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+
+    let trade = r#"{"id":"publicTrade.BTC-3450380806-1672917511074","topic":"publicTrade.BTC","ts":1672917511074,"data":[{"T":1672917511074,"s":"BTC-6JAN23-17500-C","S":"Buy","v":"0.1","p":"10","i":"e66c9b8a-5d5c-5e4c-a5a5-0e7fa1b4d1f7","BT":false,"mP":"7.86","iP":"16823.73","mIv":"0.4896","iv":"0.514"}],"type":"snapshot"}"#;
+    match serde_json::from_str(trade).unwrap() {
+        WebsocketEvents::OptionTradeEvent(trades) => {
+            assert_eq!(trades.data[0].iv, amount("0.514"));
+            assert_eq!(trades.data[0].index_price, amount("16823.73"));
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+}
+
+#[tokio::test]
+async fn test_runtime_subscriptions() {
+    let server = MockWsServer::start().await.unwrap();
+    server.reject_topic("orderbook.1.BADUSDT");
+    let ws: Stream = Bybit::new_with_config(&server.config(), api_key(), secret());
+    let (handle, mut events) = ws.connect(Category::Spot).await.unwrap();
+    handle
+        .subscribe(&["publicTrade.BTCUSDT", "orderbook.1.BTCUSDT"])
+        .await
+        .unwrap();
+    assert_eq!(
+        handle.topics(),
+        vec!["orderbook.1.BTCUSDT", "publicTrade.BTCUSDT"]
+    );
+    let trades = json!([{"T": 1672304486865u64, "s": "BTCUSDT", "S": "Buy", "v": "0.001",
+        "p": "16578.50", "L": "PlusTick", "i": "1", "BT": false}]);
+    assert_eq!(server.push("publicTrade.BTCUSDT", "snapshot", trades), 1);
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(WebsocketEvents::TradeEvent(_)))
+    ));
+
+    assert!(matches!(
+        handle.subscribe(&["publicTrade.BTCUSDT"]).await,
+        Err(BybitError::DuplicateSubscription(_))
+    ));
+    assert!(matches!(
+        handle.unsubscribe(&["publicTrade.ETHUSDT"]).await,
+        Err(BybitError::NotSubscribed(_))
+    ));
+    let too_many: Vec<String> =
+        (0..11).map(|i| format!("tickers.COIN{}USDT", i)).collect();
+    let too_many: Vec<&str> = too_many.iter().map(String::as_str).collect();
+    assert!(matches!(
+        handle.subscribe(&too_many).await,
+        Err(BybitError::SubscriptionLimitExceeded(_))
+    ));
+    assert!(matches!(
+        handle.subscribe(&["orderbook.1.BADUSDT"]).await,
+        Err(BybitError::SubscriptionRejected(_))
+    ));
+
+    handle.unsubscribe(&["publicTrade.BTCUSDT"]).await.unwrap();
+    assert_eq!(handle.topics(), vec!["orderbook.1.BTCUSDT"]);
+    assert_eq!(server.subscribers("publicTrade.BTCUSDT"), 0);
+}
+
+#[test]
+fn test_frame_dispatch() {
+    let trade = r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304486868,"data":[{"T":1672304486865,"s":"BTCUSDT","S":"Buy","v":"0.001","p":"16578.50","L":"PlusTick","i":"1","BT":false}]}"#;
+    assert!(matches!(
+        WebsocketEvents::from_frame(trade),
+        WebsocketEvents::TradeEvent(_)
+    ));
+
+    let pong =
+        r#"{"success":true,"ret_msg":"pong","conn_id":"1","req_id":"1","op":"ping"}"#;
+    assert!(matches!(
+        WebsocketEvents::from_frame(pong),
+        WebsocketEvents::Unknown(frame) if frame == pong
+    ));
+
+    // The topic routes the frame to an order book, which requires a `data` field.
+    let broken = r#"{"topic":"orderbook.50.BTCUSDT","type":"delta","ts":1,"cts":1}"#;
+    match WebsocketEvents::from_frame(broken) {
+        WebsocketEvents::ParseError(e) => {
+            assert!(e.error.contains("data"));
+            assert_eq!(e.frame, broken);
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+    assert!(matches!(
+        WebsocketEvents::from_frame("not json"),
+        WebsocketEvents::ParseError(_)
+    ));
+
+    // Routing reads the top level fields, whatever their order or the payload.
+    let delta = r#"{"data":{"symbol":"BTCUSDT","type":"snapshot","lastPrice":"1"},"cs":1,"type":"delta","ts":1,"topic":"tickers.BTCUSDT"}"#;
+    assert!(matches!(
+        WebsocketEvents::from_frame(delta),
+        WebsocketEvents::TickerDeltaEvent(_)
+    ));
+    let trade = r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1,"data":[{"T":1,"s":"BTCUSDT","S":"Buy","v":"1","p":"1","L":"PlusTick","i":"\"mIv\"","BT":false}]}"#;
+    assert!(matches!(
+        WebsocketEvents::from_frame(trade),
+        WebsocketEvents::TradeEvent(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_control_events() {
+    let server = MockWsServer::start().await.unwrap();
+    server.reject_auth("error:signature verification failed");
+    let ws: Stream = Bybit::new_with_config(&server.config(), api_key(), secret());
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let public = ws.clone();
+    tokio::spawn(async move {
+        let request = Subscription::new("subscribe", vec!["publicTrade.BTCUSDT"]);
+        let _ = public
+            .ws_subscribe(request, Category::Linear, move |event| {
+                tx.send(event).map_err(|e| e.to_string().into())
+            })
+            .await;
+    });
+    assert!(matches!(
+        rx.recv().await,
+        Some(WebsocketEvents::Subscribed(topics)) if topics == vec!["publicTrade.BTCUSDT"]
+    ));
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let request = Subscription::new("subscribe", vec!["order"]);
+    let result = ws
+        .ws_priv_subscribe(request, move |event| {
+            tx.send(event).map_err(|e| e.to_string().into())
+        })
+        .await;
+    assert!(matches!(result, Err(BybitError::AuthFailed(_))));
+    assert!(matches!(
+        rx.recv().await,
+        Some(WebsocketEvents::AuthFailed(reason)) if reason.contains("signature")
+    ));
+}
+
+#[test]
+fn test_shard_policy() {
+    let topics: Vec<String> = (0..25).map(|i| format!("tickers.COIN{}USDT", i)).collect();
+    let mut topics: Vec<&str> = topics.iter().map(String::as_str).collect();
+    topics.push("tickers.COIN0USDT");
+    let shards = ShardPolicy::default()
+        .set_max_topics_per_connection(10)
+        .shard(&topics);
+    let sizes: Vec<usize> = shards.iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![10, 10, 5]);
+    assert_eq!(shards[2][4], "tickers.COIN24USDT");
+}
+
+#[tokio::test]
+async fn test_sharded_subscribe() {
+    let server = MockWsServer::start().await.unwrap();
+    let ws: Stream = Bybit::new_with_config(&server.config(), api_key(), secret());
+    let topics: Vec<String> = (0..25).map(|i| format!("tickers.COIN{}USDT", i)).collect();
+    let topics: Vec<&str> = topics.iter().map(String::as_str).collect();
+    let policy = ShardPolicy::default()
+        .set_max_args_per_request(4)
+        .set_max_topics_per_connection(10);
+    let mut events = ws.subscribe_sharded(&topics, Category::Linear, policy);
+    let mut subscribed = Vec::new();
+    let mut acks = 0;
+    while subscribed.len() < topics.len() {
+        match events.next().await {
+            Some(Ok(WebsocketEvents::Subscribed(topics))) => {
+                acks += 1;
+                subscribed.extend(topics);
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+    subscribed.sort();
+    let mut expected: Vec<String> = topics.iter().map(|t| t.to_string()).collect();
+    expected.sort();
+    assert_eq!(subscribed, expected);
+    // 10 + 10 + 5 topics sent 4 args at a time.
+    assert_eq!(acks, 8);
+    assert_eq!(server.connections(), 3);
+}
+
+#[test]
+fn test_ticker_delta_merge() {
+    let snapshot = json!({
+        "topic": "tickers.BTCUSDT",
+        "type": "snapshot",
+        "cs": 1,
+        "ts": 1,
+        "data": {
+            "symbol": "BTCUSDT", "tickDirection": "PlusTick", "price24hPcnt": "0.01",
+            "lastPrice": "60000", "prevPrice24h": "59000", "highPrice24h": "61000",
+            "lowPrice24h": "58000", "prevPrice1h": "59900", "markPrice": "60001",
+            "indexPrice": "60002", "openInterest": "100", "openInterestValue": "6000000",
+            "turnover24h": "1000000", "volume24h": "20", "nextFundingTime": "1700000000000",
+            "fundingRate": "0.0001", "bid1Price": "59999", "bid1Size": "1",
+            "ask1Price": "60000", "ask1Size": "2"
+        }
+    })
+    .to_string();
+    let delta = json!({
+        "topic": "tickers.BTCUSDT",
+        "type": "delta",
+        "cs": 2,
+        "ts": 2,
+        "data": { "symbol": "BTCUSDT", "lastPrice": "60100", "bid1Price": "60099" }
+    })
+    .to_string();
+
+    let cache = TickerCache::new();
+    let early = WebsocketEvents::from_frame(&delta);
+    assert!(matches!(early, WebsocketEvents::TickerDeltaEvent(_)));
+    assert!(matches!(
+        cache.apply(&early),
+        Some(Err(BybitError::MissingTickerSnapshot(_)))
+    ));
+
+    let first = cache
+        .apply(&WebsocketEvents::from_frame(&snapshot))
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.last_price, amount("60000"));
+
+    let merged = cache.apply(&early).unwrap().unwrap();
+    assert_eq!(merged.last_price, amount("60100"));
+    assert_eq!(merged.bid_price, amount("60099"));
+    assert_eq!(merged.ask_price, amount("60000"));
+    assert_eq!(merged.funding_rate, Some(amount("0.0001")));
+    assert_eq!(cache.get("BTCUSDT").unwrap().last_price, amount("60100"));
+    assert!(cache.get("ETHUSDT").is_none());
+}
+
+fn order_update() -> Value {
+    serde_json::from_str(
+        r#"[{
+        "symbol": "BTCUSDT", "orderId": "1", "side": "Buy", "orderType": "Limit",
+        "cancelType": "UNKNOWN", "price": "60000", "qty": "0.01", "orderIv": "",
+        "timeInForce": "GTC", "orderStatus": "New", "orderLinkId": "link-1",
+        "lastPriceOnCreated": "60010", "reduceOnly": false, "leavesQty": "0.01",
+        "leavesValue": "600", "cumExecQty": "0", "cumExecValue": "0", "avgPrice": "",
+        "blockTradeId": "", "positionIdx": 0, "cumExecFee": "0", "createdTime": "1",
+        "updatedTime": "1", "rejectReason": "EC_NoError", "stopOrderType": "",
+        "tpslMode": "", "triggerPrice": "", "takeProfit": "", "stopLoss": "",
+        "tpTriggerBy": "", "slTriggerBy": "", "tpLimitPrice": "", "slLimitPrice": "",
+        "triggerDirection": 0, "triggerBy": "", "closeOnTrigger": false,
+        "category": "linear", "placeType": "", "smpType": "None", "smpGroup": 0,
+        "smpOrderId": "", "feeCurrency": ""
+    }]"#,
+    )
+    .unwrap()
+}
+
+fn execution_update() -> Value {
+    json!([{
+        "category": "linear", "symbol": "BTCUSDT", "execFee": "0.006", "execId": "e-1",
+        "execPrice": "60000", "execQty": "0.01", "execType": "Trade", "execValue": "600",
+        "isMaker": false, "feeRate": "0.00001", "tradeIv": "", "markIv": "",
+        "blockTradeId": "", "markPrice": "60001", "indexPrice": "", "underlyingPrice": "",
+        "leavesQty": "0", "orderId": "1", "orderLinkId": "link-1", "orderPrice": "60000",
+        "orderQty": "0.01", "orderType": "Limit", "stopOrderType": "UNKNOWN",
+        "side": "Buy", "execTime": "1", "isLeverage": "0", "closedSize": "", "seq": 1
+    }])
+}
+
+#[tokio::test]
+async fn test_mock_public_stream() {
+    let server = MockWsServer::start().await.unwrap();
+    let ws: Stream = Bybit::new_with_config(&server.config(), None, None);
+    let topics = vec!["orderbook.1.BTCUSDT", "publicTrade.BTCUSDT"];
+    let mut events =
+        ws.subscribe(Subscription::new("subscribe", topics), Category::Linear);
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(WebsocketEvents::Subscribed(_)))
+    ));
+
+    let book = json!({"s": "BTCUSDT", "b": [["60000", "1"]], "a": [["60001", "2"]], "u": 1, "seq": 7});
+    assert_eq!(server.push("orderbook.1.BTCUSDT", "snapshot", book), 1);
+    match events.next().await {
+        Some(Ok(WebsocketEvents::OrderBookEvent(book))) => {
+            assert_eq!(book.data.symbol, "BTCUSDT");
+            assert_eq!(book.data.seq, 7);
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    let trades = json!([{
+        "T": 1, "s": "BTCUSDT", "S": "Sell", "v": "0.5", "p": "60000.5",
+        "L": "MinusTick", "i": "t-1", "BT": false
+    }]);
+    server.push("publicTrade.BTCUSDT", "snapshot", trades);
+    match events.next().await {
+        Some(Ok(WebsocketEvents::TradeEvent(trade))) => {
+            assert_eq!(trade.data[0].price, amount("60000.5"))
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    assert_eq!(server.push("tickers.BTCUSDT", "snapshot", json!({})), 0);
+}
+
+#[tokio::test]
+async fn test_mock_private_stream() {
+    let server = MockWsServer::start().await.unwrap();
+    let ws: Stream = Bybit::new_with_config(&server.config(), None, None);
+    let topics = vec!["order", "execution"];
+    let mut events = ws.subscribe_private(Subscription::new("subscribe", topics));
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(WebsocketEvents::AuthOk))
+    ));
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(WebsocketEvents::Subscribed(_)))
+    ));
+
+    server.push_private("order", order_update());
+    match events.next().await {
+        Some(Ok(WebsocketEvents::OrderEvent(order))) => {
+            assert_eq!(order.data[0].order_link_id, "link-1");
+            assert_eq!(order.data[0].leaves_qty, amount("0.01"));
+            assert!(order.data[0].avg_price.is_none());
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    server.push_private("execution", execution_update());
+    match events.next().await {
+        Some(Ok(WebsocketEvents::ExecutionEvent(execution))) => {
+            assert_eq!(execution.data[0].exec_id, "e-1");
+            assert_eq!(execution.data[0].exec_price, amount("60000"));
+            assert!(execution.data[0].index_price.is_none());
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    assert_eq!(server.requests()[0]["op"], "auth");
+
+    server.reject_auth("error:signature verification failed");
+    let request = Subscription::new("subscribe", vec!["order"]);
+    let result = ws.ws_priv_subscribe(request, |_| Ok(())).await;
+    assert!(matches!(result, Err(BybitError::AuthFailed(_))));
+}
+
+#[tokio::test]
+async fn test_mock_trade_stream() {
+    let server = MockWsServer::start().await.unwrap();
+    let ws: Stream = Bybit::new_with_config(&server.config(), None, None);
+    let trader = ws.ws_trader().await.unwrap();
+    let order = OrderRequest {
+        symbol: Cow::Borrowed("BTCUSDT"),
+        qty: amount("0.01"),
+        order_link_id: Some(Cow::Borrowed("link-7")),
+        ..OrderRequest::default()
+    };
+    let placed = trader.place_order(order.clone()).await.unwrap();
+    assert_eq!(placed.order_link_id, "link-7");
+
+    server.reject_orders(Some((10001, "params error")));
+    assert!(matches!(
+        trader.place_order(order).await,
+        Err(BybitError::TradeRequestRejected { code: 10001, .. })
+    ));
+}